@@ -1,21 +1,28 @@
-#![allow(unused_imports)]
-
-use boondock::{errors::Result, Docker};
+use boondock::{errors::Result, progress::ProgressEvent, Docker, PullImageOptions};
+use futures::TryStreamExt;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    /*
     let docker = Docker::connect_with_defaults()?;
 
-    let image = "debian".to_string();
-    let tag = "latest".to_string();
-    let statuses = docker.create_image(image, tag).await?;
-
-    if let Some(last) = statuses.last() {
-        println!("{}", last.status?);
-    } else {
-        println!("none");
+    let mut progress = docker
+        .pull_image("debian", "latest", PullImageOptions::default())
+        .await?;
+    while let Some(message) = progress.try_next().await? {
+        match message {
+            ProgressEvent::Status {
+                id: Some(id),
+                status,
+            } => println!("{}: {}", id, status),
+            ProgressEvent::Status { id: None, status } => println!("{}", status),
+            ProgressEvent::Progress {
+                id: Some(id),
+                status,
+                current,
+                total: Some(total),
+            } => println!("{}: {} {}/{}", id, status, current, total),
+            _ => {}
+        }
     }
-    */
     Ok(())
 }
@@ -1,11 +1,17 @@
 use boondock::{errors::Result, ContainerListOptions, Docker};
+use futures::TryStreamExt;
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let docker = Docker::connect_with_defaults()?;
     let opts = ContainerListOptions::default();
-    if let Some(container) = docker.containers(opts).await?.get(0) {
-        docker.export_container(container).await?;
+    if let Some(container) = docker.containers(opts).await?.first() {
+        let mut export = docker.export_container(container).await?;
+        let mut total = 0;
+        while let Some(chunk) = export.try_next().await? {
+            total += chunk.len();
+        }
+        println!("exported {} bytes", total);
     }
     Ok(())
 }
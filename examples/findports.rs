@@ -6,7 +6,7 @@ async fn find_all_exported_ports() -> Result<()> {
     let docker = Docker::connect_with_defaults()?;
     let containers = docker.containers(ContainerListOptions::default()).await?;
     for container in &containers {
-        let info = docker.container_info(&container).await?;
+        let info = docker.container_info(container).await?;
 
         // Uncomment this to dump everything we know about a container.
         //println!("{:#?}", &info);
@@ -24,7 +24,7 @@ async fn main() -> Result<()> {
     if let Err(err) = find_all_exported_ports().await {
         write!(io::stderr(), "Error: ")?;
         for e in err.iter() {
-            write!(io::stderr(), "{}\n", e)?;
+            writeln!(io::stderr(), "{}", e)?;
         }
     }
     Ok(())
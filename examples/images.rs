@@ -1,9 +1,9 @@
-use boondock::{errors::Result, Docker};
+use boondock::{errors::Result, Docker, ImageListOptions};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let docker = Docker::connect_with_defaults()?;
-    let images = docker.images(false).await?;
+    let images = docker.images(ImageListOptions::default()).await?;
 
     for image in &images {
         println!(
@@ -1,11 +1,14 @@
-use boondock::{errors::Result, ContainerListOptions, Docker};
+use boondock::{errors::Result, ContainerListOptions, Docker, ProcessesOptions};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let docker = Docker::connect_with_defaults()?;
     let opts = ContainerListOptions::default();
-    if let Some(container) = docker.containers(opts).await?.get(0) {
-        for process in docker.processes(container).await? {
+    if let Some(container) = docker.containers(opts).await?.first() {
+        for process in docker
+            .processes(container, ProcessesOptions::default())
+            .await?
+        {
             println!("{:#?}", process);
         }
     }
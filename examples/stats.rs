@@ -1,17 +1,15 @@
-#![allow(unused_imports)]
-
-use boondock::{errors::Result, ContainerListOptions, Docker};
+use boondock::{errors::Result, ContainerListOptions, Docker, StatsOptions};
+use futures::StreamExt;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    /*
     let docker = Docker::connect_with_defaults()?;
     let opts = ContainerListOptions::default();
-    if let Some(container) = docker.containers(opts).await?.get(0) {
-        for stats in docker.stats(container).await? {
-            println!("{:#?}", stats);
+    if let Some(container) = docker.containers(opts).await?.first() {
+        let mut stats = docker.stats(container, StatsOptions::default()).await?;
+        while let Some(stats) = stats.next().await {
+            println!("{:#?}", stats?);
         }
     }
-    */
     Ok(())
 }
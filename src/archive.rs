@@ -0,0 +1,228 @@
+//! Building and unpacking the tar archives used by `Docker::copy_file_to`,
+//! `Docker::copy_file_from` and `Docker::build_image_from_dir`.
+
+use bytes::Bytes;
+use futures::{channel::mpsc, executor, SinkExt};
+use std::path::{Component, Path};
+use std::{fs, io};
+
+use crate::dockerignore::DockerIgnore;
+use crate::errors::*;
+
+/// How much data `ChannelWriter` buffers before sending it.
+const CHANNEL_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Split a path inside a container into its parent directory and final
+/// component.  Container paths always use `/`, whatever the host OS.
+pub(crate) fn split_container_path(path: &str) -> Result<(&str, &str)> {
+    let trimmed = path.trim_end_matches('/');
+    let (dir, name) = match trimmed.rfind('/') {
+        Some(0) => ("/", &trimmed[1..]),
+        Some(pos) => (&trimmed[..pos], &trimmed[pos + 1..]),
+        None => (".", trimmed),
+    };
+    if name.is_empty() || name == "." || name == ".." {
+        return Err(format!("cannot copy to or from container path {:?}", path).into());
+    }
+    Ok((dir, name))
+}
+
+/// Build a tar archive containing the file or directory at `local`, stored
+/// under the name `name`.
+pub(crate) fn tar_path(local: &Path, name: &str) -> Result<Vec<u8>> {
+    let mkerr = || format!("could not archive {}", local.display());
+    let mut builder = tar::Builder::new(Vec::new());
+    builder.follow_symlinks(false);
+    let metadata = fs::metadata(local).chain_err(mkerr)?;
+    if metadata.is_dir() {
+        builder.append_dir_all(name, local).chain_err(mkerr)?;
+    } else {
+        builder
+            .append_path_with_name(local, name)
+            .chain_err(mkerr)?;
+    }
+    builder.into_inner().chain_err(mkerr)
+}
+
+/// Unpack a tar archive returned by `Docker::get_archive` to `local`.  The
+/// archive's single top-level entry is renamed to `local`, so that the
+/// contents of a directory end up directly inside it.
+///
+/// We unpack into a staging directory next to `local` first, using
+/// `Entry::unpack_in`, so that symlinks in the archive can't be used to
+/// write outside it.
+pub(crate) fn untar_to(archive: &[u8], local: &Path) -> Result<()> {
+    let mkerr = || format!("could not extract archive to {}", local.display());
+    let name = local
+        .file_name()
+        .ok_or_else(|| format!("cannot extract archive to {}", local.display()))?;
+    let mut staging_name = std::ffi::OsString::from(".");
+    staging_name.push(name);
+    staging_name.push(format!(".boondock-{}", std::process::id()));
+    let staging = local.with_file_name(staging_name);
+    fs::create_dir_all(&staging).chain_err(mkerr)?;
+    let result = unpack_staged(archive, &staging, local).chain_err(mkerr);
+    let _ = fs::remove_dir_all(&staging);
+    result
+}
+
+/// Unpack `archive` into `staging`, and then move its top-level entries to
+/// `local`.
+fn unpack_staged(archive: &[u8], staging: &Path, local: &Path) -> Result<()> {
+    let mut archive = tar::Archive::new(io::Cursor::new(archive));
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.into_owned();
+        if !is_safe_path(&path) || !entry.unpack_in(staging)? {
+            return Err(format!("unsafe path in archive: {}", path.display()).into());
+        }
+    }
+    for entry in fs::read_dir(staging)? {
+        move_to(&entry?.path(), local)?;
+    }
+    Ok(())
+}
+
+/// Is `path` a relative path in an archive which stays inside the archive?
+fn is_safe_path(path: &Path) -> bool {
+    let mut components = path.components();
+    match components.next() {
+        Some(Component::Normal(_)) => {}
+        _ => return false,
+    }
+    components.all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
+/// Move `src` to `dest`, merging the contents of directories which already
+/// exist.
+fn move_to(src: &Path, dest: &Path) -> io::Result<()> {
+    let src_is_dir = fs::symlink_metadata(src)?.is_dir();
+    match fs::symlink_metadata(dest) {
+        Ok(meta) if meta.is_dir() && src_is_dir => {
+            for entry in fs::read_dir(src)? {
+                let entry = entry?;
+                move_to(&entry.path(), &dest.join(entry.file_name()))?;
+            }
+            Ok(())
+        }
+        Ok(meta) if meta.is_dir() => Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} is a directory", dest.display()),
+        )),
+        Ok(_) => fs::remove_file(dest).and_then(|()| fs::rename(src, dest)),
+        Err(ref err) if err.kind() == io::ErrorKind::NotFound => fs::rename(src, dest),
+        Err(err) => Err(err),
+    }
+}
+
+/// Write a tar archive of the build context in `dir` to `writer`, leaving
+/// out anything matched by `dir/.dockerignore`.  Like the `docker` client,
+/// we always include the Dockerfile and `.dockerignore` itself.
+pub(crate) fn tar_build_context<W: io::Write>(
+    dir: &Path,
+    dockerfile: &str,
+    writer: W,
+) -> Result<()> {
+    let mkerr = || format!("could not archive build context {}", dir.display());
+    let ignore = match fs::read_to_string(dir.join(".dockerignore")) {
+        Ok(text) => DockerIgnore::parse(&text),
+        Err(ref err) if err.kind() == io::ErrorKind::NotFound => DockerIgnore::default(),
+        Err(err) => return Err(err).chain_err(mkerr),
+    };
+    let dockerfile = dockerfile
+        .split('/')
+        .filter(|c| !c.is_empty() && *c != ".")
+        .collect::<Vec<_>>()
+        .join("/");
+    let always = [dockerfile.as_str(), ".dockerignore"];
+    let mut builder = tar::Builder::new(writer);
+    builder.follow_symlinks(false);
+    add_context_dir(&mut builder, dir, "", &ignore, &always).chain_err(mkerr)?;
+    builder
+        .into_inner()
+        .chain_err(mkerr)?
+        .flush()
+        .chain_err(mkerr)
+}
+
+/// Add the contents of `root/rel` to `builder`, recursively.
+fn add_context_dir<W: io::Write>(
+    builder: &mut tar::Builder<W>,
+    root: &Path,
+    rel: &str,
+    ignore: &DockerIgnore,
+    always: &[&str],
+) -> io::Result<()> {
+    let mut entries = fs::read_dir(root.join(rel))?.collect::<io::Result<Vec<_>>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let name = entry.file_name().into_string().map_err(|name| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("build context contains non-UTF-8 name {:?}", name),
+            )
+        })?;
+        let path = if rel.is_empty() {
+            name
+        } else {
+            format!("{}/{}", rel, name)
+        };
+        let keep = !ignore.is_excluded(&path) || always.contains(&path.as_str());
+        if entry.file_type()?.is_dir() {
+            if keep {
+                builder.append_dir(&path, entry.path())?;
+            }
+            // Even if the directory is excluded, some of its contents may
+            // not be.
+            let prefix = format!("{}/", path);
+            if keep || ignore.has_exceptions() || always.iter().any(|a| a.starts_with(&prefix)) {
+                add_context_dir(builder, root, &path, ignore, always)?;
+            }
+        } else if keep {
+            builder.append_path_with_name(entry.path(), &path)?;
+        }
+    }
+    Ok(())
+}
+
+/// A blocking writer which sends what it's given to an async channel in
+/// large chunks, so that we can stream an archive as we build it.
+pub(crate) struct ChannelWriter {
+    buf: Vec<u8>,
+    sender: mpsc::Sender<io::Result<Bytes>>,
+}
+
+impl ChannelWriter {
+    /// Create a writer which sends to `sender`.  This must not be used from
+    /// an async task, because writes block until the receiver catches up.
+    pub(crate) fn new(sender: mpsc::Sender<io::Result<Bytes>>) -> ChannelWriter {
+        ChannelWriter {
+            buf: Vec::with_capacity(CHANNEL_CHUNK_SIZE),
+            sender,
+        }
+    }
+
+    /// Send everything we've buffered.
+    fn send_buffered(&mut self) -> io::Result<()> {
+        if self.buf.is_empty() {
+            return Ok(());
+        }
+        let chunk = Bytes::from(self.buf.split_off(0));
+        executor::block_on(self.sender.send(Ok(chunk)))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "receiver went away"))
+    }
+}
+
+impl io::Write for ChannelWriter {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(data);
+        if self.buf.len() >= CHANNEL_CHUNK_SIZE {
+            self.send_buffered()?;
+        }
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.send_buffered()
+    }
+}
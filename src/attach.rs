@@ -0,0 +1,106 @@
+//! Interactive connections to a running container.
+
+use futures::stream::{self, Stream, StreamExt};
+use hyper::upgrade::Upgraded;
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, ReadHalf, WriteHalf};
+
+use crate::errors::*;
+use crate::logs::{LogDecoder, LogLine};
+
+/// Output from an attached container.  Unless the container has a TTY, this
+/// will be multiplexed using the same framing as `Docker::logs`, which you
+/// can decode using `into_stream`.
+pub struct AttachReader {
+    inner: ReadHalf<Upgraded>,
+    /// The `Content-Type` Docker sent when upgrading the connection, which
+    /// tells us whether the output is multiplexed.
+    content_type: Option<String>,
+}
+
+/// Input to an attached container's stdin.
+pub struct AttachWriter {
+    inner: WriteHalf<Upgraded>,
+}
+
+/// Split a hijacked connection into read and write halves.
+pub(crate) fn split(
+    upgraded: Upgraded,
+    content_type: Option<String>,
+) -> (AttachReader, AttachWriter) {
+    let (reader, writer) = tokio::io::split(upgraded);
+    (
+        AttachReader {
+            inner: reader,
+            content_type,
+        },
+        AttachWriter { inner: writer },
+    )
+}
+
+impl AttachReader {
+    /// Convert our output into a stream of stdout and stderr chunks.
+    pub fn into_stream(self) -> impl Stream<Item = Result<LogLine>> + Send + Unpin {
+        let decoder = LogDecoder::for_content_type(self.content_type.as_deref());
+        decode_attach_stream(decoder, self)
+    }
+}
+
+/// Decode the output of `reader` as it arrives.  Each chunk is returned as
+/// soon as `decoder` can make sense of it, so interactive output like a
+/// shell prompt isn't held back.
+pub(crate) fn decode_attach_stream<R>(
+    decoder: LogDecoder,
+    reader: R,
+) -> impl Stream<Item = Result<LogLine>> + Send + Unpin
+where
+    R: AsyncRead + Send + Unpin + 'static,
+{
+    stream::unfold(Some((decoder, reader)), |state| async move {
+        let (mut decoder, mut reader) = state?;
+        let mut buf = vec![0; 8 * 1024];
+        loop {
+            if let Some(line) = decoder.next_line() {
+                return Some((Ok(line), Some((decoder, reader))));
+            }
+            match reader.read(&mut buf).await {
+                Ok(0) => return decoder.finish().map(|line| (Ok(line), None)),
+                Ok(n) => decoder.extend(&buf[..n]),
+                Err(err) => return Some((Err(err.into()), None)),
+            }
+        }
+    })
+    .boxed()
+}
+
+impl AsyncRead for AttachReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for AttachWriter {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
@@ -0,0 +1,102 @@
+//! Credentials for talking to image registries.
+
+use std::fmt;
+
+use crate::config::CliConfig;
+use crate::errors::*;
+
+/// Credentials for an image registry, sent to Docker in the
+/// `X-Registry-Auth` header.
+#[derive(Clone, Default, Serialize)]
+pub struct RegistryAuth {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    serveraddress: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    identitytoken: Option<String>,
+}
+
+impl RegistryAuth {
+    /// Log in with a username and password.
+    pub fn new(username: &str, password: &str) -> Self {
+        RegistryAuth {
+            username: Some(username.to_owned()),
+            password: Some(password.to_owned()),
+            ..RegistryAuth::default()
+        }
+    }
+
+    /// Log in with an identity token, as returned by `docker login` for
+    /// registries which support OAuth.
+    pub fn identity_token(token: &str) -> Self {
+        RegistryAuth {
+            identitytoken: Some(token.to_owned()),
+            ..RegistryAuth::default()
+        }
+    }
+
+    /// The registry these credentials are for, such as
+    /// `"https://index.docker.io/v1/"`.
+    pub fn server_address(mut self, address: &str) -> Self {
+        self.serveraddress = Some(address.to_owned());
+        self
+    }
+
+    /// Load the credentials saved by `docker login` for `registry`, such
+    /// as `"registry.example.com"` or `"docker.io"`, using the `docker`
+    /// client's `config.json`.  If it configures a credential helper for
+    /// the registry, such as `osxkeychain` or `ecr-login`, we run
+    /// `docker-credential-<helper>` to get the credentials.  Returns `None`
+    /// if there aren't any.
+    pub async fn from_docker_config(registry: &str) -> Result<Option<Self>> {
+        CliConfig::load().await?.registry_auth(registry).await
+    }
+
+    /// Encode these credentials for the `X-Registry-Auth` header.
+    pub(crate) fn to_header(&self) -> Result<String> {
+        let json = serde_json::to_vec(self).chain_err(|| "could not serialize registry auth")?;
+        Ok(base64::encode_config(&json, base64::URL_SAFE))
+    }
+}
+
+/// Don't include secrets in debug output.
+impl fmt::Debug for RegistryAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegistryAuth")
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "<redacted>"))
+            .field("serveraddress", &self.serveraddress)
+            .field(
+                "identitytoken",
+                &self.identitytoken.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+/// The response to `Docker::check_auth`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct AuthResponse {
+    /// A message such as `"Login Succeeded"`.
+    pub Status: String,
+    /// A token which can be used instead of the password from now on.
+    /// Docker sends an empty string for registries which don't support
+    /// OAuth, so use `identity_token` instead.
+    #[serde(default)]
+    pub IdentityToken: Option<String>,
+}
+
+impl AuthResponse {
+    /// Credentials using the identity token returned by the registry, if
+    /// there was one.
+    pub fn identity_token(&self) -> Option<RegistryAuth> {
+        self.IdentityToken
+            .as_deref()
+            .filter(|token| !token.is_empty())
+            .map(RegistryAuth::identity_token)
+    }
+}
@@ -0,0 +1,122 @@
+//! Building images.
+
+use prost::Message;
+
+use crate::errors::*;
+
+/// The `id` of BuildKit messages which carry a trace in `aux`.
+pub(crate) const BUILDKIT_TRACE_ID: &str = "moby.buildkit.trace";
+
+/// Decode a base64-encoded BuildKit trace.
+pub(crate) fn decode_trace(encoded: &str) -> Result<BuildkitStatus> {
+    let bytes = base64::decode(encoded).chain_err(|| "could not decode BuildKit trace")?;
+    BuildkitStatus::decode(&bytes[..]).chain_err(|| "could not decode BuildKit trace")
+}
+
+/// The image built by `Docker::build_image`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct BuildResult {
+    /// The ID of the new image, such as `"sha256:..."`.
+    pub ID: String,
+}
+
+/// The progress of a BuildKit build, as reported by a single trace.  Each
+/// trace describes only what has changed since the last one.
+#[derive(Clone, PartialEq, Message)]
+pub struct BuildkitStatus {
+    /// Build steps which have started or finished.
+    #[prost(message, repeated, tag = "1")]
+    pub vertexes: Vec<Vertex>,
+    /// Progress within build steps, such as layers being downloaded.
+    #[prost(message, repeated, tag = "2")]
+    pub statuses: Vec<VertexStatus>,
+    /// Output from build steps.
+    #[prost(message, repeated, tag = "3")]
+    pub logs: Vec<VertexLog>,
+    #[prost(message, repeated, tag = "4")]
+    pub warnings: Vec<VertexWarning>,
+}
+
+/// A step in a BuildKit build.
+#[derive(Clone, PartialEq, Message)]
+pub struct Vertex {
+    #[prost(string, tag = "1")]
+    pub digest: String,
+    /// The digests of the steps this one depends on.
+    #[prost(string, repeated, tag = "2")]
+    pub inputs: Vec<String>,
+    /// A description of the step, such as `"[2/3] RUN make"`.
+    #[prost(string, tag = "3")]
+    pub name: String,
+    #[prost(bool, tag = "4")]
+    pub cached: bool,
+    #[prost(message, optional, tag = "5")]
+    pub started: Option<Timestamp>,
+    #[prost(message, optional, tag = "6")]
+    pub completed: Option<Timestamp>,
+    /// Why the step failed, or empty.
+    #[prost(string, tag = "7")]
+    pub error: String,
+}
+
+/// Progress of a task within a step.
+#[derive(Clone, PartialEq, Message)]
+pub struct VertexStatus {
+    #[prost(string, tag = "1")]
+    pub id: String,
+    /// The digest of the step this belongs to.
+    #[prost(string, tag = "2")]
+    pub vertex: String,
+    #[prost(string, tag = "3")]
+    pub name: String,
+    #[prost(int64, tag = "4")]
+    pub current: i64,
+    #[prost(int64, tag = "5")]
+    pub total: i64,
+    #[prost(message, optional, tag = "6")]
+    pub timestamp: Option<Timestamp>,
+    #[prost(message, optional, tag = "7")]
+    pub started: Option<Timestamp>,
+    #[prost(message, optional, tag = "8")]
+    pub completed: Option<Timestamp>,
+}
+
+/// Output from a step.
+#[derive(Clone, PartialEq, Message)]
+pub struct VertexLog {
+    /// The digest of the step this belongs to.
+    #[prost(string, tag = "1")]
+    pub vertex: String,
+    #[prost(message, optional, tag = "2")]
+    pub timestamp: Option<Timestamp>,
+    /// 1 for stdout, 2 for stderr.
+    #[prost(int64, tag = "3")]
+    pub stream: i64,
+    #[prost(bytes, tag = "4")]
+    pub msg: Vec<u8>,
+}
+
+/// A warning about the build, such as a deprecated Dockerfile instruction.
+#[derive(Clone, PartialEq, Message)]
+pub struct VertexWarning {
+    #[prost(string, tag = "1")]
+    pub vertex: String,
+    #[prost(int64, tag = "2")]
+    pub level: i64,
+    #[prost(bytes, tag = "3")]
+    pub short: Vec<u8>,
+    #[prost(bytes, repeated, tag = "4")]
+    pub detail: Vec<Vec<u8>>,
+    #[prost(string, tag = "5")]
+    pub url: String,
+}
+
+/// A protobuf timestamp.
+#[derive(Clone, PartialEq, Message)]
+pub struct Timestamp {
+    #[prost(int64, tag = "1")]
+    pub seconds: i64,
+    #[prost(int32, tag = "2")]
+    pub nanos: i32,
+}
@@ -0,0 +1,9 @@
+//! Container checkpoints, which require an experimental Docker daemon with
+//! CRIU installed.
+
+/// A saved checkpoint of a container.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct Checkpoint {
+    pub Name: String,
+}
@@ -0,0 +1,211 @@
+//! The configuration file used by the `docker` command-line client,
+//! normally `~/.docker/config.json`.
+
+use std::collections::HashMap;
+use std::env;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use tokio::{fs, task};
+
+use crate::auth::RegistryAuth;
+use crate::errors::*;
+
+/// The parts of the `docker` client's configuration which we understand.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CliConfig {
+    /// The key sequence used to detach from a container, such as
+    /// `"ctrl-p,ctrl-q"`.
+    #[serde(default)]
+    pub detach_keys: Option<String>,
+    /// Credentials saved by `docker login`, indexed by registry.
+    #[serde(default)]
+    pub auths: HashMap<String, AuthEntry>,
+    /// The credential helper used for all registries, such as
+    /// `"osxkeychain"`.
+    #[serde(default)]
+    pub creds_store: Option<String>,
+    /// Credential helpers for specific registries, indexed by hostname.
+    #[serde(default)]
+    pub cred_helpers: HashMap<String, String>,
+}
+
+/// Credentials for a single registry, as stored in `config.json`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct AuthEntry {
+    /// `"username:password"`, encoded using base64.
+    #[serde(default)]
+    pub auth: Option<String>,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    #[serde(default)]
+    pub identitytoken: Option<String>,
+}
+
+impl CliConfig {
+    /// Load the configuration file, returning the default configuration if
+    /// it doesn't exist.
+    pub(crate) async fn load() -> Result<CliConfig> {
+        let path = match config_path() {
+            Some(path) => path,
+            None => return Ok(CliConfig::default()),
+        };
+        let data = match fs::read(&path).await {
+            Ok(data) => data,
+            Err(ref err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(CliConfig::default())
+            }
+            Err(err) => return Err(err).chain_err(|| format!("could not read {}", path.display())),
+        };
+        serde_json::from_slice(&data).chain_err(|| format!("could not parse {}", path.display()))
+    }
+
+    /// Look up the credentials for `registry`, which may be a hostname like
+    /// `"registry.example.com"` or a URL.  Like the `docker` client, we ask
+    /// a credential helper if one is configured, and otherwise use the
+    /// credentials saved in `auths`.
+    pub(crate) async fn registry_auth(&self, registry: &str) -> Result<Option<RegistryAuth>> {
+        match self.credential_helper(registry) {
+            Some(helper) => {
+                let helper = helper.to_owned();
+                let server = helper_server_url(registry);
+                task::spawn_blocking(move || run_credential_helper(&helper, &server))
+                    .await
+                    .chain_err(|| "credential helper panicked")?
+            }
+            None => self.saved_auth(registry),
+        }
+    }
+
+    /// The name of the credential helper for `registry`, if any.  The
+    /// helper program is called `docker-credential-<name>`.
+    pub(crate) fn credential_helper(&self, registry: &str) -> Option<&str> {
+        let wanted = registry_hostname(registry);
+        self.cred_helpers
+            .iter()
+            .find(|(key, _)| registry_hostname(key) == wanted)
+            .map(|(_, helper)| helper.as_str())
+            .or(self.creds_store.as_deref())
+            .filter(|helper| !helper.is_empty())
+    }
+
+    /// Look up the credentials for `registry` in `auths`.
+    pub(crate) fn saved_auth(&self, registry: &str) -> Result<Option<RegistryAuth>> {
+        let wanted = registry_hostname(registry);
+        let found = self
+            .auths
+            .iter()
+            .find(|(key, _)| registry_hostname(key) == wanted);
+        let (key, entry) = match found {
+            Some(found) => found,
+            None => return Ok(None),
+        };
+        let auth = if let Some(ref token) = entry.identitytoken {
+            RegistryAuth::identity_token(token)
+        } else if let Some(ref encoded) = entry.auth {
+            let mkerr = || format!("invalid credentials for {} in config.json", key);
+            let decoded = base64::decode(encoded).chain_err(mkerr)?;
+            let decoded = String::from_utf8(decoded).chain_err(mkerr)?;
+            let mut parts = decoded.splitn(2, ':');
+            match (parts.next(), parts.next()) {
+                (Some(username), Some(password)) => RegistryAuth::new(username, password),
+                _ => return Err(mkerr().into()),
+            }
+        } else if let (Some(username), Some(password)) = (&entry.username, &entry.password) {
+            RegistryAuth::new(username, password)
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(auth.server_address(key)))
+    }
+}
+
+/// The output of `docker-credential-<name> get`.
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case)]
+struct HelperCredentials {
+    ServerURL: Option<String>,
+    Username: String,
+    Secret: String,
+}
+
+/// Ask the credential helper `helper` for the credentials for `server`.
+fn run_credential_helper(helper: &str, server: &str) -> Result<Option<RegistryAuth>> {
+    let program = format!("docker-credential-{}", helper);
+    let mkerr = || format!("could not run {}", program);
+    let mut child = Command::new(&program)
+        .arg("get")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .chain_err(mkerr)?;
+    child
+        .stdin
+        .take()
+        .expect("stdin should be piped")
+        .write_all(server.as_bytes())
+        .chain_err(mkerr)?;
+    let output = child.wait_with_output().chain_err(mkerr)?;
+    if !output.status.success() {
+        let message = String::from_utf8_lossy(&output.stdout).into_owned()
+            + &String::from_utf8_lossy(&output.stderr);
+        if message.contains("credentials not found") {
+            return Ok(None);
+        }
+        return Err(format!("{} failed: {}", program, message.trim()).into());
+    }
+    parse_helper_output(&output.stdout, server)
+        .chain_err(|| format!("could not parse output of {}", program))
+        .map(Some)
+}
+
+/// Convert the output of a credential helper into `RegistryAuth`.
+pub(crate) fn parse_helper_output(output: &[u8], server: &str) -> Result<RegistryAuth> {
+    let creds: HelperCredentials =
+        serde_json::from_slice(output).chain_err(|| "invalid credential helper output")?;
+    // Helpers store identity tokens with this special username.
+    let auth = if creds.Username == "<token>" {
+        RegistryAuth::identity_token(&creds.Secret)
+    } else {
+        RegistryAuth::new(&creds.Username, &creds.Secret)
+    };
+    Ok(auth.server_address(creds.ServerURL.as_deref().unwrap_or(server)))
+}
+
+/// The server URL which credential helpers expect for `registry`.  Docker
+/// Hub credentials are stored under its old index URL.
+fn helper_server_url(registry: &str) -> String {
+    match registry_hostname(registry) {
+        "index.docker.io" => "https://index.docker.io/v1/".to_owned(),
+        host => host.to_owned(),
+    }
+}
+
+/// Reduce a registry address to a hostname, the way the `docker` client
+/// compares `auths` keys.  Docker Hub has several names.
+fn registry_hostname(registry: &str) -> &str {
+    let without_scheme = registry
+        .strip_prefix("https://")
+        .or_else(|| registry.strip_prefix("http://"))
+        .unwrap_or(registry);
+    let host = without_scheme.split('/').next().unwrap_or("");
+    match host {
+        "docker.io" | "index.docker.io" | "registry-1.docker.io" => "index.docker.io",
+        host => host,
+    }
+}
+
+/// The path to `config.json`.  Like the `docker` client, we look in
+/// `DOCKER_CONFIG` first, and then in `~/.docker`.
+fn config_path() -> Option<PathBuf> {
+    let dir = match env::var_os("DOCKER_CONFIG") {
+        Some(dir) => PathBuf::from(dir),
+        None => dirs::home_dir()?.join(".docker"),
+    };
+    Some(dir.join("config.json"))
+}
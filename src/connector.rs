@@ -108,6 +108,7 @@ impl Connector {
     }
 }
 
+#[allow(clippy::large_enum_variant)]
 pub(crate) enum Stream {
     /// An HTTPS or HTTP stream.
     Https(MaybeHttpsStream<<HttpConnector as Service<Uri>>::Response>),
@@ -252,7 +253,7 @@ fn docker_client_key() -> Result<CertifiedKey> {
 
     // Look up our certificates.
     let mut all_certs = certs(&dir.join("cert.pem"))?;
-    all_certs.extend(certs(&dir.join("ca.pem"))?.into_iter());
+    all_certs.extend(certs(&dir.join("ca.pem"))?);
 
     // Look up our keys.
     let key_path = dir.join("key.pem");
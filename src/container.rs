@@ -1,21 +1,56 @@
 use std;
 use std::collections::HashMap;
 
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+
+use crate::image::null_to_default;
+use crate::logs::LogLine;
+#[cfg(feature = "chrono")]
+use crate::timestamps;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(non_snake_case)]
-//Labels, HostConfig
 pub struct Container {
     pub Id: String,
     pub Image: String,
+    /// The ID of the image, which `Image` may refer to by name.
+    #[serde(default)]
+    pub ImageID: String,
+    /// A human-readable description, such as "Up 2 hours".
     pub Status: String,
+    /// The state of the container.  Only reported by newer daemons.
+    #[serde(default)]
+    pub State: Option<ContainerStatus>,
     pub Command: String,
+    #[cfg(not(feature = "chrono"))]
     pub Created: u64,
+    #[cfg(feature = "chrono")]
+    #[serde(with = "timestamps::unix")]
+    pub Created: DateTime<Utc>,
     pub Names: Vec<String>,
     pub Ports: Vec<Port>,
-    pub SizeRw: Option<u64>, // I guess it is optional on Mac.
+    /// Size of the files created or changed by this container.  Only
+    /// present if `ContainerListOptions::size` was specified.
+    pub SizeRw: Option<u64>,
+    /// Total size of all the files in this container.  Only present if
+    /// `ContainerListOptions::size` was specified.
     pub SizeRootFs: Option<u64>,
     pub Labels: Option<HashMap<String, String>>,
     pub HostConfig: HostConfig,
+    #[serde(default)]
+    pub NetworkSettings: ContainerNetworkSettings,
+    #[serde(default)]
+    pub Mounts: Vec<Mount>,
+}
+
+/// The subset of `NetworkSettings` included when listing containers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ContainerNetworkSettings {
+    /// The networks this container is attached to, by name.
+    #[serde(default)]
+    pub Networks: HashMap<String, EndpointSettings>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +66,137 @@ pub struct Port {
 #[allow(non_snake_case)]
 pub struct HostConfig {
     pub NetworkMode: String,
+    /// Only returned by `Docker::container_info`.
+    #[serde(default)]
+    pub RestartPolicy: Option<RestartPolicy>,
+    /// Whether the container will be removed when it exits.  Only returned
+    /// by `Docker::container_info`.
+    #[serde(default)]
+    pub AutoRemove: bool,
+    #[serde(default)]
+    pub Devices: Option<Vec<DeviceMapping>>,
+    #[serde(default)]
+    pub CapAdd: Option<Vec<String>>,
+    #[serde(default)]
+    pub CapDrop: Option<Vec<String>>,
+    #[serde(default)]
+    pub Privileged: bool,
+    #[serde(default)]
+    pub SecurityOpt: Option<Vec<String>>,
+    #[serde(default)]
+    pub CgroupParent: String,
+    /// The size of `/dev/shm`, in bytes.
+    #[serde(default)]
+    pub ShmSize: Option<u64>,
+    #[serde(default)]
+    pub DeviceRequests: Option<Vec<DeviceRequest>>,
+}
+
+/// A request for devices, such as GPUs, which are provided by a device
+/// driver rather than mapped by path.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct DeviceRequest {
+    /// The driver to use, such as `"nvidia"`.  If empty, Docker picks a
+    /// driver based on `Capabilities`.
+    #[serde(default)]
+    pub Driver: String,
+    /// The number of devices to request, or -1 for all of them.  Ignored
+    /// if `DeviceIDs` is not empty.
+    #[serde(default)]
+    pub Count: i64,
+    #[serde(default, deserialize_with = "crate::image::null_to_default")]
+    pub DeviceIDs: Vec<String>,
+    /// Alternative sets of capabilities which the driver must support,
+    /// such as `[["gpu"]]`.
+    #[serde(default, deserialize_with = "crate::image::null_to_default")]
+    pub Capabilities: Vec<Vec<String>>,
+    /// Driver-specific options.
+    #[serde(default, deserialize_with = "crate::image::null_to_default")]
+    pub Options: HashMap<String, String>,
+}
+
+impl DeviceRequest {
+    /// Request `count` GPUs, or all of them if `count` is -1.
+    pub fn gpus(count: i64) -> Self {
+        DeviceRequest {
+            Count: count,
+            Capabilities: vec![vec!["gpu".to_owned()]],
+            ..DeviceRequest::default()
+        }
+    }
+
+    /// Request the GPUs with the specified IDs or indices.
+    pub fn gpu_ids<S: AsRef<str>>(ids: &[S]) -> Self {
+        DeviceRequest {
+            DeviceIDs: ids.iter().map(|id| id.as_ref().to_owned()).collect(),
+            Capabilities: vec![vec!["gpu".to_owned()]],
+            ..DeviceRequest::default()
+        }
+    }
+}
+
+/// A host device made available inside a container.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct DeviceMapping {
+    pub PathOnHost: String,
+    pub PathInContainer: String,
+    /// Some combination of `r`, `w` and `m`.
+    pub CgroupPermissions: String,
+}
+
+/// What Docker should do when a container exits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "RawRestartPolicy", into = "RawRestartPolicy")]
+pub enum RestartPolicy {
+    /// Never restart the container.
+    No,
+    /// Always restart the container.
+    Always,
+    /// Restart the container if it exits with a non-zero status, at most
+    /// `max_retries` times.  A `max_retries` of 0 means no limit.
+    OnFailure { max_retries: u64 },
+    /// Always restart the container, unless it was explicitly stopped.
+    UnlessStopped,
+}
+
+/// The wire format of `RestartPolicy`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+struct RawRestartPolicy {
+    Name: String,
+    #[serde(default)]
+    MaximumRetryCount: u64,
+}
+
+impl From<RawRestartPolicy> for RestartPolicy {
+    fn from(raw: RawRestartPolicy) -> Self {
+        match raw.Name.as_str() {
+            "always" => RestartPolicy::Always,
+            "on-failure" => RestartPolicy::OnFailure {
+                max_retries: raw.MaximumRetryCount,
+            },
+            "unless-stopped" => RestartPolicy::UnlessStopped,
+            // Docker uses "" as well as "no".
+            _ => RestartPolicy::No,
+        }
+    }
+}
+
+impl From<RestartPolicy> for RawRestartPolicy {
+    fn from(policy: RestartPolicy) -> Self {
+        let (name, max_retries) = match policy {
+            RestartPolicy::No => ("no", 0),
+            RestartPolicy::Always => ("always", 0),
+            RestartPolicy::OnFailure { max_retries } => ("on-failure", max_retries),
+            RestartPolicy::UnlessStopped => ("unless-stopped", 0),
+        };
+        RawRestartPolicy {
+            Name: name.to_owned(),
+            MaximumRetryCount: max_retries,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,11 +205,15 @@ pub struct ContainerInfo {
     pub AppArmorProfile: String,
     pub Args: Vec<String>,
     pub Config: Config,
+    #[cfg(not(feature = "chrono"))]
     pub Created: String,
+    #[cfg(feature = "chrono")]
+    #[serde(with = "timestamps::rfc3339")]
+    pub Created: DateTime<Utc>,
     pub Driver: String,
     // ExecIDs
     // GraphDriver
-    // HostConfig
+    pub HostConfig: HostConfig,
     pub HostnamePath: String,
     pub HostsPath: String,
     pub Id: String,
@@ -93,8 +263,15 @@ pub struct Config {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(non_snake_case)]
 pub struct Mount {
-    // Name (optional)
-    // Driver (optional)
+    /// The kind of mount, such as "bind" or "volume".
+    #[serde(default)]
+    pub Type: Option<String>,
+    /// The name of the volume, for volume mounts.
+    #[serde(default)]
+    pub Name: Option<String>,
+    /// The volume driver, for volume mounts.
+    #[serde(default)]
+    pub Driver: Option<String>,
     pub Source: String,
     pub Destination: String,
     pub Mode: String,
@@ -105,19 +282,36 @@ pub struct Mount {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(non_snake_case)]
 pub struct NetworkSettings {
+    #[serde(default)]
     pub Bridge: String,
+    #[serde(default)]
     pub EndpointID: String,
+    #[serde(default)]
     pub Gateway: String,
+    #[serde(default)]
     pub GlobalIPv6Address: String,
+    #[serde(default)]
     pub GlobalIPv6PrefixLen: u32,
+    #[serde(default)]
     pub HairpinMode: bool,
+    #[serde(default)]
     pub IPAddress: String,
+    #[serde(default)]
     pub IPPrefixLen: u32,
+    #[serde(default)]
     pub IPv6Gateway: String,
+    #[serde(default)]
     pub LinkLocalIPv6Address: String,
+    #[serde(default)]
     pub LinkLocalIPv6PrefixLen: u32,
+    #[serde(default)]
     pub MacAddress: String,
-    pub Networks: HashMap<String, Network>,
+    /// The networks this container is attached to, by name.  The
+    /// top-level address fields above only describe the default `bridge`
+    /// network, so look here to find a container's address on any other
+    /// network.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Networks: HashMap<String, EndpointSettings>,
     pub Ports: Option<HashMap<String, Option<Vec<PortMapping>>>>,
     pub SandboxID: String,
     pub SandboxKey: String,
@@ -126,21 +320,81 @@ pub struct NetworkSettings {
     //pub SecondaryIPv6Addresses: ,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl NetworkSettings {
+    /// The container's IPv4 address on `network`, without the prefix
+    /// length.
+    pub fn ip_address(&self, network: &str) -> Option<&str> {
+        self.Networks
+            .get(network)
+            .map(|endpoint| endpoint.IPAddress.as_str())
+            .filter(|address| !address.is_empty())
+    }
+
+    /// The container's global IPv6 address on `network`, if the network
+    /// has IPv6 enabled.
+    pub fn ipv6_address(&self, network: &str) -> Option<&str> {
+        self.Networks
+            .get(network)
+            .map(|endpoint| endpoint.GlobalIPv6Address.as_str())
+            .filter(|address| !address.is_empty())
+    }
+}
+
+/// How a container is attached to a network.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[allow(non_snake_case)]
-pub struct Network {
-    pub Aliases: Option<Vec<String>>,
+pub struct EndpointSettings {
+    /// Static addresses requested when the container was connected.
+    #[serde(default)]
+    pub IPAMConfig: Option<EndpointIpamConfig>,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Links: Vec<String>,
+    /// Other names this container can be reached by on the network.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Aliases: Vec<String>,
+    #[serde(default)]
+    pub NetworkID: String,
+    #[serde(default)]
     pub EndpointID: String,
+    #[serde(default)]
     pub Gateway: String,
-    pub GlobalIPv6Address: String,
-    pub GlobalIPv6PrefixLen: u32,
-    //pub IPAMConfig: ,
+    #[serde(default)]
     pub IPAddress: String,
+    #[serde(default)]
     pub IPPrefixLen: u32,
+    #[serde(default)]
     pub IPv6Gateway: String,
-    //pub Links:
+    #[serde(default)]
+    pub GlobalIPv6Address: String,
+    #[serde(default)]
+    pub GlobalIPv6PrefixLen: u32,
+    #[serde(default)]
     pub MacAddress: String,
-    pub NetworkID: String,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub DriverOpts: HashMap<String, String>,
+}
+
+/// Static addresses for a container on a network.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct EndpointIpamConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub IPv4Address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub IPv6Address: Option<String>,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub LinkLocalIPs: Vec<String>,
+}
+
+impl EndpointIpamConfig {
+    /// Were no addresses requested?
+    pub(crate) fn is_empty(&self) -> bool {
+        self.IPv4Address.is_none() && self.IPv6Address.is_none() && self.LinkLocalIPs.is_empty()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -153,7 +407,7 @@ pub struct PortMapping {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(non_snake_case)]
 pub struct State {
-    pub Status: String,
+    pub Status: ContainerStatus,
     pub Running: bool,
     pub Paused: bool,
     pub Restarting: bool,
@@ -164,8 +418,202 @@ pub struct State {
     pub Pid: i64,
     pub ExitCode: i64,
     pub Error: String,
+    #[cfg(not(feature = "chrono"))]
     pub StartedAt: String,
+    #[cfg(not(feature = "chrono"))]
     pub FinishedAt: String,
+    /// When this container was last started, or `None` if it never was.
+    #[cfg(feature = "chrono")]
+    #[serde(with = "timestamps::optional_rfc3339")]
+    pub StartedAt: Option<DateTime<Utc>>,
+    /// When this container last exited, or `None` if it hasn't.
+    #[cfg(feature = "chrono")]
+    #[serde(with = "timestamps::optional_rfc3339")]
+    pub FinishedAt: Option<DateTime<Utc>>,
+    /// Only present if the container has a healthcheck.
+    pub Health: Option<Health>,
+}
+
+/// The lifecycle state of a container.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum ContainerStatus {
+    Created,
+    Running,
+    Paused,
+    Restarting,
+    Removing,
+    Exited,
+    Dead,
+    /// A state we don't know about.
+    Unknown(String),
+}
+
+impl ContainerStatus {
+    /// The name Docker uses for this state.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ContainerStatus::Created => "created",
+            ContainerStatus::Running => "running",
+            ContainerStatus::Paused => "paused",
+            ContainerStatus::Restarting => "restarting",
+            ContainerStatus::Removing => "removing",
+            ContainerStatus::Exited => "exited",
+            ContainerStatus::Dead => "dead",
+            ContainerStatus::Unknown(other) => other,
+        }
+    }
+}
+
+impl From<String> for ContainerStatus {
+    fn from(status: String) -> Self {
+        match status.as_str() {
+            "created" => ContainerStatus::Created,
+            "running" => ContainerStatus::Running,
+            "paused" => ContainerStatus::Paused,
+            "restarting" => ContainerStatus::Restarting,
+            "removing" => ContainerStatus::Removing,
+            "exited" => ContainerStatus::Exited,
+            "dead" => ContainerStatus::Dead,
+            _ => ContainerStatus::Unknown(status),
+        }
+    }
+}
+
+impl From<ContainerStatus> for String {
+    fn from(status: ContainerStatus) -> Self {
+        match status {
+            ContainerStatus::Unknown(other) => other,
+            known => known.as_str().to_owned(),
+        }
+    }
+}
+
+impl std::fmt::Display for ContainerStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The results of a container's healthcheck.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct Health {
+    pub Status: HealthStatus,
+    pub FailingStreak: u64,
+    /// The most recent healthcheck results.
+    pub Log: Option<Vec<HealthcheckResult>>,
+}
+
+/// The health of a container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    /// The container has no healthcheck.
+    None,
+    /// The container is still starting up.
+    Starting,
+    Healthy,
+    Unhealthy,
+    /// A status we don't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+impl HealthStatus {
+    /// The name Docker uses for this status, or `None` for a status we
+    /// don't know the name of.
+    pub fn as_str(self) -> Option<&'static str> {
+        match self {
+            HealthStatus::None => Some("none"),
+            HealthStatus::Starting => Some("starting"),
+            HealthStatus::Healthy => Some("healthy"),
+            HealthStatus::Unhealthy => Some("unhealthy"),
+            HealthStatus::Unknown => None,
+        }
+    }
+}
+
+/// The result of running a healthcheck once.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct HealthcheckResult {
+    #[cfg(not(feature = "chrono"))]
+    pub Start: String,
+    #[cfg(not(feature = "chrono"))]
+    pub End: String,
+    #[cfg(feature = "chrono")]
+    #[serde(with = "timestamps::rfc3339")]
+    pub Start: DateTime<Utc>,
+    #[cfg(feature = "chrono")]
+    #[serde(with = "timestamps::rfc3339")]
+    pub End: DateTime<Utc>,
+    pub ExitCode: i64,
+    pub Output: String,
+}
+
+/// The response to `Docker::create_container`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct CreateContainerResponse {
+    pub Id: String,
+    pub Warnings: Option<Vec<String>>,
+}
+
+/// The response to `Docker::update_container`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct UpdateContainerResponse {
+    pub Warnings: Option<Vec<String>>,
+}
+
+/// The response to `Docker::wait_container`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ContainerWaitResponse {
+    /// The exit code of the container.
+    pub StatusCode: i64,
+    /// Any error which occurred while waiting.
+    pub Error: Option<ContainerWaitError>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ContainerWaitError {
+    pub Message: String,
+}
+
+/// The response to `Docker::prune_containers`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct PruneContainersResponse {
+    pub ContainersDeleted: Option<Vec<String>>,
+    pub SpaceReclaimed: u64,
+}
+
+/// Information about a file or directory inside a container, as returned
+/// by the archive endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathStat {
+    pub name: String,
+    pub size: i64,
+    /// The Go `os.FileMode` of the path, which includes type bits like
+    /// `1 << 31` for directories as well as the Unix permissions.
+    pub mode: u32,
+    pub mtime: String,
+    #[serde(rename = "linkTarget")]
+    pub link_target: String,
+}
+
+/// The result of `Docker::run`.
+#[derive(Debug, Clone)]
+pub struct RunResult {
+    /// The ID of the container, which will no longer exist if
+    /// `RunOptions::remove` was specified.
+    pub container_id: String,
+    pub exit_code: i64,
+    /// Everything the container wrote to stdout and stderr.
+    pub logs: Vec<LogLine>,
 }
 
 impl std::fmt::Display for Container {
@@ -174,6 +622,18 @@ impl std::fmt::Display for Container {
     }
 }
 
+impl AsRef<str> for Container {
+    fn as_ref(&self) -> &str {
+        &self.Id
+    }
+}
+
+impl AsRef<str> for ContainerInfo {
+    fn as_ref(&self) -> &str {
+        &self.Id
+    }
+}
+
 impl std::fmt::Display for ContainerInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
         write!(f, "{}", self.Id)
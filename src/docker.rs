@@ -1,23 +1,66 @@
-use hyper::{client::Client, Body, Request, Response, Uri};
-use std::{convert::TryFrom, env};
-use tokio::stream::StreamExt;
+use bytes::Bytes;
+use error_chain::ChainedError;
+use futures::{channel::mpsc, executor, stream, SinkExt, Stream, TryStreamExt};
+use hyper::{client::Client, header, upgrade::Upgraded, Body, Request, Response, StatusCode, Uri};
+use log::warn;
+use std::{
+    convert::TryFrom,
+    env, io,
+    path::Path,
+    time::{Duration, Instant},
+};
+use tokio::{
+    fs::File,
+    io::{AsyncWrite, AsyncWriteExt},
+    stream::StreamExt,
+    task, time,
+};
 
+use crate::archive;
+use crate::attach::{self, AttachReader, AttachWriter};
+use crate::auth::{AuthResponse, RegistryAuth};
+#[cfg(feature = "checkpoint")]
+use crate::checkpoint::Checkpoint;
+use crate::config::CliConfig;
 use crate::connector::Connector;
-use crate::container::{Container, ContainerInfo};
+use crate::container::{
+    Container, ContainerInfo, ContainerWaitResponse, CreateContainerResponse, HealthStatus,
+    PathStat, PruneContainersResponse, RunResult, UpdateContainerResponse,
+};
 use crate::errors::*;
+use crate::event::EventStream;
+use crate::exec::{CreateExecResponse, ExecConfig, ExecInfo, ExecResult, ExecStartConfig};
 use crate::filesystem::FilesystemChange;
-use crate::image::Image;
+use crate::image::{Image, ImageInfo, RemovedImage, SearchResult};
+use crate::json_lines::decode_json_lines;
+use crate::logs::{
+    decode_log_stream, decode_service_log_stream, LogLine, LogStream, ServiceLogLine,
+};
+use crate::network::{CreateNetworkResponse, Network, PruneNetworksResponse};
+use crate::node::{Node, NodeAvailability, NodeSpec};
 use crate::options::*;
 use crate::process::{Process, Top};
-use crate::system::SystemInfo;
+use crate::progress::{decode_progress, ProgressEvent};
+use crate::reference::ImageReference;
+use crate::secret::{CreateSecretResponse, Secret, SecretSpec};
+use crate::service::{
+    CreateServiceResponse, Service, ServiceSpec, UpdateServiceResponse, UpdateStatus, UpdateStep,
+    UpdateWatch,
+};
+use crate::session;
+use crate::stats::Stats;
+use crate::swarm::{ObjectVersion, Swarm, SwarmSpec, UnlockKey};
+use crate::system::{DiskUsage, PingInfo, SystemInfo};
+use crate::task::Task;
 use crate::version::Version;
+use crate::volume::{PruneVolumesResponse, Volume, VolumeList};
 
-use serde::de::DeserializeOwned;
-use serde_json;
+use serde::{de::DeserializeOwned, Serialize};
+use url::form_urlencoded;
 
 /// The default `DOCKER_HOST` address that we will try to connect to.
 #[cfg(unix)]
-pub const DEFAULT_DOCKER_HOST: &'static str = "unix:///var/run/docker.sock";
+pub const DEFAULT_DOCKER_HOST: &str = "unix:///var/run/docker.sock";
 
 /// The default `DOCKER_HOST` address that we will try to connect to.
 ///
@@ -25,7 +68,16 @@ pub const DEFAULT_DOCKER_HOST: &'static str = "unix:///var/run/docker.sock";
 /// Windows, but we don't support Windows pipes yet.  However, the TCP port
 /// is still available.
 #[cfg(windows)]
-pub const DEFAULT_DOCKER_HOST: &'static str = "tcp://localhost:2375";
+pub const DEFAULT_DOCKER_HOST: &str = "tcp://localhost:2375";
+
+/// How often `wait_for_healthy` checks on a container.
+const HEALTH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How often `exec_output` checks whether a command has finished.
+const EXEC_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How often `watch_service_update` checks on a rolling update.
+const UPDATE_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 /// Used to build URLs.
 enum UrlBuilder {
@@ -64,9 +116,9 @@ impl Docker {
         // Dispatch to the correct connection function.
         let mkerr = || ErrorKind::CouldNotConnect(host.clone());
         if host.starts_with("unix://") {
-            Docker::connect_with_unix(&host).chain_err(&mkerr)
+            Docker::connect_with_unix(&host).chain_err(mkerr)
         } else if host.starts_with("tcp://") {
-            Docker::connect_with_ssl(&host).chain_err(&mkerr)
+            Docker::connect_with_ssl(&host).chain_err(mkerr)
         } else {
             Err(ErrorKind::UnsupportedScheme(host.clone()).into())
         }
@@ -112,16 +164,33 @@ impl Docker {
     }
 
     fn build_empty_get_request(&self, request_url: &Uri) -> Result<Request<Body>> {
-        Ok(Request::get(request_url)
+        Request::get(request_url)
+            .body(Body::empty())
+            .chain_err(|| "error building request")
+    }
+
+    fn build_post_request(&self, request_url: &Uri, body: Body) -> Result<Request<Body>> {
+        Request::post(request_url)
+            .body(body)
+            .chain_err(|| "error building request")
+    }
+
+    fn build_delete_request(&self, request_url: &Uri) -> Result<Request<Body>> {
+        Request::delete(request_url)
             .body(Body::empty())
-            .chain_err(|| "error building request")?)
+            .chain_err(|| "error building request")
     }
 
-    /*
-    fn build_post_request(&self, request_url: &Uri) -> Builder {
+    fn build_json_post_request<B>(&self, request_url: &Uri, body: &B) -> Result<Request<Body>>
+    where
+        B: Serialize,
+    {
+        let json = serde_json::to_vec(body).chain_err(|| "error serializing request")?;
         Request::post(request_url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(json))
+            .chain_err(|| "error building request")
     }
-    */
 
     async fn start_request(&self, request: Request<Body>) -> Result<Response<Body>> {
         let response = self.client.request(request).await?;
@@ -143,12 +212,64 @@ impl Docker {
         Ok(data)
     }
 
-    /*
-    fn arrayify(&self, s: &[u8]) -> String {
-        let wrapped = format!("[{}]", s);
-        wrapped.clone().replace(b"}\r\n{", b"}{").replace(b"}{", b"},{")
+    /// `GET` a URL and return the response body as a stream of bytes.
+    async fn get_body_stream(
+        &self,
+        url: &str,
+    ) -> Result<impl Stream<Item = Result<Bytes>> + Send + Unpin> {
+        let request_url = self.get_url(url)?;
+        let request = self.build_empty_get_request(&request_url)?;
+        let response = self.start_request(request).await?;
+        Ok(response.into_body().map_err(Error::from))
+    }
+
+    /// Send a request asking Docker to hijack the connection and switch to
+    /// `protocol`, and return the raw bidirectional stream once the upgrade
+    /// succeeds, along with the `Content-Type` Docker says it will send, if
+    /// any.
+    async fn upgrade_request(
+        &self,
+        request_url: &Uri,
+        protocol: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<(Upgraded, Option<String>)> {
+        let mut request = Request::post(request_url)
+            .header(header::CONNECTION, "Upgrade")
+            .header(header::UPGRADE, protocol);
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        let request = request
+            .body(Body::empty())
+            .chain_err(|| "error building request")?;
+        let response = self.client.request(request).await?;
+        let status = response.status();
+        if status != StatusCode::SWITCHING_PROTOCOLS {
+            return Err(format!("HTTP upgrade failed: {}", status).into());
+        }
+        let content_type = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_owned);
+        let upgraded = response.into_body().on_upgrade().await?;
+        Ok((upgraded, content_type))
+    }
+
+    /// `POST` to a URL which performs an action and returns no useful body.
+    /// Docker returns `304 Not Modified` when a container is already in the
+    /// requested state, which we treat as success, just like the CLI.
+    async fn post_action(&self, url: &str) -> Result<()> {
+        let request_url = self.get_url(url)?;
+        let request = self.build_post_request(&request_url, Body::empty())?;
+        let response = self.client.request(request).await?;
+        let status = response.status();
+        if status.is_success() || status == StatusCode::NOT_MODIFIED {
+            Ok(())
+        } else {
+            Err(format!("HTTP request failed: {}", status).into())
+        }
     }
-    */
 
     /// `GET` a URL and decode it.
     async fn decode_url<'a, T>(&'a self, type_name: &'static str, url: &'a str) -> Result<T>
@@ -157,6 +278,30 @@ impl Docker {
     {
         let request_url = self.get_url(url)?;
         let request = self.build_empty_get_request(&request_url)?;
+        self.decode_request(type_name, request).await
+    }
+
+    /// `POST` a JSON body to a URL and decode the response.
+    async fn decode_json_post<'a, B, T>(
+        &'a self,
+        type_name: &'static str,
+        url: &'a str,
+        body: &'a B,
+    ) -> Result<T>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        let request_url = self.get_url(url)?;
+        let request = self.build_json_post_request(&request_url, body)?;
+        self.decode_request(type_name, request).await
+    }
+
+    /// Execute a request and decode the JSON it returns.
+    async fn decode_request<T>(&self, type_name: &'static str, request: Request<Body>) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
         let body = self.execute_request(request).await?;
         let info = serde_json::from_slice::<T>(&body).chain_err(|| {
             ErrorKind::ParseError(type_name, String::from_utf8_lossy(&body[..]).into_owned())
@@ -169,128 +314,1400 @@ impl Docker {
         self.decode_url("Container", &url).await
     }
 
-    pub async fn processes(&self, container: &Container) -> Result<Vec<Process>> {
-        let url = format!("/containers/{}/top", container.Id);
-        let top: Top = self.decode_url("Top", &url).await?;
+    /// Create a new container.  The container will not be started.
+    pub async fn create_container(
+        &self,
+        opts: ContainerCreateOptions,
+    ) -> Result<CreateContainerResponse> {
+        let url = format!("/containers/create?{}", opts.to_url_params());
+        self.decode_json_post("CreateContainerResponse", &url, &opts)
+            .await
+    }
 
-        let mut processes: Vec<Process> = Vec::new();
-        let mut process_iter = top.Processes.iter();
-        loop {
-            let process = match process_iter.next() {
-                Some(process) => process,
-                None => {
-                    break;
-                }
-            };
+    /// Start a container.
+    pub async fn start_container(&self, id: impl AsRef<str>) -> Result<()> {
+        let url = format!("/containers/{}/start", id.as_ref());
+        self.post_action(&url).await
+    }
 
-            let mut p = Process {
-                user: String::new(),
-                pid: String::new(),
-                cpu: None,
-                memory: None,
-                vsz: None,
-                rss: None,
-                tty: None,
-                stat: None,
-                start: None,
-                time: None,
-                command: String::new(),
-            };
+    /// Stop a container, waiting up to `timeout` before killing it.  If
+    /// `timeout` is `None`, use the daemon's default.
+    pub async fn stop_container(
+        &self,
+        id: impl AsRef<str>,
+        timeout: Option<Duration>,
+    ) -> Result<()> {
+        let url = format!(
+            "/containers/{}/stop?{}",
+            id.as_ref(),
+            timeout_params(timeout)
+        );
+        self.post_action(&url).await
+    }
 
-            let mut value_iter = process.iter();
-            let mut i: usize = 0;
-            loop {
-                let value = match value_iter.next() {
-                    Some(value) => value,
-                    None => {
-                        break;
+    /// Restart a container, waiting up to `timeout` for it to stop before
+    /// killing it.  If `timeout` is `None`, use the daemon's default.
+    pub async fn restart_container(
+        &self,
+        id: impl AsRef<str>,
+        timeout: Option<Duration>,
+    ) -> Result<()> {
+        let url = format!(
+            "/containers/{}/restart?{}",
+            id.as_ref(),
+            timeout_params(timeout)
+        );
+        self.post_action(&url).await
+    }
+
+    /// Send a signal to a container.  If `signal` is `None`, send
+    /// `SIGKILL`.
+    pub async fn kill_container(&self, id: impl AsRef<str>, signal: Option<Signal>) -> Result<()> {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        if let Some(signal) = signal {
+            params.append_pair("signal", &signal.to_string());
+        }
+        let url = format!("/containers/{}/kill?{}", id.as_ref(), params.finish());
+        self.post_action(&url).await
+    }
+
+    /// Pause all processes in a container.
+    pub async fn pause_container(&self, id: impl AsRef<str>) -> Result<()> {
+        let url = format!("/containers/{}/pause", id.as_ref());
+        self.post_action(&url).await
+    }
+
+    /// Resume a paused container.
+    pub async fn unpause_container(&self, id: impl AsRef<str>) -> Result<()> {
+        let url = format!("/containers/{}/unpause", id.as_ref());
+        self.post_action(&url).await
+    }
+
+    /// Give a container a new name.
+    pub async fn rename_container(&self, id: impl AsRef<str>, new_name: &str) -> Result<()> {
+        let params = form_urlencoded::Serializer::new(String::new())
+            .append_pair("name", new_name)
+            .finish();
+        let url = format!("/containers/{}/rename?{}", id.as_ref(), params);
+        self.post_action(&url).await
+    }
+
+    /// Change the resource limits and restart policy of a container
+    /// without restarting it.
+    pub async fn update_container(
+        &self,
+        id: impl AsRef<str>,
+        opts: UpdateContainerOptions,
+    ) -> Result<UpdateContainerResponse> {
+        let url = format!("/containers/{}/update", id.as_ref());
+        self.decode_json_post("UpdateContainerResponse", &url, &opts)
+            .await
+    }
+
+    /// Block until a container satisfies `condition`, and return its exit
+    /// status.
+    pub async fn wait_container(
+        &self,
+        id: impl AsRef<str>,
+        condition: WaitCondition,
+    ) -> Result<ContainerWaitResponse> {
+        let url = format!(
+            "/containers/{}/wait?{}",
+            id.as_ref(),
+            condition.to_url_params()
+        );
+        let request_url = self.get_url(&url)?;
+        let request = self.build_post_request(&request_url, Body::empty())?;
+        self.decode_request("ContainerWaitResponse", request).await
+    }
+
+    /// Remove a container.
+    pub async fn remove_container(
+        &self,
+        id: impl AsRef<str>,
+        opts: RemoveContainerOptions,
+    ) -> Result<()> {
+        let url = format!("/containers/{}?{}", id.as_ref(), opts.to_url_params());
+        let request_url = self.get_url(&url)?;
+        let request = self.build_delete_request(&request_url)?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    /// Remove all stopped containers matching `opts`.
+    pub async fn prune_containers(
+        &self,
+        opts: PruneContainersOptions,
+    ) -> Result<PruneContainersResponse> {
+        let url = format!("/containers/prune?{}", opts.to_url_params());
+        let request_url = self.get_url(&url)?;
+        let request = self.build_post_request(&request_url, Body::empty())?;
+        self.decode_request("PruneContainersResponse", request)
+            .await
+    }
+
+    /// Fetch the logs of a container, separating stdout and stderr.  If
+    /// `opts` includes `follow`, this will not return until the container
+    /// exits, so you probably want `logs_stream` instead.
+    pub async fn logs(&self, id: impl AsRef<str>, opts: LogOptions) -> Result<Vec<LogLine>> {
+        self.logs_stream(id, opts).await?.try_collect().await
+    }
+
+    /// Stream the logs of a container as they arrive.  Combine this with
+    /// `LogOptions::follow` to tail a running container.
+    pub async fn logs_stream(
+        &self,
+        id: impl AsRef<str>,
+        opts: LogOptions,
+    ) -> Result<impl Stream<Item = Result<LogLine>> + Send + Unpin> {
+        let url = format!("/containers/{}/logs?{}", id.as_ref(), opts.to_url_params());
+        let request_url = self.get_url(&url)?;
+        let request = self.build_empty_get_request(&request_url)?;
+        let response = self.start_request(request).await?;
+        Ok(decode_log_stream(response, opts.has_timestamps()))
+    }
+
+    /// Attach to a running container, returning separate handles for
+    /// reading its output and writing to its stdin.  Unless
+    /// `AttachOptions::detach_keys` is used, this honors `detachKeys` in
+    /// the `docker` client's `config.json`, falling back to Docker's default
+    /// keys if that file can't be read.
+    pub async fn attach_container(
+        &self,
+        id: impl AsRef<str>,
+        mut opts: AttachOptions,
+    ) -> Result<(AttachReader, AttachWriter)> {
+        if !opts.has_detach_keys() {
+            match CliConfig::load().await {
+                Ok(config) => {
+                    if let Some(keys) = config.detach_keys {
+                        opts = opts.detach_keys(&keys);
                     }
-                };
-                let key = &top.Titles[i];
-                match key.as_ref() {
-                    "UID" => p.user = value.clone(),
-                    "USER" => p.user = value.clone(),
-                    "PID" => p.pid = value.clone(),
-                    "%CPU" => p.cpu = Some(value.clone()),
-                    "%MEM" => p.memory = Some(value.clone()),
-                    "VSZ" => p.vsz = Some(value.clone()),
-                    "RSS" => p.rss = Some(value.clone()),
-                    "TTY" => p.tty = Some(value.clone()),
-                    "STAT" => p.stat = Some(value.clone()),
-                    "START" => p.start = Some(value.clone()),
-                    "STIME" => p.start = Some(value.clone()),
-                    "TIME" => p.time = Some(value.clone()),
-                    "CMD" => p.command = value.clone(),
-                    "COMMAND" => p.command = value.clone(),
-                    _ => {}
                 }
+                Err(err) => warn!("could not read detach keys from docker config: {}", err),
+            }
+        }
+        let url = format!(
+            "/containers/{}/attach?{}",
+            id.as_ref(),
+            opts.to_url_params()
+        );
+        let request_url = self.get_url(&url)?;
+        let (upgraded, content_type) = self.upgrade_request(&request_url, "tcp", &[]).await?;
+        Ok(attach::split(upgraded, content_type))
+    }
+
+    /// Resize the TTY of a container, in character cells.
+    pub async fn resize_container_tty(
+        &self,
+        id: impl AsRef<str>,
+        width: u16,
+        height: u16,
+    ) -> Result<()> {
+        let params = form_urlencoded::Serializer::new(String::new())
+            .append_pair("h", &height.to_string())
+            .append_pair("w", &width.to_string())
+            .finish();
+        let url = format!("/containers/{}/resize?{}", id.as_ref(), params);
+        self.post_action(&url).await
+    }
+
+    /// Download `path` from a container as a tar archive.  Returns
+    /// information about `path` and a stream of tar data.
+    pub async fn get_archive(
+        &self,
+        id: impl AsRef<str>,
+        path: &str,
+    ) -> Result<(PathStat, impl Stream<Item = Result<Bytes>> + Send + Unpin)> {
+        let params = form_urlencoded::Serializer::new(String::new())
+            .append_pair("path", path)
+            .finish();
+        let url = format!("/containers/{}/archive?{}", id.as_ref(), params);
+        let request_url = self.get_url(&url)?;
+        let request = self.build_empty_get_request(&request_url)?;
+        let response = self.start_request(request).await?;
+        let stat = decode_path_stat(&response)?;
+        Ok((stat, response.into_body().map_err(Error::from)))
+    }
+
+    /// Get information about `path` inside a container without downloading
+    /// it.
+    pub async fn stat_archive(&self, id: impl AsRef<str>, path: &str) -> Result<PathStat> {
+        let params = form_urlencoded::Serializer::new(String::new())
+            .append_pair("path", path)
+            .finish();
+        let url = format!("/containers/{}/archive?{}", id.as_ref(), params);
+        let request_url = self.get_url(&url)?;
+        let request = Request::head(&request_url)
+            .body(Body::empty())
+            .chain_err(|| "error building request")?;
+        let response = self.start_request(request).await?;
+        decode_path_stat(&response)
+    }
+
+    /// Upload a tar archive and extract it into the directory `path` inside
+    /// a container.  The archive is streamed, so it may be arbitrarily
+    /// large.
+    pub async fn put_archive<S>(
+        &self,
+        id: impl AsRef<str>,
+        path: &str,
+        body: S,
+        opts: PutArchiveOptions,
+    ) -> Result<()>
+    where
+        S: Stream<Item = io::Result<Bytes>> + Send + 'static,
+    {
+        let params = query_params(&[("path", path)], &opts.to_url_params());
+        let url = format!("/containers/{}/archive?{}", id.as_ref(), params);
+        let request_url = self.get_url(&url)?;
+        let request = Request::put(&request_url)
+            .header(header::CONTENT_TYPE, "application/x-tar")
+            .body(Body::wrap_stream(body))
+            .chain_err(|| "error building request")?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    /// Copy the file or directory at `local` to `path` inside a container,
+    /// like `docker cp`.  The parent of `path` must already exist.  The
+    /// archive is built in memory, so this is intended for configuration
+    /// files and other small inputs.
+    pub async fn copy_file_to(
+        &self,
+        id: impl AsRef<str>,
+        local: impl AsRef<Path>,
+        path: &str,
+    ) -> Result<()> {
+        let (dir, name) = archive::split_container_path(path)?;
+        let local = local.as_ref().to_owned();
+        let name = name.to_owned();
+        let tar = task::spawn_blocking(move || archive::tar_path(&local, &name))
+            .await
+            .chain_err(|| "could not build archive")??;
+        let body = stream::once(async { Ok(Bytes::from(tar)) });
+        self.put_archive(id, dir, body, PutArchiveOptions::default())
+            .await
+    }
 
-                i = i + 1;
+    /// Copy the file or directory at `path` inside a container to `local`,
+    /// like `docker cp`.  The archive is read into memory, so this is
+    /// intended for small outputs.
+    pub async fn copy_file_from(
+        &self,
+        id: impl AsRef<str>,
+        path: &str,
+        local: impl AsRef<Path>,
+    ) -> Result<()> {
+        archive::split_container_path(path)?;
+        let (_, mut stream) = self.get_archive(id, path).await?;
+        let mut tar = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            tar.extend_from_slice(&chunk?);
+        }
+        let local = local.as_ref().to_owned();
+        task::spawn_blocking(move || archive::untar_to(&tar, &local))
+            .await
+            .chain_err(|| "could not extract archive")?
+    }
+
+    /// Create a container, start it, wait for it to exit, and collect its
+    /// logs, like `docker run`.
+    pub async fn run(&self, opts: RunOptions) -> Result<RunResult> {
+        let (create, remove) = opts.into_parts();
+        let created = self.create_container(create).await?;
+        let result = self.run_created(&created.Id).await;
+        if remove {
+            let opts = RemoveContainerOptions::default().force().volumes();
+            let removed = self.remove_container(&created.Id, opts).await;
+            // Report any error from running the container first.
+            let result = result?;
+            removed?;
+            Ok(result)
+        } else {
+            result
+        }
+    }
+
+    /// The part of `run` that happens after creating the container.
+    async fn run_created(&self, id: &str) -> Result<RunResult> {
+        self.start_container(id).await?;
+        let status = self.wait_container(id, WaitCondition::NotRunning).await?;
+        if let Some(err) = status.Error {
+            return Err(format!("error waiting for container {}: {}", id, err.Message).into());
+        }
+        let logs = self.logs(id, LogOptions::default()).await?;
+        Ok(RunResult {
+            container_id: id.to_owned(),
+            exit_code: status.StatusCode,
+            logs,
+        })
+    }
+
+    /// Run `cmd` inside a running container, wait for it to finish, and
+    /// return its exit code and output.
+    pub async fn exec_output<S: AsRef<str>>(
+        &self,
+        id: impl AsRef<str>,
+        cmd: &[S],
+    ) -> Result<ExecResult> {
+        let config = ExecConfig {
+            AttachStdout: true,
+            AttachStderr: true,
+            Cmd: cmd.iter().map(|s| s.as_ref().to_owned()).collect(),
+        };
+        let url = format!("/containers/{}/exec", id.as_ref());
+        let exec: CreateExecResponse = self
+            .decode_json_post("CreateExecResponse", &url, &config)
+            .await?;
+
+        // Run the command and collect its output.
+        let start = ExecStartConfig {
+            Detach: false,
+            Tty: false,
+        };
+        let request_url = self.get_url(&format!("/exec/{}/start", exec.Id))?;
+        let request = self.build_json_post_request(&request_url, &start)?;
+        let response = self.start_request(request).await?;
+        let mut stdout = vec![];
+        let mut stderr = vec![];
+        let mut lines = decode_log_stream(response, false);
+        while let Some(line) = lines.next().await {
+            let line = line?;
+            match line.stream {
+                LogStream::Stderr => stderr.extend(line.bytes),
+                _ => stdout.extend(line.bytes),
             }
-            processes.push(p);
         }
 
-        Ok(processes)
+        // Look up the exit code.  Docker may not have noticed that the
+        // command finished when the output ends, so wait until it has.
+        let info = loop {
+            let info = self.exec_info(&exec.Id).await?;
+            if !info.Running {
+                break info;
+            }
+            time::delay_for(EXEC_POLL_INTERVAL).await;
+        };
+        let exit_code = info
+            .ExitCode
+            .ok_or_else(|| format!("exec {} did not report an exit code", exec.Id))?;
+        Ok(ExecResult {
+            exit_code,
+            stdout,
+            stderr,
+        })
+    }
+
+    /// Get information about an exec instance.
+    pub async fn exec_info(&self, exec_id: &str) -> Result<ExecInfo> {
+        let url = format!("/exec/{}/json", exec_id);
+        self.decode_url("ExecInfo", &url).await
+    }
+
+    /// Checkpoint a running container.
+    #[cfg(feature = "checkpoint")]
+    pub async fn create_checkpoint(
+        &self,
+        id: impl AsRef<str>,
+        opts: CheckpointCreateOptions,
+    ) -> Result<()> {
+        let url = format!("/containers/{}/checkpoints", id.as_ref());
+        let request_url = self.get_url(&url)?;
+        let request = self.build_json_post_request(&request_url, &opts)?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    /// List the checkpoints of a container, optionally looking in
+    /// `checkpoint_dir` instead of the default location.
+    #[cfg(feature = "checkpoint")]
+    pub async fn list_checkpoints(
+        &self,
+        id: impl AsRef<str>,
+        checkpoint_dir: Option<&str>,
+    ) -> Result<Vec<Checkpoint>> {
+        let url = format!(
+            "/containers/{}/checkpoints?{}",
+            id.as_ref(),
+            checkpoint_dir_params(checkpoint_dir)
+        );
+        self.decode_url("Checkpoint", &url).await
+    }
+
+    /// Delete a checkpoint, optionally looking in `checkpoint_dir` instead
+    /// of the default location.
+    #[cfg(feature = "checkpoint")]
+    pub async fn delete_checkpoint(
+        &self,
+        id: impl AsRef<str>,
+        checkpoint_id: &str,
+        checkpoint_dir: Option<&str>,
+    ) -> Result<()> {
+        let url = format!(
+            "/containers/{}/checkpoints/{}?{}",
+            id.as_ref(),
+            checkpoint_id,
+            checkpoint_dir_params(checkpoint_dir)
+        );
+        let request_url = self.get_url(&url)?;
+        let request = self.build_delete_request(&request_url)?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    /// List the processes running inside a container.
+    pub async fn processes(
+        &self,
+        id: impl AsRef<str>,
+        opts: ProcessesOptions,
+    ) -> Result<Vec<Process>> {
+        let url = format!("/containers/{}/top?{}", id.as_ref(), opts.to_url_params());
+        let top: Top = self.decode_url("Top", &url).await?;
+
+        Ok(top
+            .Processes
+            .iter()
+            .map(|row| Process::from_row(&top.Titles, row))
+            .collect())
+    }
+
+    /// Stream resource usage statistics for a container.  By default, the
+    /// daemon sends a new sample roughly once per second.
+    pub async fn stats(
+        &self,
+        id: impl AsRef<str>,
+        opts: StatsOptions,
+    ) -> Result<impl Stream<Item = Result<Stats>> + Send + Unpin> {
+        let url = format!("/containers/{}/stats?{}", id.as_ref(), opts.to_url_params());
+        let request_url = self.get_url(&url)?;
+        let request = self.build_empty_get_request(&request_url)?;
+        let response = self.start_request(request).await?;
+        Ok(decode_json_lines("Stats", response.into_body()))
+    }
+
+    /// Fetch a single resource usage sample for a container.  This asks
+    /// the daemon not to wait for a second sample, so `precpu_stats` will be
+    /// empty.
+    pub async fn stats_once(&self, id: impl AsRef<str>) -> Result<Stats> {
+        let url = format!("/containers/{}/stats?stream=0&one-shot=1", id.as_ref());
+        self.decode_url("Stats", &url).await
+    }
+
+    /// Pull `image:tag` from a registry, streaming progress messages as
+    /// they arrive.  If Docker reports an error, the stream will return it
+    /// as an `Err`.
+    ///
+    /// If `tag` is empty, `image` is parsed as an `ImageReference`, so it
+    /// may include a tag or be pinned to a digest, as in
+    /// `"alpine@sha256:..."`.  Images without either are pulled as
+    /// `latest`.
+    pub async fn pull_image(
+        &self,
+        image: &str,
+        tag: &str,
+        opts: PullImageOptions,
+    ) -> Result<impl Stream<Item = Result<ProgressEvent>> + Send + Unpin> {
+        let (image, tag) = if tag.is_empty() {
+            let reference = ImageReference::parse(image)?;
+            (reference.name(), reference.tag_or_digest().to_owned())
+        } else {
+            (image.to_owned(), tag.to_owned())
+        };
+        let params = query_params(
+            &[("fromImage", &image), ("tag", &tag)],
+            &opts.to_url_params(),
+        );
+        let url = format!("/images/create?{}", params);
+        let request_url = self.get_url(&url)?;
+        let request = self.build_post_request(&request_url, Body::empty())?;
+        let response = self.start_request(request).await?;
+        Ok(decode_progress(response.into_body()))
+    }
+
+    /// Pull several images at once, with at most `concurrency` pulls
+    /// running at a time.  Each image is parsed as an `ImageReference`.
+    /// Progress messages from all the pulls are merged into a single
+    /// stream, labelled with the image they are about.  If one pull fails,
+    /// the others carry on.
+    pub fn pull_images<'a>(
+        &'a self,
+        images: &[&str],
+        concurrency: usize,
+    ) -> impl Stream<Item = (String, Result<ProgressEvent>)> + Send + 'a {
+        let images = images
+            .iter()
+            .map(|&image| image.to_owned())
+            .collect::<Vec<_>>();
+        // `tokio::stream::StreamExt` doesn't have `flatten_unordered`.
+        let pulls = futures::StreamExt::map(stream::iter(images), move |image| {
+            let label = image.clone();
+            let pull = async move {
+                self.pull_image(&image, "", PullImageOptions::default())
+                    .await
+            };
+            let progress = stream::once(pull)
+                .try_flatten()
+                .map(move |progress| (label.clone(), progress));
+            Box::pin(progress)
+        });
+        futures::StreamExt::flatten_unordered(pulls, concurrency.max(1))
+    }
+
+    /// Make sure `image` is available locally, pulling it if it's
+    /// missing, and return `true` if we pulled it.  If `pull_latest` is set,
+    /// images tagged `latest` (or with no tag) are always pulled, so that
+    /// they are kept up to date.  `image` is parsed as an
+    /// `ImageReference`.
+    pub async fn ensure_image(&self, image: &str, pull_latest: bool) -> Result<bool> {
+        let reference = ImageReference::parse(image)?;
+        let is_latest = reference.digest().is_none() && reference.tag_or_digest() == "latest";
+        if !(pull_latest && is_latest) && self.image_exists(image).await? {
+            return Ok(false);
+        }
+        let mut progress = self
+            .pull_image(image, "", PullImageOptions::default())
+            .await?;
+        while let Some(message) = progress.next().await {
+            message?;
+        }
+        Ok(true)
     }
 
-    /*
-    pub async fn stats(&self, container: &Container) -> Result<StatsReader> {
-        if container.Status.contains("Up") == false {
-            return Err("The container is already stopped.".into());
+    /// Does `image` exist locally?
+    async fn image_exists(&self, image: &str) -> Result<bool> {
+        let url = format!("/images/{}/json", image);
+        let request_url = self.get_url(&url)?;
+        let request = self.build_empty_get_request(&request_url)?;
+        let response = self.client.request(request).await?;
+        match response.status() {
+            status if status.is_success() => Ok(true),
+            StatusCode::NOT_FOUND => Ok(false),
+            status => Err(format!("HTTP request failed: {}", status).into()),
         }
+    }
 
-        let request_url = self.get_url(&format!("/containers/{}/stats", container.Id));
-        let request = self
-            .build_empty_get_request(&request_url)?;
+    /// Create an image named `repo:tag` from `src`, a tar archive of a
+    /// root filesystem, like `docker import`.  `changes` are Dockerfile
+    /// instructions to apply to the image, such as `"CMD [\"/bin/sh\"]"`.
+    /// When the import finishes, the final message's `status` contains the
+    /// ID of the new image.
+    pub async fn import_image<S>(
+        &self,
+        src: S,
+        repo: &str,
+        tag: &str,
+        changes: &[&str],
+    ) -> Result<impl Stream<Item = Result<ProgressEvent>> + Send + Unpin>
+    where
+        S: Stream<Item = io::Result<Bytes>> + Send + 'static,
+    {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        params
+            .append_pair("fromSrc", "-")
+            .append_pair("repo", repo)
+            .append_pair("tag", tag);
+        for change in changes {
+            params.append_pair("changes", change);
+        }
+        let url = format!("/images/create?{}", params.finish());
+        let request_url = self.get_url(&url)?;
+        let request = Request::post(&request_url)
+            .header(header::CONTENT_TYPE, "application/x-tar")
+            .body(Body::wrap_stream(src))
+            .chain_err(|| "error building request")?;
         let response = self.start_request(request).await?;
-        Ok(StatsReader::new(response))
+        Ok(decode_progress(response.into_body()))
     }
 
-    pub async fn create_image(&self, image: String, tag: String) -> Result<Vec<ImageStatus>> {
-        let request_url = self.get_url(&format!("/images/create?fromImage={}&tag={}", image, tag));
-        let request = self
-            .build_post_request(&request_url)
+    /// Push `image:tag` to its registry, streaming progress messages as
+    /// they arrive.  If Docker reports an error, the stream will return it
+    /// as an `Err`.
+    pub async fn push_image(
+        &self,
+        image: &str,
+        tag: &str,
+        auth: Option<&RegistryAuth>,
+    ) -> Result<impl Stream<Item = Result<ProgressEvent>> + Send + Unpin> {
+        let params = form_urlencoded::Serializer::new(String::new())
+            .append_pair("tag", tag)
+            .finish();
+        let url = format!("/images/{}/push?{}", image, params);
+        let request_url = self.get_url(&url)?;
+        // Docker requires this header even when pushing anonymously.
+        let auth = match auth {
+            Some(auth) => auth.to_header()?,
+            None => RegistryAuth::default().to_header()?,
+        };
+        let request = Request::post(&request_url)
+            .header("X-Registry-Auth", auth)
             .body(Body::empty())
             .chain_err(|| "error building request")?;
-        let body = self.execute_request(request).await?;
-        let fixed = self.arrayify(&body);
-        let statuses = serde_json::from_str::<Vec<ImageStatus>>(&fixed)
-            .chain_err(|| ErrorKind::ParseError("ImageStatus", fixed))?;
-        Ok(statuses)
+        let response = self.start_request(request).await?;
+        Ok(decode_progress(response.into_body()))
     }
-    */
 
-    pub async fn images(&self, all: bool) -> Result<Vec<Image>> {
-        let a = match all {
-            true => "1",
-            false => "0",
+    /// Build an image from `context`, a tar archive containing a
+    /// Dockerfile and any files it uses, streaming the build output as it
+    /// arrives.  If the build fails, the stream will return an `Err`.
+    ///
+    /// If `BuildOptions::buildkit` is set, we open a BuildKit session
+    /// which stays alive until the returned stream is dropped.
+    pub async fn build_image<S>(
+        &self,
+        opts: BuildOptions,
+        context: S,
+    ) -> Result<impl Stream<Item = Result<ProgressEvent>> + Send + Unpin>
+    where
+        S: Stream<Item = io::Result<Bytes>> + Send + 'static,
+    {
+        let mut url = format!("/build?{}", opts.to_url_params());
+        let session = if opts.uses_buildkit() {
+            let (id, session) = self.start_session(opts.session_providers()).await?;
+            url.push_str(&format!("&session={}", id));
+            Some(session)
+        } else {
+            None
         };
-        let url = format!("/images/json?a={}", a);
+        let request_url = self.get_url(&url)?;
+        let request = Request::post(&request_url)
+            .header(header::CONTENT_TYPE, "application/x-tar")
+            .body(Body::wrap_stream(context))
+            .chain_err(|| "error building request")?;
+        let response = self.start_request(request).await?;
+        Ok(decode_progress(response.into_body()).map(move |event| {
+            // Keep the session open for as long as the build runs.
+            let _session = &session;
+            event
+        }))
+    }
+
+    /// Open a BuildKit session which provides any secrets and SSH agents
+    /// in `providers`, returning its ID and a handle which shuts it down
+    /// when dropped.
+    async fn start_session(
+        &self,
+        providers: &session::SessionProviders,
+    ) -> Result<(String, session::SessionHandle)> {
+        let id = session::new_session_id();
+        let methods = session::exposed_methods(providers);
+        let mut headers = vec![
+            ("X-Docker-Expose-Session-Uuid", id.as_str()),
+            ("X-Docker-Expose-Session-Name", "boondock"),
+            ("X-Docker-Expose-Session-Sharedkey", ""),
+        ];
+        for method in &methods {
+            headers.push(("X-Docker-Expose-Session-Grpc-Method", method));
+        }
+        let request_url = self.get_url("/session")?;
+        let (upgraded, _) = self.upgrade_request(&request_url, "h2c", &headers).await?;
+        Ok((id, session::spawn(upgraded, providers.clone())))
+    }
+
+    /// Build an image from the local directory `dir`, like `docker build`.
+    /// Files matched by `dir/.dockerignore` are left out, and the build
+    /// context is streamed to Docker as it is archived.
+    pub async fn build_image_from_dir(
+        &self,
+        dir: impl AsRef<Path>,
+        opts: BuildOptions,
+    ) -> Result<impl Stream<Item = Result<ProgressEvent>> + Send + Unpin> {
+        let dir = dir.as_ref().to_owned();
+        let dockerfile = opts.dockerfile_path().to_owned();
+        let (sender, receiver) = mpsc::channel(1);
+        task::spawn_blocking(move || {
+            let writer = archive::ChannelWriter::new(sender.clone());
+            if let Err(err) = archive::tar_build_context(&dir, &dockerfile, writer) {
+                // Fail the upload, which will abort the build.
+                let err = io::Error::other(err.display_chain().to_string());
+                let _ = executor::block_on(sender.clone().send(Err(err)));
+            }
+        });
+        self.build_image(opts, receiver).await
+    }
+
+    /// Tag the image `name_or_id` as `repo:tag`.
+    pub async fn tag_image(&self, name_or_id: &str, repo: &str, tag: &str) -> Result<()> {
+        let params = form_urlencoded::Serializer::new(String::new())
+            .append_pair("repo", repo)
+            .append_pair("tag", tag)
+            .finish();
+        let url = format!("/images/{}/tag?{}", name_or_id, params);
+        self.post_action(&url).await
+    }
+
+    /// Remove an image, returning the tags which were removed and the
+    /// images which were deleted as a result.
+    pub async fn remove_image(
+        &self,
+        name: &str,
+        opts: RemoveImageOptions,
+    ) -> Result<Vec<RemovedImage>> {
+        let url = format!("/images/{}?{}", name, opts.to_url_params());
+        let request_url = self.get_url(&url)?;
+        let request = self.build_delete_request(&request_url)?;
+        self.decode_request("RemovedImage", request).await
+    }
+
+    /// List images.
+    pub async fn images(&self, opts: ImageListOptions) -> Result<Vec<Image>> {
+        let url = format!("/images/json?{}", opts.to_url_params());
         self.decode_url("Image", &url).await
     }
 
-    pub async fn system_info(&self) -> Result<SystemInfo> {
-        self.decode_url("SystemInfo", &format!("/info")).await
+    /// Save an image, including all its tags and layers, as a tar archive
+    /// which can be loaded using `load_image`.
+    pub async fn save_image(
+        &self,
+        name: &str,
+    ) -> Result<impl Stream<Item = Result<Bytes>> + Send + Unpin> {
+        let url = format!("/images/{}/get", name);
+        self.get_body_stream(&url).await
     }
 
-    pub async fn container_info(&self, container: &Container) -> Result<ContainerInfo> {
-        let url = format!("/containers/{}/json", container.Id);
-        self.decode_url("ContainerInfo", &url)
+    /// Save several images as a single tar archive.  Layers shared between
+    /// the images are only included once.
+    pub async fn save_images(
+        &self,
+        names: &[&str],
+    ) -> Result<impl Stream<Item = Result<Bytes>> + Send + Unpin> {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        for name in names {
+            params.append_pair("names", name);
+        }
+        let url = format!("/images/get?{}", params.finish());
+        self.get_body_stream(&url).await
+    }
+
+    /// Load images from `body`, a tar archive created by `save_image` or
+    /// `docker save`, streaming progress messages as they arrive.  Use
+    /// `ProgressEvent::loaded_image` to find out what was loaded.  If
+    /// `quiet` is set, Docker doesn't report progress for each layer.
+    pub async fn load_image<S>(
+        &self,
+        body: S,
+        quiet: bool,
+    ) -> Result<impl Stream<Item = Result<ProgressEvent>> + Send + Unpin>
+    where
+        S: Stream<Item = io::Result<Bytes>> + Send + 'static,
+    {
+        let url = format!("/images/load?quiet={}", if quiet { "1" } else { "0" });
+        let request_url = self.get_url(&url)?;
+        let request = Request::post(&request_url)
+            .header(header::CONTENT_TYPE, "application/x-tar")
+            .body(Body::wrap_stream(body))
+            .chain_err(|| "error building request")?;
+        let response = self.start_request(request).await?;
+        Ok(decode_progress(response.into_body()))
+    }
+
+    /// Get detailed information about an image.  `name` may be an ID, a
+    /// name with an optional tag, or a digest-pinned reference like
+    /// `"alpine@sha256:..."`.
+    pub async fn image_info(&self, name: &str) -> Result<ImageInfo> {
+        let url = format!("/images/{}/json", name);
+        self.decode_url("ImageInfo", &url).await
+    }
+
+    /// Search Docker Hub for images matching `term`.
+    pub async fn search_images(
+        &self,
+        term: &str,
+        opts: SearchOptions,
+    ) -> Result<Vec<SearchResult>> {
+        let params = query_params(&[("term", term)], &opts.to_url_params());
+        let url = format!("/images/search?{}", params);
+        self.decode_url("SearchResult", &url).await
+    }
+
+    /// List networks.
+    pub async fn networks(&self, opts: NetworkListOptions) -> Result<Vec<Network>> {
+        let url = format!("/networks?{}", opts.to_url_params());
+        self.decode_url("Network", &url).await
+    }
+
+    /// Get detailed information about a network, including the containers
+    /// attached to it.  Set `verbose` to include swarm peers, and `scope`
+    /// to only look for networks with that scope, such as `"swarm"`.
+    pub async fn network_info(
+        &self,
+        id_or_name: &str,
+        verbose: bool,
+        scope: Option<&str>,
+    ) -> Result<Network> {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        if verbose {
+            params.append_pair("verbose", "1");
+        }
+        if let Some(scope) = scope {
+            params.append_pair("scope", scope);
+        }
+        let url = format!("/networks/{}?{}", id_or_name, params.finish());
+        self.decode_url("Network", &url).await
+    }
+
+    /// Create a network.
+    pub async fn create_network(
+        &self,
+        opts: CreateNetworkOptions,
+    ) -> Result<CreateNetworkResponse> {
+        self.decode_json_post("CreateNetworkResponse", "/networks/create", &opts)
             .await
-            .chain_err(|| ErrorKind::ContainerInfo(container.Id.clone()))
     }
 
-    pub async fn filesystem_changes(&self, container: &Container) -> Result<Vec<FilesystemChange>> {
-        let url = format!("/containers/{}/changes", container.Id);
-        self.decode_url("FilesystemChange", &url).await
+    /// Remove a network.  Fails if containers are still attached to it.
+    pub async fn remove_network(&self, id_or_name: &str) -> Result<()> {
+        let url = format!("/networks/{}", id_or_name);
+        let request_url = self.get_url(&url)?;
+        let request = self.build_delete_request(&request_url)?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    /// Attach a container to a network.
+    pub async fn connect_network(
+        &self,
+        network: &str,
+        container: &str,
+        config: EndpointConfig,
+    ) -> Result<()> {
+        let body = serde_json::json!({
+            "Container": container,
+            "EndpointConfig": config,
+        });
+        let url = format!("/networks/{}/connect", network);
+        let request_url = self.get_url(&url)?;
+        let request = self.build_json_post_request(&request_url, &body)?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    /// Remove all unused networks matching `opts`.
+    pub async fn prune_networks(
+        &self,
+        opts: PruneNetworksOptions,
+    ) -> Result<PruneNetworksResponse> {
+        let url = format!("/networks/prune?{}", opts.to_url_params());
+        let request_url = self.get_url(&url)?;
+        let request = self.build_post_request(&request_url, Body::empty())?;
+        self.decode_request("PruneNetworksResponse", request).await
+    }
+
+    /// List volumes.
+    pub async fn volumes(&self, opts: VolumeListOptions) -> Result<Vec<Volume>> {
+        let url = format!("/volumes?{}", opts.to_url_params());
+        let list: VolumeList = self.decode_url("VolumeList", &url).await?;
+        Ok(list.Volumes)
+    }
+
+    /// Create a volume.  If a volume with the same name already exists,
+    /// Docker returns it instead.
+    pub async fn create_volume(&self, opts: CreateVolumeOptions) -> Result<Volume> {
+        self.decode_json_post("Volume", "/volumes/create", &opts)
+            .await
+    }
+
+    /// Get detailed information about a volume.
+    pub async fn volume_info(&self, name: &str) -> Result<Volume> {
+        let url = format!("/volumes/{}", name);
+        self.decode_url("Volume", &url).await
+    }
+
+    /// Remove a volume.  Set `force` to remove it even if it's in use.
+    pub async fn remove_volume(&self, name: &str, force: bool) -> Result<()> {
+        let url = format!("/volumes/{}?force={}", name, if force { "1" } else { "0" });
+        let request_url = self.get_url(&url)?;
+        let request = self.build_delete_request(&request_url)?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    /// Remove all unused volumes matching `opts`.
+    pub async fn prune_volumes(&self, opts: PruneVolumesOptions) -> Result<PruneVolumesResponse> {
+        let url = format!("/volumes/prune?{}", opts.to_url_params());
+        let request_url = self.get_url(&url)?;
+        let request = self.build_post_request(&request_url, Body::empty())?;
+        self.decode_request("PruneVolumesResponse", request).await
     }
 
-    pub async fn export_container(&self, container: &Container) -> Result<Response<Body>> {
-        let url = format!("/containers/{}/export", container.Id);
+    /// Stream events from the Docker host as they happen, such as
+    /// containers starting and stopping.
+    pub async fn events(&self, opts: EventOptions) -> Result<EventStream> {
+        let url = format!("/events?{}", opts.to_url_params());
         let request_url = self.get_url(&url)?;
         let request = self.build_empty_get_request(&request_url)?;
         let response = self.start_request(request).await?;
-        Ok(response)
+        let events = decode_json_lines("EventMessage", response.into_body());
+        Ok(EventStream::new(Box::pin(events)))
+    }
+
+    pub async fn system_info(&self) -> Result<SystemInfo> {
+        self.decode_url("SystemInfo", "/info").await
+    }
+
+    /// Check that `auth` can log in to its registry, without pulling or
+    /// pushing anything.  Fails if the credentials are rejected.
+    pub async fn check_auth(&self, auth: &RegistryAuth) -> Result<AuthResponse> {
+        self.decode_json_post("AuthResponse", "/auth", auth).await
+    }
+
+    /// Report how much disk space is used by images, containers, volumes
+    /// and the build cache, like `docker system df -v`.
+    pub async fn system_df(&self) -> Result<DiskUsage> {
+        self.decode_url("DiskUsage", "/system/df").await
+    }
+
+    /// Create a new swarm with this node as its first manager.  Returns
+    /// the ID of the node.
+    pub async fn swarm_init(&self, opts: SwarmInitOptions) -> Result<String> {
+        self.decode_json_post("String", "/swarm/init", &opts).await
+    }
+
+    /// Join an existing swarm as a worker or manager, depending on the join
+    /// token.
+    pub async fn swarm_join(&self, opts: SwarmJoinOptions) -> Result<()> {
+        let request_url = self.get_url("/swarm/join")?;
+        let request = self.build_json_post_request(&request_url, &opts)?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    /// Leave the swarm.  Managers must set `force`, which may break the
+    /// swarm if too few managers remain.
+    pub async fn swarm_leave(&self, force: bool) -> Result<()> {
+        let url = format!("/swarm/leave?force={}", if force { "1" } else { "0" });
+        let request_url = self.get_url(&url)?;
+        let request = self.build_post_request(&request_url, Body::empty())?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    /// Inspect the swarm this node manages.
+    pub async fn swarm_info(&self) -> Result<Swarm> {
+        self.decode_url("Swarm", "/swarm").await
+    }
+
+    /// Change the settings of the swarm.  `version` must be the `Version`
+    /// of the `Swarm` which `spec` was based on, or Docker will refuse the
+    /// update.
+    pub async fn swarm_update(
+        &self,
+        spec: &SwarmSpec,
+        version: ObjectVersion,
+        opts: SwarmUpdateOptions,
+    ) -> Result<()> {
+        let version = version.Index.to_string();
+        let params = query_params(&[("version", &version)], &opts.to_url_params());
+        let url = format!("/swarm/update?{}", params);
+        let request_url = self.get_url(&url)?;
+        let request = self.build_json_post_request(&request_url, spec)?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    /// Get the key needed to unlock a manager after it restarts, if the
+    /// swarm has autolock enabled.  Keep it somewhere safe.
+    pub async fn swarm_unlock_key(&self) -> Result<String> {
+        let key: UnlockKey = self.decode_url("UnlockKey", "/swarm/unlockkey").await?;
+        Ok(key.UnlockKey)
+    }
+
+    /// Unlock a manager which restarted while autolock was enabled, using
+    /// the key from `swarm_unlock_key`.
+    pub async fn swarm_unlock(&self, key: &str) -> Result<()> {
+        let body = serde_json::json!({ "UnlockKey": key });
+        let request_url = self.get_url("/swarm/unlock")?;
+        let request = self.build_json_post_request(&request_url, &body)?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    /// List the services running on the swarm.
+    pub async fn services(&self, opts: ServiceListOptions) -> Result<Vec<Service>> {
+        let url = format!("/services?{}", opts.to_url_params());
+        self.decode_url("Service", &url).await
+    }
+
+    /// Inspect a service.  Set `insert_defaults` to fill in the spec with
+    /// Docker's defaults for anything which wasn't specified.
+    pub async fn service_info(&self, id_or_name: &str, insert_defaults: bool) -> Result<Service> {
+        let url = format!(
+            "/services/{}?insertDefaults={}",
+            id_or_name,
+            if insert_defaults { "1" } else { "0" }
+        );
+        self.decode_url("Service", &url).await
+    }
+
+    /// Create a service.  Pass `auth` if the image is in a private
+    /// registry, so that swarm nodes can pull it.
+    pub async fn create_service(
+        &self,
+        spec: &ServiceSpec,
+        auth: Option<&RegistryAuth>,
+    ) -> Result<CreateServiceResponse> {
+        let request_url = self.get_url("/services/create")?;
+        let mut request = self.build_json_post_request(&request_url, spec)?;
+        if let Some(auth) = auth {
+            let value = header::HeaderValue::from_str(&auth.to_header()?)
+                .chain_err(|| "error building request")?;
+            request.headers_mut().insert("X-Registry-Auth", value);
+        }
+        self.decode_request("CreateServiceResponse", request).await
+    }
+
+    /// Replace the spec of a service.  `version` must be the `Version` of
+    /// the `Service` which `spec` was based on.  If someone else updated
+    /// the service in the meantime, Docker refuses the update, and you
+    /// should fetch the service again and reapply your changes.
+    pub async fn update_service(
+        &self,
+        id_or_name: &str,
+        version: ObjectVersion,
+        spec: &ServiceSpec,
+        opts: UpdateServiceOptions,
+    ) -> Result<UpdateServiceResponse> {
+        let version = version.Index.to_string();
+        let params = query_params(&[("version", &version)], &opts.to_url_params());
+        let url = format!("/services/{}/update?{}", id_or_name, params);
+        let request_url = self.get_url(&url)?;
+        let mut request = self.build_json_post_request(&request_url, spec)?;
+        if let Some(auth) = opts.registry_auth() {
+            let value = header::HeaderValue::from_str(&auth.to_header()?)
+                .chain_err(|| "error building request")?;
+            request.headers_mut().insert("X-Registry-Auth", value);
+        }
+        self.decode_request("UpdateServiceResponse", request).await
+    }
+
+    /// Change how many replicas a service runs, like
+    /// `docker service scale`.  Fails if the service isn't replicated, or if
+    /// someone else updates it at the same time.
+    pub async fn scale_service(
+        &self,
+        id_or_name: &str,
+        replicas: u64,
+    ) -> Result<UpdateServiceResponse> {
+        let service = self.service_info(id_or_name, false).await?;
+        let mut spec = service.Spec;
+        spec.set_replicas(replicas)?;
+        self.update_service(
+            &service.ID,
+            service.Version,
+            &spec,
+            UpdateServiceOptions::default(),
+        )
+        .await
+    }
+
+    /// Watch the rolling update started by `update_service`, like
+    /// `docker service update --detach=false`, returning each new
+    /// `UpdateStatus` until the update or rollback completes.  If the update
+    /// pauses because tasks failed, we roll it back if `rollback_on_failure`
+    /// is set, and otherwise return an `Err`.
+    ///
+    /// `service` should be the `Service` you fetched before calling
+    /// `update_service`, so that we can tell the status left over from an
+    /// earlier update from the status of this one.  If Docker doesn't start
+    /// a rolling update within a few seconds, we assume the change didn't
+    /// need the tasks replacing, and end the stream without returning
+    /// anything.
+    pub fn watch_service_update<'a>(
+        &'a self,
+        service: &'a Service,
+        rollback_on_failure: bool,
+    ) -> impl Stream<Item = Result<UpdateStatus>> + Send + Unpin + 'a {
+        let id_or_name = service.ID.as_str();
+        let started = Instant::now();
+        let watch = UpdateWatch::new(service.UpdateStatus.clone(), rollback_on_failure);
+        Box::pin(stream::unfold(Some(watch), move |watch| async move {
+            let mut watch = watch?;
+            loop {
+                if watch.has_polled() {
+                    time::delay_for(UPDATE_POLL_INTERVAL).await;
+                }
+                let service = match self.service_info(id_or_name, false).await {
+                    Ok(service) => service,
+                    Err(err) => return Some((Err(err), None)),
+                };
+                match watch.observe(service.UpdateStatus.as_ref(), started.elapsed()) {
+                    UpdateStep::Wait => continue,
+                    UpdateStep::NoUpdate => return None,
+                    UpdateStep::Report(status) => return Some((Ok(status), Some(watch))),
+                    UpdateStep::Finish(status) => return Some((Ok(status), None)),
+                    UpdateStep::Rollback(status) => {
+                        let opts = UpdateServiceOptions::default().rollback();
+                        let rollback = self
+                            .update_service(&service.ID, service.Version, &service.Spec, opts)
+                            .await;
+                        if let Err(err) = rollback {
+                            return Some((Err(err), None));
+                        }
+                        return Some((Ok(status), Some(watch)));
+                    }
+                    UpdateStep::Fail(status) => {
+                        let err = format!(
+                            "update of service '{}' paused: {}",
+                            id_or_name, status.Message
+                        );
+                        return Some((Err(err.into()), None));
+                    }
+                }
+            }
+        }))
+    }
+
+    /// List the tasks running on the swarm.
+    pub async fn tasks(&self, opts: TaskListOptions) -> Result<Vec<Task>> {
+        let url = format!("/tasks?{}", opts.to_url_params());
+        self.decode_url("Task", &url).await
+    }
+
+    /// Inspect a task.
+    pub async fn task_info(&self, id: &str) -> Result<Task> {
+        let url = format!("/tasks/{}", id);
+        self.decode_url("Task", &url).await
+    }
+
+    /// Stream the logs of a single task, without having to find its
+    /// container on the node running it.
+    pub async fn task_logs(
+        &self,
+        id: &str,
+        opts: LogOptions,
+    ) -> Result<impl Stream<Item = Result<ServiceLogLine>> + Send + Unpin> {
+        let params = query_params(&[("details", "1")], &opts.to_url_params());
+        let url = format!("/tasks/{}/logs?{}", id, params);
+        let request_url = self.get_url(&url)?;
+        let request = self.build_empty_get_request(&request_url)?;
+        let response = self.start_request(request).await?;
+        Ok(decode_service_log_stream(response, opts.has_timestamps()))
+    }
+
+    /// Remove a service, stopping all its tasks.
+    pub async fn remove_service(&self, id_or_name: &str) -> Result<()> {
+        let url = format!("/services/{}", id_or_name);
+        let request_url = self.get_url(&url)?;
+        let request = self.build_delete_request(&request_url)?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    /// Stream the logs of every task in a service, labelled with the task
+    /// which wrote each line.  Combine this with `LogOptions::follow` to
+    /// tail the whole service.
+    pub async fn service_logs(
+        &self,
+        id_or_name: &str,
+        opts: LogOptions,
+    ) -> Result<impl Stream<Item = Result<ServiceLogLine>> + Send + Unpin> {
+        let params = query_params(&[("details", "1")], &opts.to_url_params());
+        let url = format!("/services/{}/logs?{}", id_or_name, params);
+        let request_url = self.get_url(&url)?;
+        let request = self.build_empty_get_request(&request_url)?;
+        let response = self.start_request(request).await?;
+        Ok(decode_service_log_stream(response, opts.has_timestamps()))
+    }
+
+    /// List the nodes in the swarm.
+    pub async fn nodes(&self, opts: NodeListOptions) -> Result<Vec<Node>> {
+        let url = format!("/nodes?{}", opts.to_url_params());
+        self.decode_url("Node", &url).await
+    }
+
+    /// Inspect a node.
+    pub async fn node_info(&self, id_or_name: &str) -> Result<Node> {
+        let url = format!("/nodes/{}", id_or_name);
+        self.decode_url("Node", &url).await
+    }
+
+    /// Replace the spec of a node, such as to change its labels.  `version`
+    /// must be the `Version` of the `Node` which `spec` was based on.
+    pub async fn update_node(
+        &self,
+        id_or_name: &str,
+        version: ObjectVersion,
+        spec: &NodeSpec,
+    ) -> Result<()> {
+        let url = format!("/nodes/{}/update?version={}", id_or_name, version.Index);
+        let request_url = self.get_url(&url)?;
+        let request = self.build_json_post_request(&request_url, spec)?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    /// Move all tasks off a node and stop scheduling new ones on it, like
+    /// `docker node update --availability drain`.
+    pub async fn drain_node(&self, id_or_name: &str) -> Result<()> {
+        self.set_node_availability(id_or_name, NodeAvailability::Drain)
+            .await
+    }
+
+    /// Allow tasks to be scheduled on a node again after draining or
+    /// pausing it.
+    pub async fn activate_node(&self, id_or_name: &str) -> Result<()> {
+        self.set_node_availability(id_or_name, NodeAvailability::Active)
+            .await
+    }
+
+    /// Change the availability of a node, leaving the rest of its spec
+    /// alone.
+    async fn set_node_availability(
+        &self,
+        id_or_name: &str,
+        availability: NodeAvailability,
+    ) -> Result<()> {
+        let node = self.node_info(id_or_name).await?;
+        if node.Spec.Availability == availability {
+            return Ok(());
+        }
+        let mut spec = node.Spec;
+        spec.Availability = availability;
+        self.update_node(&node.ID, node.Version, &spec).await
+    }
+
+    /// Remove a node from the swarm.  Nodes which are still up must be
+    /// removed with `force`.
+    pub async fn remove_node(&self, id_or_name: &str, force: bool) -> Result<()> {
+        let url = format!(
+            "/nodes/{}?force={}",
+            id_or_name,
+            if force { "1" } else { "0" }
+        );
+        let request_url = self.get_url(&url)?;
+        let request = self.build_delete_request(&request_url)?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    /// List the secrets stored in the swarm.
+    pub async fn secrets(&self, opts: SecretListOptions) -> Result<Vec<Secret>> {
+        let url = format!("/secrets?{}", opts.to_url_params());
+        self.decode_url("Secret", &url).await
+    }
+
+    /// Store a secret in the swarm, so that services can use it.
+    pub async fn create_secret(&self, spec: &SecretSpec) -> Result<CreateSecretResponse> {
+        self.decode_json_post("CreateSecretResponse", "/secrets/create", spec)
+            .await
+    }
+
+    /// Inspect a secret.  Docker doesn't return its data.
+    pub async fn inspect_secret(&self, id_or_name: &str) -> Result<Secret> {
+        let url = format!("/secrets/{}", id_or_name);
+        self.decode_url("Secret", &url).await
+    }
+
+    /// Update a secret.  Docker only allows its labels to change, so to
+    /// rotate a secret, create a new one and update the services using it.
+    /// `version` must be the `Version` of the `Secret` which `spec` was
+    /// based on.
+    pub async fn update_secret(
+        &self,
+        id_or_name: &str,
+        version: ObjectVersion,
+        spec: &SecretSpec,
+    ) -> Result<()> {
+        let url = format!("/secrets/{}/update?version={}", id_or_name, version.Index);
+        let request_url = self.get_url(&url)?;
+        let request = self.build_json_post_request(&request_url, spec)?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    /// Delete a secret.  Fails if services are still using it.
+    pub async fn delete_secret(&self, id_or_name: &str) -> Result<()> {
+        let url = format!("/secrets/{}", id_or_name);
+        let request_url = self.get_url(&url)?;
+        let request = self.build_delete_request(&request_url)?;
+        self.execute_request(request).await?;
+        Ok(())
+    }
+
+    pub async fn container_info(&self, id: impl AsRef<str>) -> Result<ContainerInfo> {
+        let url = format!("/containers/{}/json", id.as_ref());
+        self.decode_url("ContainerInfo", &url)
+            .await
+            .chain_err(|| ErrorKind::ContainerInfo(id.as_ref().to_owned()))
+    }
+
+    /// Wait until a container's healthcheck passes, polling its status
+    /// until `timeout` elapses.  Fails immediately if the container becomes
+    /// unhealthy, stops running, or has no healthcheck.
+    pub async fn wait_for_healthy(&self, id: impl AsRef<str>, timeout: Duration) -> Result<()> {
+        let id = id.as_ref();
+        let poll = async {
+            loop {
+                let info = self.container_info(id).await?;
+                let problem = match info.State.Health.map(|h| h.Status) {
+                    Some(HealthStatus::Healthy) => return Ok(()),
+                    _ if !info.State.Running => format!("status {}", info.State.Status),
+                    Some(HealthStatus::Starting) => {
+                        time::delay_for(HEALTH_POLL_INTERVAL).await;
+                        continue;
+                    }
+                    Some(HealthStatus::Unhealthy) => "healthcheck failed".to_owned(),
+                    _ => "no healthcheck".to_owned(),
+                };
+                return Err(ErrorKind::ContainerNotHealthy(id.to_owned(), problem).into());
+            }
+        };
+        time::timeout(timeout, poll)
+            .await
+            .chain_err(|| ErrorKind::Timeout(format!("container '{}' to become healthy", id)))?
+    }
+
+    pub async fn filesystem_changes(&self, id: impl AsRef<str>) -> Result<Vec<FilesystemChange>> {
+        let url = format!("/containers/{}/changes", id.as_ref());
+        self.decode_url("FilesystemChange", &url).await
+    }
+
+    /// Export the filesystem of a container as a tar archive.
+    pub async fn export_container(
+        &self,
+        id: impl AsRef<str>,
+    ) -> Result<impl Stream<Item = Result<Bytes>> + Send + Unpin> {
+        let url = format!("/containers/{}/export", id.as_ref());
+        self.get_body_stream(&url).await
+    }
+
+    /// Export the filesystem of a container as a tar archive, writing it to
+    /// `writer`.  `progress` is called with the total number of bytes
+    /// written so far after each chunk.  Returns the size of the archive.
+    pub async fn export_container_to<W, F>(
+        &self,
+        id: impl AsRef<str>,
+        writer: &mut W,
+        progress: F,
+    ) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+        F: FnMut(u64),
+    {
+        let stream = self.export_container(id).await?;
+        copy_stream_to(stream, writer, progress).await
+    }
+
+    /// Export the filesystem of a container as a tar archive, writing it to
+    /// the file at `path`.  See `export_container_to` for details.
+    pub async fn export_container_to_file<F>(
+        &self,
+        id: impl AsRef<str>,
+        path: impl AsRef<Path>,
+        progress: F,
+    ) -> Result<u64>
+    where
+        F: FnMut(u64),
+    {
+        let path = path.as_ref();
+        let mut file = File::create(path)
+            .await
+            .chain_err(|| format!("could not create {}", path.display()))?;
+        let written = self.export_container_to(id, &mut file, progress).await?;
+        file.flush()
+            .await
+            .chain_err(|| format!("could not write {}", path.display()))?;
+        Ok(written)
     }
 
     pub async fn ping(&self) -> Result<Vec<u8>> {
@@ -299,7 +1716,82 @@ impl Docker {
         self.execute_request(request).await
     }
 
+    /// Check that the daemon is responding, and find out what it supports
+    /// from the headers it returns, without downloading anything else.
+    pub async fn ping_info(&self) -> Result<PingInfo> {
+        let request_url = self.get_url("/_ping")?;
+        let request = Request::head(&request_url)
+            .body(Body::empty())
+            .chain_err(|| "error building request")?;
+        let started = Instant::now();
+        let response = self.start_request(request).await?;
+        Ok(PingInfo::from_headers(
+            response.headers(),
+            started.elapsed(),
+        ))
+    }
+
     pub async fn version(&self) -> Result<Version> {
         self.decode_url("Version", "/version").await
     }
 }
+
+/// Copy `stream` to `writer`, calling `progress` with the total number of
+/// bytes written after each chunk.
+async fn copy_stream_to<S, W, F>(mut stream: S, writer: &mut W, mut progress: F) -> Result<u64>
+where
+    S: Stream<Item = Result<Bytes>> + Unpin,
+    W: AsyncWrite + Unpin,
+    F: FnMut(u64),
+{
+    let mut written = 0;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        writer.write_all(&chunk).await?;
+        written += chunk.len() as u64;
+        progress(written);
+    }
+    Ok(written)
+}
+
+/// Build a query string from `pairs` followed by `opts_params`, the
+/// already-encoded parameters from an options type.
+pub(crate) fn query_params(pairs: &[(&str, &str)], opts_params: &str) -> String {
+    let mut params = form_urlencoded::Serializer::new(String::new());
+    params.extend_pairs(pairs);
+    params.extend_pairs(form_urlencoded::parse(opts_params.as_bytes()));
+    params.finish()
+}
+
+/// Build the `t=` parameter used by `stop` and `restart`.
+fn timeout_params(timeout: Option<Duration>) -> String {
+    let mut params = form_urlencoded::Serializer::new(String::new());
+    if let Some(timeout) = timeout {
+        params.append_pair("t", &timeout.as_secs().to_string());
+    }
+    params.finish()
+}
+
+/// Build the `dir=` parameter used by the checkpoint endpoints.
+#[cfg(feature = "checkpoint")]
+fn checkpoint_dir_params(checkpoint_dir: Option<&str>) -> String {
+    let mut params = form_urlencoded::Serializer::new(String::new());
+    if let Some(dir) = checkpoint_dir {
+        params.append_pair("dir", dir);
+    }
+    params.finish()
+}
+
+/// Decode the base64-encoded JSON `X-Docker-Container-Path-Stat` header
+/// returned by the archive endpoints.
+pub(crate) fn decode_path_stat(response: &Response<Body>) -> Result<PathStat> {
+    let header = response
+        .headers()
+        .get("X-Docker-Container-Path-Stat")
+        .ok_or("missing X-Docker-Container-Path-Stat header")?;
+    let json = base64::decode(header.as_bytes())
+        .chain_err(|| "could not decode X-Docker-Container-Path-Stat header")?;
+    serde_json::from_slice(&json).chain_err(|| {
+        ErrorKind::ParseError("PathStat", String::from_utf8_lossy(&json).into_owned())
+    })
+}
@@ -0,0 +1,165 @@
+//! Parsing and matching `.dockerignore` files.
+//!
+//! Patterns follow the same rules as the `docker` client: `*`, `?` and
+//! `[...]` match within a single path component, `**` matches any number of
+//! components, lines starting with `!` re-include paths, and the last
+//! matching pattern wins.  A pattern which matches a directory also
+//! matches everything inside it.
+
+/// The patterns from a `.dockerignore` file.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DockerIgnore {
+    patterns: Vec<Pattern>,
+}
+
+/// A single line from a `.dockerignore` file.
+#[derive(Debug, Clone)]
+struct Pattern {
+    components: Vec<String>,
+    /// Does this pattern start with `!`?
+    exception: bool,
+}
+
+impl DockerIgnore {
+    /// Parse the contents of a `.dockerignore` file.
+    pub(crate) fn parse(text: &str) -> DockerIgnore {
+        let patterns = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let (exception, line) = match line.strip_prefix('!') {
+                    Some(rest) => (true, rest.trim()),
+                    None => (false, line),
+                };
+                let components = clean(line);
+                if components.is_empty() {
+                    None
+                } else {
+                    Some(Pattern {
+                        components,
+                        exception,
+                    })
+                }
+            })
+            .collect();
+        DockerIgnore { patterns }
+    }
+
+    /// Should `path`, relative to the build context and separated by `/`,
+    /// be left out of the context?
+    pub(crate) fn is_excluded(&self, path: &str) -> bool {
+        let path = clean(path);
+        let mut excluded = false;
+        for pattern in &self.patterns {
+            if pattern.exception == excluded && pattern.matches(&path) {
+                excluded = !pattern.exception;
+            }
+        }
+        excluded
+    }
+
+    /// Are there any `!` patterns?  If not, we never need to look inside
+    /// an excluded directory.
+    pub(crate) fn has_exceptions(&self) -> bool {
+        self.patterns.iter().any(|p| p.exception)
+    }
+}
+
+impl Pattern {
+    /// Does this pattern match `path`, or any directory containing it?
+    fn matches(&self, path: &[String]) -> bool {
+        (1..=path.len()).any(|len| match_components(&self.components, &path[..len]))
+    }
+}
+
+/// Split a path into components, removing empty components and `.`, and
+/// resolving `..`, like Go's `filepath.Clean`.
+fn clean(path: &str) -> Vec<String> {
+    let mut components: Vec<String> = vec![];
+    for component in path.split('/') {
+        match component {
+            "" | "." => {}
+            ".." => {
+                components.pop();
+            }
+            other => components.push(other.to_owned()),
+        }
+    }
+    components
+}
+
+/// Match pattern components against path components, handling `**`.
+fn match_components(pattern: &[String], path: &[String]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((first, rest)) if first == "**" => {
+            (0..=path.len()).any(|skip| match_components(rest, &path[skip..]))
+        }
+        Some((first, rest)) => match path.split_first() {
+            Some((component, path_rest)) => {
+                let pattern: Vec<char> = first.chars().collect();
+                let text: Vec<char> = component.chars().collect();
+                wildcard(&pattern, &text) && match_components(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Match a single component against a pattern containing `*`, `?`,
+/// `[...]` and `\` escapes.
+fn wildcard(pattern: &[char], text: &[char]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => (0..=text.len()).any(|skip| wildcard(rest, &text[skip..])),
+        Some(('?', rest)) => !text.is_empty() && wildcard(rest, &text[1..]),
+        Some(('[', rest)) => match (text.split_first(), match_class(rest)) {
+            (Some((&c, text_rest)), Some((matcher, pattern_rest))) => {
+                matcher(c) && wildcard(pattern_rest, text_rest)
+            }
+            _ => false,
+        },
+        Some(('\\', rest)) if !rest.is_empty() => {
+            text.first() == Some(&rest[0]) && wildcard(&rest[1..], &text[1..])
+        }
+        Some((&p, rest)) => text.first() == Some(&p) && wildcard(rest, &text[1..]),
+    }
+}
+
+/// Parse a character class following a `[`, returning a function to test
+/// characters against it and the rest of the pattern.  Returns `None` if
+/// the class is not terminated.
+#[allow(clippy::type_complexity)]
+fn match_class(pattern: &[char]) -> Option<(Box<dyn Fn(char) -> bool>, &[char])> {
+    let (negated, mut rest) = match pattern.split_first() {
+        Some(('^', rest)) | Some(('!', rest)) => (true, rest),
+        _ => (false, pattern),
+    };
+    let mut ranges = vec![];
+    loop {
+        let (lo, after) = match rest {
+            [']', after @ ..] if !ranges.is_empty() => {
+                let matcher =
+                    move |c: char| ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi) != negated;
+                return Some((Box::new(matcher), after));
+            }
+            ['\\', c, after @ ..] => (*c, after),
+            [c, after @ ..] => (*c, after),
+            [] => return None,
+        };
+        rest = after;
+        let hi = match rest {
+            ['-', '\\', c, after @ ..] => {
+                rest = after;
+                *c
+            }
+            ['-', c, after @ ..] if *c != ']' => {
+                rest = after;
+                *c
+            }
+            _ => lo,
+        };
+        ranges.push((lo, hi));
+    }
+}
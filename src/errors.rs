@@ -1,5 +1,8 @@
 //! Error-handling with the `error_chain` crate.
 
+// `error_chain!` expands to `cfg` checks that newer compilers don't know about.
+#![allow(unexpected_cfgs)]
+
 use hyper;
 use std::env;
 use std::io;
@@ -22,6 +25,11 @@ error_chain! {
             display("could not connected to Docker at '{}'", &host)
         }
 
+        ContainerNotHealthy(id: String, problem: String) {
+            description("container did not become healthy")
+            display("container '{}' did not become healthy: {}", &id, &problem)
+        }
+
         NoCertPath {
             description("could not find DOCKER_CERT_PATH")
             display("could not find DOCKER_CERT_PATH")
@@ -32,6 +40,11 @@ error_chain! {
             display("could not parse JSON for {} from Docker", wanted)
         }
 
+        ProgressError(message: String) {
+            description("Docker reported an error")
+            display("Docker reported an error: {}", &message)
+        }
+
         SslDisabled {
             description("Docker SSL support was disabled at compile time")
             display("Docker SSL support was disabled at compile time")
@@ -42,6 +55,11 @@ error_chain! {
             display("could not connect to Docker at '{}' using SSL", &host)
         }
 
+        Timeout(what: String) {
+            description("timed out waiting for Docker")
+            display("timed out waiting for {}", &what)
+        }
+
         UnsupportedScheme(host: String) {
             description("unsupported Docker URL scheme")
             display("do not know how to connect to Docker at '{}'", &host)
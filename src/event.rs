@@ -0,0 +1,311 @@
+//! Events reported by `Docker::events`.
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use futures::stream::{BoxStream, Stream};
+use std::collections::HashMap;
+use std::fmt;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::container::HealthStatus;
+use crate::errors::*;
+use crate::image::null_to_default;
+#[cfg(feature = "chrono")]
+use crate::timestamps;
+
+/// Something which happened on the Docker host, such as a container
+/// starting or an image being pulled.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct EventMessage {
+    /// The kind of object affected.
+    pub Type: EventType,
+    /// What happened.
+    pub Action: EventAction,
+    pub Actor: EventActor,
+    /// `"local"` or `"swarm"`.
+    #[serde(default)]
+    pub scope: String,
+    /// When the event happened, in seconds since the Unix epoch.
+    pub time: u64,
+    /// When the event happened, in nanoseconds since the Unix epoch.
+    #[serde(default)]
+    pub timeNano: u64,
+}
+
+impl EventMessage {
+    /// The actor attribute `name`, such as a container's `"name"` or
+    /// `"image"`, or one of its labels.
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.Actor.Attributes.get(name).map(String::as_str)
+    }
+
+    /// When the event happened, to the nanosecond if Docker told us.
+    pub fn system_time(&self) -> SystemTime {
+        if self.timeNano > 0 {
+            UNIX_EPOCH + Duration::from_nanos(self.timeNano)
+        } else {
+            UNIX_EPOCH + Duration::from_secs(self.time)
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl EventMessage {
+    /// When the event happened.
+    pub fn timestamp(&self) -> Option<DateTime<Utc>> {
+        timestamps::from_unix(self.time)
+    }
+}
+
+/// The kind of object an event is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventType {
+    Builder,
+    Config,
+    Container,
+    Daemon,
+    Image,
+    Network,
+    Node,
+    Plugin,
+    Secret,
+    Service,
+    Volume,
+    /// A type we don't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EventType::Builder => "builder",
+            EventType::Config => "config",
+            EventType::Container => "container",
+            EventType::Daemon => "daemon",
+            EventType::Image => "image",
+            EventType::Network => "network",
+            EventType::Node => "node",
+            EventType::Plugin => "plugin",
+            EventType::Secret => "secret",
+            EventType::Service => "service",
+            EventType::Volume => "volume",
+            EventType::Unknown => "unknown",
+        };
+        f.write_str(name)
+    }
+}
+
+/// What happened in an event.  Not every action applies to every
+/// `EventType`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum EventAction {
+    Attach,
+    Commit,
+    Connect,
+    Copy,
+    Create,
+    Delete,
+    Destroy,
+    Detach,
+    Die,
+    Disconnect,
+    /// An exec instance was created to run the specified command.
+    ExecCreate(String),
+    ExecDetach,
+    ExecDie,
+    /// An exec instance started running the specified command.
+    ExecStart(String),
+    Export,
+    /// A container's healthcheck changed status.
+    HealthStatus(HealthStatus),
+    Import,
+    Kill,
+    Load,
+    Mount,
+    Oom,
+    Pause,
+    Prune,
+    Pull,
+    Push,
+    Reload,
+    Remove,
+    Rename,
+    Resize,
+    Restart,
+    Save,
+    Start,
+    Stop,
+    Tag,
+    Top,
+    Unmount,
+    Unpause,
+    Untag,
+    Update,
+    /// An action we don't know about, exactly as Docker reported it.
+    Other(String),
+}
+
+impl From<String> for EventAction {
+    fn from(action: String) -> Self {
+        // Some actions carry details after a `": "`.
+        let (name, detail) = match action.find(": ") {
+            Some(pos) => (&action[..pos], Some(&action[pos + 2..])),
+            None => (action.as_str(), None),
+        };
+        match (name, detail) {
+            ("attach", None) => EventAction::Attach,
+            ("commit", None) => EventAction::Commit,
+            ("connect", None) => EventAction::Connect,
+            ("copy", None) => EventAction::Copy,
+            ("create", None) => EventAction::Create,
+            ("delete", None) => EventAction::Delete,
+            ("destroy", None) => EventAction::Destroy,
+            ("detach", None) => EventAction::Detach,
+            ("die", None) => EventAction::Die,
+            ("disconnect", None) => EventAction::Disconnect,
+            ("exec_create", Some(cmd)) => EventAction::ExecCreate(cmd.to_owned()),
+            ("exec_detach", None) => EventAction::ExecDetach,
+            ("exec_die", None) => EventAction::ExecDie,
+            ("exec_start", Some(cmd)) => EventAction::ExecStart(cmd.to_owned()),
+            ("export", None) => EventAction::Export,
+            ("health_status", Some(status)) => {
+                let status = serde_json::from_value(serde_json::Value::from(status))
+                    .unwrap_or(HealthStatus::Unknown);
+                EventAction::HealthStatus(status)
+            }
+            ("import", None) => EventAction::Import,
+            ("kill", None) => EventAction::Kill,
+            ("load", None) => EventAction::Load,
+            ("mount", None) => EventAction::Mount,
+            ("oom", None) => EventAction::Oom,
+            ("pause", None) => EventAction::Pause,
+            ("prune", None) => EventAction::Prune,
+            ("pull", None) => EventAction::Pull,
+            ("push", None) => EventAction::Push,
+            ("reload", None) => EventAction::Reload,
+            ("remove", None) => EventAction::Remove,
+            ("rename", None) => EventAction::Rename,
+            ("resize", None) => EventAction::Resize,
+            ("restart", None) => EventAction::Restart,
+            ("save", None) => EventAction::Save,
+            ("start", None) => EventAction::Start,
+            ("stop", None) => EventAction::Stop,
+            ("tag", None) => EventAction::Tag,
+            ("top", None) => EventAction::Top,
+            ("unmount", None) => EventAction::Unmount,
+            ("unpause", None) => EventAction::Unpause,
+            ("untag", None) => EventAction::Untag,
+            ("update", None) => EventAction::Update,
+            _ => EventAction::Other(action),
+        }
+    }
+}
+
+impl fmt::Display for EventAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EventAction::Attach => "attach",
+            EventAction::Commit => "commit",
+            EventAction::Connect => "connect",
+            EventAction::Copy => "copy",
+            EventAction::Create => "create",
+            EventAction::Delete => "delete",
+            EventAction::Destroy => "destroy",
+            EventAction::Detach => "detach",
+            EventAction::Die => "die",
+            EventAction::Disconnect => "disconnect",
+            EventAction::ExecCreate(cmd) => return write!(f, "exec_create: {}", cmd),
+            EventAction::ExecDetach => "exec_detach",
+            EventAction::ExecDie => "exec_die",
+            EventAction::ExecStart(cmd) => return write!(f, "exec_start: {}", cmd),
+            EventAction::Export => "export",
+            EventAction::HealthStatus(status) => {
+                return write!(f, "health_status: {}", status.as_str().unwrap_or("unknown"))
+            }
+            EventAction::Import => "import",
+            EventAction::Kill => "kill",
+            EventAction::Load => "load",
+            EventAction::Mount => "mount",
+            EventAction::Oom => "oom",
+            EventAction::Pause => "pause",
+            EventAction::Prune => "prune",
+            EventAction::Pull => "pull",
+            EventAction::Push => "push",
+            EventAction::Reload => "reload",
+            EventAction::Remove => "remove",
+            EventAction::Rename => "rename",
+            EventAction::Resize => "resize",
+            EventAction::Restart => "restart",
+            EventAction::Save => "save",
+            EventAction::Start => "start",
+            EventAction::Stop => "stop",
+            EventAction::Tag => "tag",
+            EventAction::Top => "top",
+            EventAction::Unmount => "unmount",
+            EventAction::Unpause => "unpause",
+            EventAction::Untag => "untag",
+            EventAction::Update => "update",
+            EventAction::Other(action) => action,
+        };
+        f.write_str(name)
+    }
+}
+
+impl From<EventAction> for String {
+    fn from(action: EventAction) -> Self {
+        action.to_string()
+    }
+}
+
+/// The object an event is about.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct EventActor {
+    /// The ID of the container, image, network or other object.
+    #[serde(default)]
+    pub ID: String,
+    /// Extra information, such as a container's `"name"` and `"image"`,
+    /// and its labels.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Attributes: HashMap<String, String>,
+}
+
+/// The stream of events returned by `Docker::events`.  If the connection
+/// drops, pass `last_seen` to `EventOptions::resume_from` to pick up where
+/// this stream left off.
+pub struct EventStream {
+    events: BoxStream<'static, Result<EventMessage>>,
+    last_seen: Option<SystemTime>,
+}
+
+impl EventStream {
+    pub(crate) fn new(events: BoxStream<'static, Result<EventMessage>>) -> EventStream {
+        EventStream {
+            events,
+            last_seen: None,
+        }
+    }
+
+    /// When the most recent event we've returned happened.
+    pub fn last_seen(&self) -> Option<SystemTime> {
+        self.last_seen
+    }
+}
+
+impl Stream for EventStream {
+    type Item = Result<EventMessage>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = self.events.as_mut().poll_next(cx);
+        if let Poll::Ready(Some(Ok(ref event))) = poll {
+            self.last_seen = Some(event.system_time());
+        }
+        poll
+    }
+}
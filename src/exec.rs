@@ -0,0 +1,45 @@
+//! Running commands inside existing containers.
+
+/// The result of running a command with `Docker::exec_output`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecResult {
+    pub exit_code: i64,
+    pub stdout: Vec<u8>,
+    pub stderr: Vec<u8>,
+}
+
+/// The body of a request to create an exec instance.
+#[derive(Debug, Clone, Serialize)]
+#[allow(non_snake_case)]
+pub(crate) struct ExecConfig {
+    pub AttachStdout: bool,
+    pub AttachStderr: bool,
+    pub Cmd: Vec<String>,
+}
+
+/// The body of a request to start an exec instance.
+#[derive(Debug, Clone, Serialize)]
+#[allow(non_snake_case)]
+pub(crate) struct ExecStartConfig {
+    pub Detach: bool,
+    pub Tty: bool,
+}
+
+/// The response when creating an exec instance.
+#[derive(Debug, Clone, Deserialize)]
+#[allow(non_snake_case)]
+pub(crate) struct CreateExecResponse {
+    pub Id: String,
+}
+
+/// Information about an exec instance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ExecInfo {
+    pub ID: String,
+    pub Running: bool,
+    /// Only present once the command has finished running.
+    pub ExitCode: Option<i64>,
+    pub ContainerID: String,
+    pub Pid: i64,
+}
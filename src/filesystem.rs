@@ -1,6 +1,41 @@
+//! Changes to a container's filesystem.
+
 #[derive(Debug, Serialize, Deserialize)]
 #[allow(non_snake_case)]
 pub struct FilesystemChange {
     pub Path: String,
-    pub Kind: u8,
+    pub Kind: ChangeKind,
+}
+
+/// How a path in a container's filesystem has changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum ChangeKind {
+    Modified,
+    Added,
+    Deleted,
+    /// A kind of change we don't know about.
+    Unknown(u8),
+}
+
+impl From<u8> for ChangeKind {
+    fn from(kind: u8) -> Self {
+        match kind {
+            0 => ChangeKind::Modified,
+            1 => ChangeKind::Added,
+            2 => ChangeKind::Deleted,
+            other => ChangeKind::Unknown(other),
+        }
+    }
+}
+
+impl From<ChangeKind> for u8 {
+    fn from(kind: ChangeKind) -> Self {
+        match kind {
+            ChangeKind::Modified => 0,
+            ChangeKind::Added => 1,
+            ChangeKind::Deleted => 2,
+            ChangeKind::Unknown(other) => other,
+        }
+    }
 }
@@ -1,7 +1,15 @@
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 use serde::de::{DeserializeOwned, Deserializer};
 use serde::Deserialize;
+use std::collections::HashMap;
 
-fn null_to_default<'de, D, T>(de: D) -> Result<T, D::Error>
+use crate::container::UnspecifiedObject;
+#[cfg(feature = "chrono")]
+use crate::timestamps;
+
+/// Deserialize `null` as the default value of a type.
+pub(crate) fn null_to_default<'de, D, T>(de: D) -> std::result::Result<T, D::Error>
 where
     D: Deserializer<'de>,
     T: DeserializeOwned + Default,
@@ -13,17 +21,138 @@ where
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(non_snake_case)]
 pub struct Image {
+    #[cfg(not(feature = "chrono"))]
     pub Created: u64,
+    #[cfg(feature = "chrono")]
+    #[serde(with = "timestamps::unix")]
+    pub Created: DateTime<Utc>,
     pub Id: String,
     pub ParentId: String,
     #[serde(deserialize_with = "null_to_default")]
     pub RepoTags: Vec<String>,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub RepoDigests: Vec<String>,
     pub Size: u64,
     pub VirtualSize: u64,
+    /// The size of layers shared with other images, or `-1` if Docker
+    /// didn't calculate it.  Only calculated by `Docker::system_df`.
+    #[serde(default = "unknown_count")]
+    pub SharedSize: i64,
+    /// The number of containers using this image, or `-1` if Docker didn't
+    /// count them.  Only counted by `Docker::system_df`.
+    #[serde(default = "unknown_count")]
+    pub Containers: i64,
+}
+
+/// Docker uses `-1` for sizes and counts which it didn't work out.
+fn unknown_count() -> i64 {
+    -1
 }
 
+/// Detailed information about an image, as returned by
+/// `Docker::image_info`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ImageStatus {
-    pub status: Option<String>,
-    pub error: Option<String>,
+#[allow(non_snake_case)]
+pub struct ImageInfo {
+    pub Id: String,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub RepoTags: Vec<String>,
+    /// Digests which pin this image in a registry, such as
+    /// `"alpine@sha256:..."`.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub RepoDigests: Vec<String>,
+    #[serde(default)]
+    pub Parent: String,
+    #[serde(default)]
+    pub Comment: String,
+    /// An RFC 3339 timestamp.
+    #[cfg(not(feature = "chrono"))]
+    pub Created: String,
+    #[cfg(feature = "chrono")]
+    #[serde(with = "timestamps::rfc3339")]
+    pub Created: DateTime<Utc>,
+    #[serde(default)]
+    pub DockerVersion: String,
+    #[serde(default)]
+    pub Author: String,
+    /// The default configuration of containers created from this image.
+    #[serde(default)]
+    pub Config: Option<ImageConfig>,
+    /// The CPU architecture, such as `"amd64"` or `"arm64"`.
+    pub Architecture: String,
+    /// The CPU variant, such as `"v8"`, if any.
+    #[serde(default)]
+    pub Variant: Option<String>,
+    pub Os: String,
+    pub Size: u64,
+    pub RootFS: RootFS,
+}
+
+/// The container configuration stored in an image.  Everything is
+/// optional, because images built `FROM scratch` may set very little.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ImageConfig {
+    #[serde(default)]
+    pub User: String,
+    #[serde(default)]
+    pub Env: Option<Vec<String>>,
+    #[serde(default)]
+    pub Cmd: Option<Vec<String>>,
+    #[serde(default)]
+    pub Entrypoint: Option<Vec<String>>,
+    #[serde(default)]
+    pub WorkingDir: String,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Labels: HashMap<String, String>,
+    #[serde(default)]
+    pub ExposedPorts: Option<HashMap<String, UnspecifiedObject>>,
+    #[serde(default)]
+    pub Volumes: Option<HashMap<String, UnspecifiedObject>>,
+    #[serde(default)]
+    pub StopSignal: Option<String>,
+}
+
+/// The layers which make up an image.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct RootFS {
+    /// Usually `"layers"`.
+    pub Type: String,
+    /// The digests of each layer, from the bottom up.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Layers: Vec<String>,
+}
+
+/// An image found by `Docker::search_images`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub is_official: bool,
+    #[serde(default)]
+    pub is_automated: bool,
+    #[serde(default)]
+    pub star_count: u64,
+}
+
+/// Something removed by `Docker::remove_image`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RemovedImage {
+    /// A tag which was removed, such as `"example/app:1.0"`.
+    Untagged(String),
+    /// The ID of an image or layer which was deleted.
+    Deleted(String),
+}
+
+/// What was pushed by `Docker::push_image`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct PushResult {
+    pub Tag: String,
+    /// The digest of the pushed manifest, such as `"sha256:..."`.
+    pub Digest: String,
+    pub Size: u64,
 }
@@ -0,0 +1,55 @@
+//! Decoding for the newline-delimited JSON streams returned by endpoints
+//! like `/containers/{id}/stats` and `/images/create`.
+
+use futures::stream::{self, Stream, StreamExt};
+use hyper::Body;
+use serde::de::DeserializeOwned;
+
+use crate::errors::*;
+
+/// Decode `body` as a stream of JSON values, one per line.  Blank lines
+/// are ignored, and a final value without a trailing newline is still
+/// returned.
+pub(crate) fn decode_json_lines<T>(
+    type_name: &'static str,
+    body: Body,
+) -> impl Stream<Item = Result<T>> + Send + Unpin
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let state = Some((Vec::new(), body));
+    stream::unfold(state, move |state| async move {
+        let (mut buf, mut body) = state?;
+        loop {
+            if let Some(pos) = buf.iter().position(|&b| b == b'\n') {
+                let line = buf.drain(..=pos).collect::<Vec<u8>>();
+                if let Some(item) = parse_line(type_name, &line) {
+                    return Some((item, Some((buf, body))));
+                }
+                continue;
+            }
+            match body.next().await {
+                Some(Ok(chunk)) => buf.extend_from_slice(&chunk),
+                Some(Err(err)) => return Some((Err(err.into()), None)),
+                None => return parse_line(type_name, &buf).map(|item| (item, None)),
+            }
+        }
+    })
+    .boxed()
+}
+
+/// Parse a single line, returning `None` if it's blank.
+fn parse_line<T>(type_name: &'static str, line: &[u8]) -> Option<Result<T>>
+where
+    T: DeserializeOwned,
+{
+    let text = String::from_utf8_lossy(line);
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    Some(
+        serde_json::from_str::<T>(text)
+            .chain_err(|| ErrorKind::ParseError(type_name, text.to_owned())),
+    )
+}
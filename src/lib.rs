@@ -11,19 +11,43 @@ extern crate error_chain;
 extern crate serde_derive;
 
 // declare modules
+mod archive;
+pub mod attach;
+pub mod auth;
+pub mod build;
+#[cfg(feature = "checkpoint")]
+pub mod checkpoint;
+mod config;
 mod connector;
 pub mod container;
 mod docker;
+mod dockerignore;
 pub mod errors;
+pub mod event;
+pub mod exec;
 pub mod filesystem;
 pub mod image;
+mod json_lines;
+pub mod logs;
+pub mod network;
+pub mod node;
 mod options;
 pub mod process;
-//pub mod stats;
+pub mod progress;
+pub mod reference;
+pub mod secret;
+pub mod service;
+mod session;
+pub mod stats;
+pub mod swarm;
 pub mod system;
+pub mod task;
 mod test;
+#[cfg(feature = "chrono")]
+mod timestamps;
 //mod util;
 pub mod version;
+pub mod volume;
 
 // publicly re-export
 pub use crate::docker::Docker;
@@ -0,0 +1,287 @@
+//! Container log output, and the stream framing Docker uses to send it.
+
+use futures::stream::{self, Stream, StreamExt};
+use hyper::{header, Body, Response};
+use std::collections::HashMap;
+use url::form_urlencoded;
+
+use crate::errors::*;
+
+/// Which stream a chunk of output was written to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogStream {
+    Stdin,
+    Stdout,
+    Stderr,
+}
+
+/// A chunk of output from a container.  When Docker multiplexes stdout and
+/// stderr, each chunk corresponds to a single write by the container, which
+/// is usually (but not always) a single line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    pub stream: LogStream,
+    /// When the line was written, as an RFC 3339 timestamp.  Only present
+    /// if `LogOptions::timestamps` was specified.
+    pub timestamp: Option<String>,
+    pub bytes: Vec<u8>,
+}
+
+#[cfg(feature = "chrono")]
+impl LogLine {
+    /// When the line was written.  Only present if `LogOptions::timestamps`
+    /// was specified.
+    pub fn timestamp_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.timestamp
+            .as_deref()
+            .and_then(crate::timestamps::parse_rfc3339)
+    }
+}
+
+/// A chunk of output from one of a service's tasks, as returned by
+/// `Docker::service_logs` and `Docker::task_logs`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceLogLine {
+    /// What Docker told us about where the line came from, such as
+    /// `"com.docker.swarm.task.id"`, plus any labels or environment
+    /// variables the log driver was configured to include.
+    pub details: HashMap<String, String>,
+    pub line: LogLine,
+}
+
+impl ServiceLogLine {
+    /// Split the details off the start of a line.  Docker sends them as
+    /// comma-separated, URL-encoded `key=value` pairs followed by a space.
+    pub(crate) fn from_log_line(mut line: LogLine) -> ServiceLogLine {
+        let mut details = HashMap::new();
+        if let Some(space) = line.bytes.iter().position(|&b| b == b' ') {
+            for pair in line.bytes[..space].split(|&b| b == b',') {
+                details.extend(form_urlencoded::parse(pair).into_owned().take(1));
+            }
+            line.bytes.drain(..=space);
+        }
+        ServiceLogLine { details, line }
+    }
+
+    /// The ID of the task which wrote this line.
+    pub fn task_id(&self) -> Option<&str> {
+        self.detail("com.docker.swarm.task.id")
+    }
+
+    /// The ID of the node running the task.
+    pub fn node_id(&self) -> Option<&str> {
+        self.detail("com.docker.swarm.node.id")
+    }
+
+    /// The ID of the service.
+    pub fn service_id(&self) -> Option<&str> {
+        self.detail("com.docker.swarm.service.id")
+    }
+
+    fn detail(&self, name: &str) -> Option<&str> {
+        self.details.get(name).map(String::as_str)
+    }
+}
+
+/// How the data we're decoding is framed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Framing {
+    /// Each chunk is preceded by an 8-byte header containing the stream
+    /// type and the length of the chunk.
+    Multiplexed,
+    /// Containers with a TTY have no framing at all, and everything is
+    /// written to stdout.
+    Raw,
+}
+
+/// Size of the header on each chunk of a multiplexed stream.
+const HEADER_LEN: usize = 8;
+
+/// An incremental decoder for Docker's log stream format.
+#[derive(Debug, Default)]
+pub(crate) struct LogDecoder {
+    buf: Vec<u8>,
+    framing: Option<Framing>,
+    /// Does each line start with a timestamp?
+    timestamps: bool,
+    /// Does each line start with details, after any timestamp?
+    details: bool,
+}
+
+impl LogDecoder {
+    /// Create a decoder for the body of `response`.  Newer daemons tell us
+    /// via `Content-Type` whether the stream is multiplexed, and for older
+    /// ones we try to detect the framing from the data.
+    pub(crate) fn for_response(response: &Response<Body>) -> LogDecoder {
+        let content_type = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok());
+        LogDecoder::for_content_type(content_type)
+    }
+
+    /// Create a decoder for data sent with the specified `Content-Type`, if
+    /// Docker told us one.  Daemons before API 1.42 say `raw-stream` even
+    /// when the stream is multiplexed, so we only trust `multiplexed-stream`
+    /// and detect everything else.
+    pub(crate) fn for_content_type(content_type: Option<&str>) -> LogDecoder {
+        let framing = match content_type {
+            Some("application/vnd.docker.multiplexed-stream") => Some(Framing::Multiplexed),
+            _ => None,
+        };
+        LogDecoder {
+            buf: vec![],
+            framing,
+            timestamps: false,
+            details: false,
+        }
+    }
+
+    /// Split the timestamp Docker adds with `timestamps=1` off the start
+    /// of each line.  Unframed output is split into lines, because there's
+    /// a timestamp at the start of each.
+    pub(crate) fn with_timestamps(mut self, timestamps: bool) -> LogDecoder {
+        self.timestamps = timestamps;
+        self
+    }
+
+    /// Expect the details Docker adds with `details=1` at the start of each
+    /// line.  Like timestamps, this means unframed output must be split
+    /// into lines.  The details are left in place for `ServiceLogLine` to
+    /// parse.
+    pub(crate) fn with_details(mut self, details: bool) -> LogDecoder {
+        self.details = details;
+        self
+    }
+
+    /// Add more data to our buffer.
+    pub(crate) fn extend(&mut self, data: &[u8]) {
+        self.buf.extend_from_slice(data);
+    }
+
+    /// Return the next complete chunk of output, if we have one.
+    pub(crate) fn next_line(&mut self) -> Option<LogLine> {
+        if self.framing.is_none() {
+            self.framing = Some(detect_framing(&self.buf)?);
+        }
+        match self.framing {
+            Some(Framing::Multiplexed) => {
+                if self.buf.len() < HEADER_LEN {
+                    return None;
+                }
+                let len = u32::from_be_bytes([self.buf[4], self.buf[5], self.buf[6], self.buf[7]])
+                    as usize;
+                if self.buf.len() < HEADER_LEN + len {
+                    return None;
+                }
+                let stream = match self.buf[0] {
+                    0 => LogStream::Stdin,
+                    2 => LogStream::Stderr,
+                    _ => LogStream::Stdout,
+                };
+                let bytes = self.buf[HEADER_LEN..HEADER_LEN + len].to_vec();
+                self.buf.drain(..HEADER_LEN + len);
+                Some(self.make_line(stream, bytes))
+            }
+            _ if self.timestamps || self.details => {
+                let end = self.buf.iter().position(|&b| b == b'\n')?;
+                let bytes = self.buf.drain(..=end).collect();
+                Some(self.make_line(LogStream::Stdout, bytes))
+            }
+            _ => self.take_raw(),
+        }
+    }
+
+    /// Call this at the end of the stream to get any remaining output.
+    /// Incomplete multiplexed chunks are discarded.
+    pub(crate) fn finish(&mut self) -> Option<LogLine> {
+        match self.framing {
+            Some(Framing::Multiplexed) => None,
+            _ => self.take_raw(),
+        }
+    }
+
+    /// Return everything in our buffer as unframed stdout.
+    fn take_raw(&mut self) -> Option<LogLine> {
+        if self.buf.is_empty() {
+            None
+        } else {
+            let bytes = self.buf.split_off(0);
+            Some(self.make_line(LogStream::Stdout, bytes))
+        }
+    }
+
+    /// Build a `LogLine`, splitting off the timestamp if we have one.
+    fn make_line(&self, stream: LogStream, mut bytes: Vec<u8>) -> LogLine {
+        let mut timestamp = None;
+        if self.timestamps {
+            if let Some(space) = bytes.iter().position(|&b| b == b' ') {
+                timestamp = Some(String::from_utf8_lossy(&bytes[..space]).into_owned());
+                bytes.drain(..=space);
+            }
+        }
+        LogLine {
+            stream,
+            timestamp,
+            bytes,
+        }
+    }
+}
+
+/// Decode the body of `response` as a stream of log output, without
+/// buffering more than a single chunk in memory.  If `timestamps` is true,
+/// each line is expected to start with a timestamp.
+pub(crate) fn decode_log_stream(
+    response: Response<Body>,
+    timestamps: bool,
+) -> impl Stream<Item = Result<LogLine>> + Send + Unpin {
+    let decoder = LogDecoder::for_response(&response).with_timestamps(timestamps);
+    decode_with(decoder, response.into_body())
+}
+
+/// Decode the body of a `Docker::service_logs` or `Docker::task_logs`
+/// response, which must have been requested with `details=1`.
+pub(crate) fn decode_service_log_stream(
+    response: Response<Body>,
+    timestamps: bool,
+) -> impl Stream<Item = Result<ServiceLogLine>> + Send + Unpin {
+    let decoder = LogDecoder::for_response(&response)
+        .with_timestamps(timestamps)
+        .with_details(true);
+    decode_with(decoder, response.into_body()).map(|line| line.map(ServiceLogLine::from_log_line))
+}
+
+/// Run `decoder` over `body`.
+fn decode_with(
+    decoder: LogDecoder,
+    body: Body,
+) -> impl Stream<Item = Result<LogLine>> + Send + Unpin {
+    stream::unfold(Some((decoder, body)), |state| async move {
+        let (mut decoder, mut body) = state?;
+        loop {
+            if let Some(line) = decoder.next_line() {
+                return Some((Ok(line), Some((decoder, body))));
+            }
+            match body.next().await {
+                Some(Ok(chunk)) => decoder.extend(&chunk),
+                Some(Err(err)) => return Some((Err(err.into()), None)),
+                None => return decoder.finish().map(|line| (Ok(line), None)),
+            }
+        }
+    })
+    .boxed()
+}
+
+/// Guess whether `data` is multiplexed by looking at the first header.
+/// Headers start with a stream type of 0, 1 or 2, followed by three zero
+/// bytes, which is extremely unlikely for real terminal output.  Returns
+/// `None` if we don't have enough data to tell yet, but decides on `Raw` as
+/// soon as a byte rules out a header, so short output like a shell prompt
+/// isn't held back.
+fn detect_framing(data: &[u8]) -> Option<Framing> {
+    match data {
+        [0..=2, 0, 0, 0, ..] => Some(Framing::Multiplexed),
+        [] | [0..=2] | [0..=2, 0] | [0..=2, 0, 0] => None,
+        _ => Some(Framing::Raw),
+    }
+}
@@ -0,0 +1,150 @@
+//! Docker networks.
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+use crate::image::null_to_default;
+#[cfg(feature = "chrono")]
+use crate::timestamps;
+
+/// A network, as returned by `Docker::networks` and `Docker::network_info`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct Network {
+    pub Name: String,
+    pub Id: String,
+    /// An RFC 3339 timestamp.
+    #[serde(default)]
+    pub Created: String,
+    /// `"local"`, `"global"` or `"swarm"`.
+    pub Scope: String,
+    /// The network driver, such as `"bridge"` or `"overlay"`.
+    pub Driver: String,
+    #[serde(default)]
+    pub EnableIPv6: bool,
+    #[serde(default)]
+    pub IPAM: Option<Ipam>,
+    /// Can containers only talk to each other, and not the outside world?
+    #[serde(default)]
+    pub Internal: bool,
+    /// Can standalone containers attach to this swarm network?
+    #[serde(default)]
+    pub Attachable: bool,
+    #[serde(default)]
+    pub Ingress: bool,
+    /// The containers attached to this network, by ID.  Docker doesn't fill
+    /// this in when listing networks.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Containers: HashMap<String, NetworkContainer>,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Options: HashMap<String, String>,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Labels: HashMap<String, String>,
+    /// The nodes taking part in a swarm network.  Only filled in by
+    /// `Docker::network_info` with `verbose` set.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Peers: Vec<NetworkPeer>,
+}
+
+#[cfg(feature = "chrono")]
+impl Network {
+    /// When this network was created.
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        timestamps::parse_rfc3339(&self.Created)
+    }
+}
+
+/// How IP addresses are assigned on a network.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct Ipam {
+    /// The IPAM driver, usually `"default"`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub Driver: String,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Config: Vec<IpamConfig>,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub Options: HashMap<String, String>,
+}
+
+/// An address range used by a network.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct IpamConfig {
+    /// Such as `"172.17.0.0/16"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Subnet: Option<String>,
+    /// The part of `Subnet` from which container addresses are allocated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub IPRange: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Gateway: Option<String>,
+}
+
+impl IpamConfig {
+    /// An address range covering `subnet`, such as `"172.28.0.0/16"`.
+    pub fn new(subnet: &str) -> IpamConfig {
+        IpamConfig {
+            Subnet: Some(subnet.to_owned()),
+            ..IpamConfig::default()
+        }
+    }
+
+    /// Only allocate container addresses from `ip_range`, such as
+    /// `"172.28.5.0/24"`.
+    pub fn ip_range(mut self, ip_range: &str) -> IpamConfig {
+        self.IPRange = Some(ip_range.to_owned());
+        self
+    }
+
+    /// Use `gateway` as the gateway address, such as `"172.28.5.254"`.
+    pub fn gateway(mut self, gateway: &str) -> IpamConfig {
+        self.Gateway = Some(gateway.to_owned());
+        self
+    }
+}
+
+/// A container attached to a network.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct NetworkContainer {
+    pub Name: String,
+    pub EndpointID: String,
+    #[serde(default)]
+    pub MacAddress: String,
+    /// Such as `"172.17.0.2/16"`, or empty.
+    #[serde(default)]
+    pub IPv4Address: String,
+    #[serde(default)]
+    pub IPv6Address: String,
+}
+
+/// A swarm node taking part in a network.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct NetworkPeer {
+    pub Name: String,
+    pub IP: String,
+}
+
+/// The response to `Docker::create_network`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct CreateNetworkResponse {
+    pub Id: String,
+    #[serde(default)]
+    pub Warning: String,
+}
+
+/// The response to `Docker::prune_networks`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct PruneNetworksResponse {
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub NetworksDeleted: Vec<String>,
+}
@@ -0,0 +1,174 @@
+//! Docker swarm nodes.
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+use crate::image::null_to_default;
+use crate::service::Resources;
+use crate::swarm::{ObjectVersion, TlsInfo};
+#[cfg(feature = "chrono")]
+use crate::timestamps;
+
+/// A node, as returned by `Docker::nodes` and `Docker::node_info`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct Node {
+    pub ID: String,
+    /// Pass this to `Docker::update_node` along with a changed `Spec`.
+    pub Version: ObjectVersion,
+    /// An RFC 3339 timestamp.
+    pub CreatedAt: String,
+    /// An RFC 3339 timestamp.
+    pub UpdatedAt: String,
+    pub Spec: NodeSpec,
+    /// What the node reported about itself.
+    #[serde(default)]
+    pub Description: Option<NodeDescription>,
+    pub Status: NodeStatus,
+    /// Only present for managers.
+    #[serde(default)]
+    pub ManagerStatus: Option<ManagerStatus>,
+}
+
+#[cfg(feature = "chrono")]
+impl Node {
+    /// When this node joined the swarm.
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        timestamps::parse_rfc3339(&self.CreatedAt)
+    }
+
+    /// When this node was last updated.
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        timestamps::parse_rfc3339(&self.UpdatedAt)
+    }
+}
+
+/// The settings of a node, which can be changed with `Docker::update_node`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct NodeSpec {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub Name: String,
+    /// Labels used in placement constraints, like `node.labels.zone`.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Labels: HashMap<String, String>,
+    pub Role: NodeRole,
+    pub Availability: NodeAvailability,
+}
+
+/// Whether a node manages the swarm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeRole {
+    Worker,
+    Manager,
+    /// A role we don't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Whether tasks may be scheduled on a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeAvailability {
+    /// New tasks may be scheduled on the node.
+    Active,
+    /// No new tasks are scheduled, but existing tasks keep running.
+    Pause,
+    /// Tasks are moved to other nodes.
+    Drain,
+    /// An availability we don't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// What a node reported about itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct NodeDescription {
+    #[serde(default)]
+    pub Hostname: String,
+    #[serde(default)]
+    pub Platform: Option<NodePlatform>,
+    /// The CPU and memory available to tasks.
+    #[serde(default)]
+    pub Resources: Option<Resources>,
+    #[serde(default)]
+    pub Engine: Option<EngineDescription>,
+    #[serde(default)]
+    pub TLSInfo: Option<TlsInfo>,
+}
+
+/// The platform of a node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct NodePlatform {
+    /// Such as `"x86_64"`.
+    #[serde(default)]
+    pub Architecture: String,
+    /// Such as `"linux"`.
+    #[serde(default)]
+    pub OS: String,
+}
+
+/// The Docker engine running on a node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct EngineDescription {
+    #[serde(default)]
+    pub EngineVersion: String,
+    /// The engine's own labels, from `daemon.json`.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Labels: HashMap<String, String>,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Plugins: Vec<EnginePlugin>,
+}
+
+/// A plugin installed on a node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct EnginePlugin {
+    /// Such as `"Volume"` or `"Network"`.
+    pub Type: String,
+    pub Name: String,
+}
+
+/// Whether a node is reachable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct NodeStatus {
+    pub State: NodeState,
+    #[serde(default)]
+    pub Message: String,
+    /// The node's IP address.
+    #[serde(default)]
+    pub Addr: String,
+}
+
+/// Whether a node is reachable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeState {
+    Ready,
+    Down,
+    Disconnected,
+    /// A state we don't know about, including Docker's own `"unknown"`.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The status of a manager node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ManagerStatus {
+    /// Is this the Raft leader?
+    #[serde(default)]
+    pub Leader: bool,
+    /// `"reachable"`, `"unreachable"` or `"unknown"`.
+    #[serde(default)]
+    pub Reachability: String,
+    /// The address other managers use to reach this one.
+    #[serde(default)]
+    pub Addr: String,
+}
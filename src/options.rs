@@ -1,17 +1,51 @@
 //! Options which can be passed to various `Docker` commands.
 
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use url::form_urlencoded;
 
+use crate::auth::RegistryAuth;
+use crate::container::{
+    ContainerStatus, DeviceMapping, DeviceRequest, EndpointIpamConfig, HealthStatus, PortMapping,
+    RestartPolicy, UnspecifiedObject,
+};
+use crate::event::{EventAction, EventType};
+use crate::network::{Ipam, IpamConfig};
+use crate::session::SessionProviders;
+use crate::swarm::SwarmSpec;
+
+/// Filters which can be applied to list and prune operations.  These are
+/// passed to Docker as a JSON-encoded `filters` parameter.
+#[derive(Debug, Clone, Default)]
+struct Filters(BTreeMap<String, Vec<String>>);
+
+impl Filters {
+    /// Add a filter value.  Multiple values for the same key are allowed.
+    fn add(&mut self, key: &str, value: &str) {
+        self.0
+            .entry(key.to_owned())
+            .or_default()
+            .push(value.to_owned());
+    }
+
+    /// Append our `filters` parameter to `params`, if we have any filters.
+    fn append_to(&self, params: &mut form_urlencoded::Serializer<'_, String>) {
+        if !self.0.is_empty() {
+            let json = serde_json::to_string(&self.0).expect("could not serialize filters");
+            params.append_pair("filters", &json);
+        }
+    }
+}
+
 /// Options for `Docker::containers`.  This uses a "builder" pattern, so
 /// most methods will consume the object and return a new one.
 #[derive(Debug, Clone, Default)]
 pub struct ContainerListOptions {
     all: bool,
-    //before: Option<String>,
-    //filter: Filter,
+    filters: Filters,
     latest: bool,
     limit: Option<u64>,
-    //since: Option<String>,
     size: bool,
 }
 
@@ -35,10 +69,73 @@ impl ContainerListOptions {
         self
     }
 
-    /// Calculate the total file sizes for our containers.  **WARNING:**
-    /// This is very expensive.
-    pub fn size(mut self) -> Self {
-        self.size = true;
+    /// Only return containers created after the container with the
+    /// specified ID or name.
+    pub fn since(mut self, id: &str) -> Self {
+        self.filters.add("since", id);
+        self
+    }
+
+    /// Only return containers created before the container with the
+    /// specified ID or name.
+    pub fn before(mut self, id: &str) -> Self {
+        self.filters.add("before", id);
+        self
+    }
+
+    /// Calculate the total file sizes for our containers, and return them
+    /// in `SizeRw` and `SizeRootFs`.  **WARNING:** This is very expensive.
+    pub fn size(mut self, size: bool) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Only return containers with the specified label, either `"key"` or
+    /// `"key=value"`.
+    pub fn label(mut self, label: &str) -> Self {
+        self.filters.add("label", label);
+        self
+    }
+
+    /// Only return containers with the specified status.
+    pub fn status(mut self, status: ContainerStatus) -> Self {
+        self.filters.add("status", status.as_str());
+        self
+    }
+
+    /// Only return containers whose name contains `name`.
+    pub fn name(mut self, name: &str) -> Self {
+        self.filters.add("name", name);
+        self
+    }
+
+    /// Only return containers created from `image`, or from an image
+    /// descended from it.
+    pub fn ancestor(mut self, image: &str) -> Self {
+        self.filters.add("ancestor", image);
+        self
+    }
+
+    /// Only return containers connected to the specified network.
+    pub fn network(mut self, network: &str) -> Self {
+        self.filters.add("network", network);
+        self
+    }
+
+    /// Only return containers with the specified health status.
+    /// `HealthStatus::Unknown` can't be sent to Docker, so it's ignored.
+    pub fn health(mut self, health: HealthStatus) -> Self {
+        if let Some(health) = health.as_str() {
+            self.filters.add("health", health);
+        }
+        self
+    }
+
+    /// Only return containers which exited with the specified code.  This
+    /// implies `all`.
+    pub fn exited(mut self, code: i32) -> Self {
+        self.all = true;
+        self.filters.add("exited", &code.to_string());
         self
     }
 
@@ -48,6 +145,7 @@ impl ContainerListOptions {
         if self.all {
             params.append_pair("all", "1");
         }
+        self.filters.append_to(&mut params);
         if self.latest {
             params.append_pair("latest", "1");
         }
@@ -60,3 +158,2217 @@ impl ContainerListOptions {
         params.finish()
     }
 }
+
+/// Options for `Docker::remove_container`.
+#[derive(Debug, Clone, Default)]
+pub struct RemoveContainerOptions {
+    force: bool,
+    volumes: bool,
+    link: bool,
+}
+
+impl RemoveContainerOptions {
+    /// Kill the container first if it's running.
+    pub fn force(mut self) -> Self {
+        self.force = true;
+        self
+    }
+
+    /// Also remove any anonymous volumes associated with the container.
+    pub fn volumes(mut self) -> Self {
+        self.volumes = true;
+        self
+    }
+
+    /// Remove the specified link instead of the container itself.
+    pub fn link(mut self) -> Self {
+        self.link = true;
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        if self.force {
+            params.append_pair("force", "1");
+        }
+        if self.volumes {
+            params.append_pair("v", "1");
+        }
+        if self.link {
+            params.append_pair("link", "1");
+        }
+        params.finish()
+    }
+}
+
+/// Options for `Docker::create_container`.  This uses a "builder" pattern,
+/// so most methods will consume the object and return a new one.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ContainerCreateOptions {
+    /// Passed as a query parameter, not in the body.
+    #[serde(skip)]
+    name: Option<String>,
+    /// Passed as a query parameter, not in the body.
+    #[serde(skip)]
+    platform: Option<String>,
+    image: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    cmd: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    env: Vec<String>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    labels: HashMap<String, String>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    exposed_ports: HashMap<String, UnspecifiedObject>,
+    tty: bool,
+    open_stdin: bool,
+    host_config: HostConfigOptions,
+}
+
+/// The `HostConfig` section of a container creation request.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct HostConfigOptions {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    binds: Vec<String>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    port_bindings: HashMap<String, Vec<PortMapping>>,
+    publish_all_ports: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    restart_policy: Option<RestartPolicy>,
+    auto_remove: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    devices: Vec<DeviceMapping>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    cap_add: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    cap_drop: Vec<String>,
+    privileged: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    security_opt: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cgroup_parent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shm_size: Option<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    device_requests: Vec<DeviceRequest>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    mounts: Vec<MountSpec>,
+}
+
+/// A filesystem to mount in a container, passed to
+/// `ContainerCreateOptions::mount`.  This is the structured equivalent of
+/// `docker run --mount`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(into = "RawMount")]
+pub enum MountSpec {
+    /// Mount the host path `source` at `target`.
+    Bind {
+        source: String,
+        target: String,
+        read_only: bool,
+        /// Whether mounts made under `source` later are visible in the
+        /// container, and vice versa.  Defaults to `RPrivate`.
+        propagation: Option<BindPropagation>,
+    },
+    /// Mount a volume at `target`.  If `source` is `None`, Docker creates
+    /// an anonymous volume.  `driver` and `driver_opts` are used if the
+    /// volume has to be created.
+    Volume {
+        source: Option<String>,
+        target: String,
+        read_only: bool,
+        driver: Option<String>,
+        driver_opts: HashMap<String, String>,
+    },
+    /// Mount a new in-memory filesystem at `target`, limited to `size`
+    /// bytes, with permissions `mode` such as `0o1777`.
+    Tmpfs {
+        target: String,
+        size: Option<u64>,
+        mode: Option<u32>,
+    },
+}
+
+/// How mounts propagate between the host and a bind mount.  See
+/// `mount(8)` for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BindPropagation {
+    Private,
+    RPrivate,
+    Shared,
+    RShared,
+    Slave,
+    RSlave,
+}
+
+/// The wire format of `MountSpec`.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawMount {
+    #[serde(rename = "Type")]
+    kind: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
+    target: String,
+    read_only: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bind_options: Option<RawBindOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    volume_options: Option<RawVolumeOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tmpfs_options: Option<RawTmpfsOptions>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawBindOptions {
+    propagation: BindPropagation,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawVolumeOptions {
+    driver_config: RawDriverConfig,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawDriverConfig {
+    name: String,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    options: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawTmpfsOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<u32>,
+}
+
+impl From<MountSpec> for RawMount {
+    fn from(mount: MountSpec) -> Self {
+        match mount {
+            MountSpec::Bind {
+                source,
+                target,
+                read_only,
+                propagation,
+            } => RawMount {
+                kind: "bind",
+                source: Some(source),
+                target,
+                read_only,
+                bind_options: propagation.map(|propagation| RawBindOptions { propagation }),
+                ..RawMount::default()
+            },
+            MountSpec::Volume {
+                source,
+                target,
+                read_only,
+                driver,
+                driver_opts,
+            } => {
+                let volume_options = if driver.is_some() || !driver_opts.is_empty() {
+                    Some(RawVolumeOptions {
+                        driver_config: RawDriverConfig {
+                            name: driver.unwrap_or_else(|| "local".to_owned()),
+                            options: driver_opts,
+                        },
+                    })
+                } else {
+                    None
+                };
+                RawMount {
+                    kind: "volume",
+                    source,
+                    target,
+                    read_only,
+                    volume_options,
+                    ..RawMount::default()
+                }
+            }
+            MountSpec::Tmpfs { target, size, mode } => RawMount {
+                kind: "tmpfs",
+                target,
+                tmpfs_options: if size.is_some() || mode.is_some() {
+                    Some(RawTmpfsOptions {
+                        size_bytes: size,
+                        mode,
+                    })
+                } else {
+                    None
+                },
+                ..RawMount::default()
+            },
+        }
+    }
+}
+
+/// A network protocol which can be used by a port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    Tcp,
+    Udp,
+    Sctp,
+}
+
+impl Protocol {
+    /// The name Docker uses for this protocol.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Protocol::Tcp => "tcp",
+            Protocol::Udp => "udp",
+            Protocol::Sctp => "sctp",
+        }
+    }
+}
+
+/// A signal which can be sent to a container with `Docker::kill_container`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    Hup,
+    Int,
+    Quit,
+    Kill,
+    Usr1,
+    Usr2,
+    Term,
+    Cont,
+    Stop,
+    Winch,
+    /// A signal number, for signals not listed here.
+    Number(u32),
+}
+
+impl std::fmt::Display for Signal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Signal::Hup => "SIGHUP",
+            Signal::Int => "SIGINT",
+            Signal::Quit => "SIGQUIT",
+            Signal::Kill => "SIGKILL",
+            Signal::Usr1 => "SIGUSR1",
+            Signal::Usr2 => "SIGUSR2",
+            Signal::Term => "SIGTERM",
+            Signal::Cont => "SIGCONT",
+            Signal::Stop => "SIGSTOP",
+            Signal::Winch => "SIGWINCH",
+            Signal::Number(n) => return write!(f, "{}", n),
+        };
+        f.write_str(name)
+    }
+}
+
+/// Format a port the way Docker does in `ExposedPorts` and `PortBindings`.
+fn port_key(port: u16, protocol: Protocol) -> String {
+    format!("{}/{}", port, protocol.as_str())
+}
+
+impl ContainerCreateOptions {
+    /// Create a new container based on `image`.
+    pub fn new(image: &str) -> Self {
+        ContainerCreateOptions {
+            image: image.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// Run `cmd` instead of the image's default command.
+    pub fn cmd<S: AsRef<str>>(mut self, cmd: &[S]) -> Self {
+        self.cmd = cmd.iter().map(|s| s.as_ref().to_owned()).collect();
+        self
+    }
+
+    /// Set the environment variable `name` to `value`.
+    pub fn env(mut self, name: &str, value: &str) -> Self {
+        self.env.push(format!("{}={}", name, value));
+        self
+    }
+
+    /// Add a label to the container.
+    pub fn label(mut self, name: &str, value: &str) -> Self {
+        self.labels.insert(name.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Expose a port, specified as `"80/tcp"` or `"53/udp"`.
+    pub fn expose(mut self, port: &str) -> Self {
+        self.exposed_ports
+            .insert(port.to_owned(), UnspecifiedObject::new());
+        self
+    }
+
+    /// Expose `port` using `protocol`.
+    pub fn expose_port(self, port: u16, protocol: Protocol) -> Self {
+        self.expose(&port_key(port, protocol))
+    }
+
+    /// Publish `container_port` as `host_port` on all host interfaces,
+    /// like `docker run -p 8080:80`.  Use a `host_port` of 0 to let Docker
+    /// pick a free port.
+    pub fn publish(self, host_port: u16, container_port: u16, protocol: Protocol) -> Self {
+        self.publish_on("", host_port, container_port, protocol)
+    }
+
+    /// Publish `container_port` as `host_port` on the host interface with
+    /// the address `host_ip`, like `docker run -p 127.0.0.1:8080:80`.
+    pub fn publish_on(
+        mut self,
+        host_ip: &str,
+        host_port: u16,
+        container_port: u16,
+        protocol: Protocol,
+    ) -> Self {
+        let key = port_key(container_port, protocol);
+        let binding = PortMapping {
+            HostIp: host_ip.to_owned(),
+            HostPort: if host_port == 0 {
+                String::new()
+            } else {
+                host_port.to_string()
+            },
+        };
+        self.host_config
+            .port_bindings
+            .entry(key.clone())
+            .or_default()
+            .push(binding);
+        self.expose(&key)
+    }
+
+    /// Publish all exposed ports on random host ports, like `docker run -P`.
+    pub fn publish_all(mut self) -> Self {
+        self.host_config.publish_all_ports = true;
+        self
+    }
+
+    /// Set the restart policy.
+    pub fn restart_policy(mut self, policy: RestartPolicy) -> Self {
+        self.host_config.restart_policy = Some(policy);
+        self
+    }
+
+    /// Give the container a name.  Creation will fail if another
+    /// container already has this name.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    /// Use the image for `platform`, such as `"linux/arm64"`, instead of
+    /// the one for the daemon's native platform.  The image must already
+    /// have been pulled for this platform.
+    pub fn platform(mut self, platform: &str) -> Self {
+        self.platform = Some(platform.to_owned());
+        self
+    }
+
+    /// Remove the container automatically when it exits.  This can't be
+    /// combined with a restart policy.
+    ///
+    /// Once the container has exited, it may disappear at any moment, so
+    /// calls like `Docker::logs` may fail.  To find out when it is gone,
+    /// use `Docker::wait_container` with `WaitCondition::Removed`, which
+    /// still reports the exit status, and call it before starting the
+    /// container so that a fast exit can't be missed.
+    pub fn auto_remove(mut self) -> Self {
+        self.host_config.auto_remove = true;
+        self
+    }
+
+    /// Make the host device `host_path` available inside the container as
+    /// `container_path`.  `permissions` is a combination of `r`, `w` and
+    /// `m` (mknod), such as `"rwm"`.
+    pub fn device(mut self, host_path: &str, container_path: &str, permissions: &str) -> Self {
+        self.host_config.devices.push(DeviceMapping {
+            PathOnHost: host_path.to_owned(),
+            PathInContainer: container_path.to_owned(),
+            CgroupPermissions: permissions.to_owned(),
+        });
+        self
+    }
+
+    /// Request devices from a device driver.  To use NVIDIA GPUs, pass
+    /// `DeviceRequest::gpus(-1)` or `DeviceRequest::gpu_ids(&["0"])`.
+    pub fn device_request(mut self, request: DeviceRequest) -> Self {
+        self.host_config.device_requests.push(request);
+        self
+    }
+
+    /// Add a kernel capability, such as `"NET_ADMIN"`, to the container.
+    pub fn cap_add(mut self, capability: &str) -> Self {
+        self.host_config.cap_add.push(capability.to_owned());
+        self
+    }
+
+    /// Remove a kernel capability, such as `"MKNOD"`, from the container.
+    pub fn cap_drop(mut self, capability: &str) -> Self {
+        self.host_config.cap_drop.push(capability.to_owned());
+        self
+    }
+
+    /// Give the container full access to the host, including all devices.
+    pub fn privileged(mut self) -> Self {
+        self.host_config.privileged = true;
+        self
+    }
+
+    /// Add a security option, such as `"seccomp=unconfined"` or
+    /// `"no-new-privileges"`.
+    pub fn security_opt(mut self, opt: &str) -> Self {
+        self.host_config.security_opt.push(opt.to_owned());
+        self
+    }
+
+    /// Run the container under the cgroup `parent`.
+    pub fn cgroup_parent(mut self, parent: &str) -> Self {
+        self.host_config.cgroup_parent = Some(parent.to_owned());
+        self
+    }
+
+    /// Set the size of `/dev/shm`, in bytes.
+    pub fn shm_size(mut self, bytes: u64) -> Self {
+        self.host_config.shm_size = Some(bytes);
+        self
+    }
+
+    /// Allocate a pseudo-TTY for the container.
+    pub fn tty(mut self) -> Self {
+        self.tty = true;
+        self
+    }
+
+    /// Keep stdin open, so that it can be written to using
+    /// `Docker::attach_container`.
+    pub fn open_stdin(mut self) -> Self {
+        self.open_stdin = true;
+        self
+    }
+
+    /// Bind-mount a host path into the container, using the same
+    /// `"/host/path:/container/path[:ro]"` syntax as `docker run -v`.
+    pub fn bind(mut self, bind: &str) -> Self {
+        self.host_config.binds.push(bind.to_owned());
+        self
+    }
+
+    /// Mount a bind mount, volume or tmpfs in the container.
+    pub fn mount(mut self, mount: MountSpec) -> Self {
+        self.host_config.mounts.push(mount);
+        self
+    }
+
+    /// Convert the options which are passed as query parameters to URL
+    /// parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        if let Some(ref name) = self.name {
+            params.append_pair("name", name);
+        }
+        if let Some(ref platform) = self.platform {
+            params.append_pair("platform", platform);
+        }
+        params.finish()
+    }
+}
+
+/// Options for `Docker::update_container`.  Only the fields which are set
+/// will be changed.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateContainerOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cpu_shares: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cpu_period: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cpu_quota: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    memory: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    memory_swap: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    memory_reservation: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    restart_policy: Option<RestartPolicy>,
+}
+
+impl UpdateContainerOptions {
+    /// Set the relative CPU weight of this container.
+    pub fn cpu_shares(mut self, shares: u64) -> Self {
+        self.cpu_shares = Some(shares);
+        self
+    }
+
+    /// Set the CFS scheduler period, in microseconds.
+    pub fn cpu_period(mut self, period: u64) -> Self {
+        self.cpu_period = Some(period);
+        self
+    }
+
+    /// Set the CFS scheduler quota, in microseconds per `cpu_period`.  Use
+    /// `-1` for no limit.
+    pub fn cpu_quota(mut self, quota: i64) -> Self {
+        self.cpu_quota = Some(quota);
+        self
+    }
+
+    /// Set the memory limit, in bytes.
+    pub fn memory(mut self, bytes: u64) -> Self {
+        self.memory = Some(bytes);
+        self
+    }
+
+    /// Set the total memory plus swap limit, in bytes.  Use `-1` for
+    /// unlimited swap.
+    pub fn memory_swap(mut self, bytes: i64) -> Self {
+        self.memory_swap = Some(bytes);
+        self
+    }
+
+    /// Set the soft memory limit, in bytes.
+    pub fn memory_reservation(mut self, bytes: u64) -> Self {
+        self.memory_reservation = Some(bytes);
+        self
+    }
+
+    /// Set the restart policy.
+    pub fn restart_policy(mut self, policy: RestartPolicy) -> Self {
+        self.restart_policy = Some(policy);
+        self
+    }
+}
+
+/// What `Docker::wait_container` should wait for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WaitCondition {
+    /// Wait until the container is not running (returning immediately if
+    /// it has already stopped).
+    #[default]
+    NotRunning,
+    /// Wait for the next time the container exits.
+    NextExit,
+    /// Wait until the container has been removed.  This is useful with
+    /// `ContainerCreateOptions::auto_remove`.
+    Removed,
+}
+
+impl WaitCondition {
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let condition = match self {
+            WaitCondition::NotRunning => "not-running",
+            WaitCondition::NextExit => "next-exit",
+            WaitCondition::Removed => "removed",
+        };
+        form_urlencoded::Serializer::new(String::new())
+            .append_pair("condition", condition)
+            .finish()
+    }
+}
+
+/// Options for `Docker::prune_containers`.
+#[derive(Debug, Clone, Default)]
+pub struct PruneContainersOptions {
+    filters: Filters,
+}
+
+impl PruneContainersOptions {
+    /// Only prune containers created before `timestamp`, which may be a
+    /// Unix timestamp, a date or a duration like `"24h"`.
+    pub fn until(mut self, timestamp: &str) -> Self {
+        self.filters.add("until", timestamp);
+        self
+    }
+
+    /// Only prune containers with the specified label, either `"key"` or
+    /// `"key=value"`.
+    pub fn label(mut self, label: &str) -> Self {
+        self.filters.add("label", label);
+        self
+    }
+
+    /// Only prune containers without the specified label.
+    pub fn label_not(mut self, label: &str) -> Self {
+        self.filters.add("label!", label);
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        self.filters.append_to(&mut params);
+        params.finish()
+    }
+}
+
+/// Options for `Docker::logs`.
+#[derive(Debug, Clone)]
+pub struct LogOptions {
+    stdout: bool,
+    stderr: bool,
+    follow: bool,
+    since: Option<SystemTime>,
+    until: Option<SystemTime>,
+    tail: Option<u64>,
+    timestamps: bool,
+}
+
+impl Default for LogOptions {
+    fn default() -> Self {
+        LogOptions {
+            stdout: true,
+            stderr: true,
+            follow: false,
+            since: None,
+            until: None,
+            tail: None,
+            timestamps: false,
+        }
+    }
+}
+
+impl LogOptions {
+    /// Should we include stdout?  Defaults to true.
+    pub fn stdout(mut self, stdout: bool) -> Self {
+        self.stdout = stdout;
+        self
+    }
+
+    /// Should we include stderr?  Defaults to true.
+    pub fn stderr(mut self, stderr: bool) -> Self {
+        self.stderr = stderr;
+        self
+    }
+
+    /// Keep the connection open and return new output as it is written.
+    /// This should normally be used with `Docker::logs_stream`.
+    pub fn follow(mut self) -> Self {
+        self.follow = true;
+        self
+    }
+
+    /// Only return output written at or after `since`.
+    pub fn since(mut self, since: SystemTime) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Only return output written before `until`.
+    pub fn until(mut self, until: SystemTime) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Only return the last `lines` lines of existing output.
+    pub fn tail(mut self, lines: u64) -> Self {
+        self.tail = Some(lines);
+        self
+    }
+
+    /// Ask Docker to record when each line was written in
+    /// `LogLine::timestamp`.
+    pub fn timestamps(mut self) -> Self {
+        self.timestamps = true;
+        self
+    }
+
+    /// Did we ask for timestamps?
+    pub(crate) fn has_timestamps(&self) -> bool {
+        self.timestamps
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        if self.stdout {
+            params.append_pair("stdout", "1");
+        }
+        if self.stderr {
+            params.append_pair("stderr", "1");
+        }
+        if self.follow {
+            params.append_pair("follow", "1");
+        }
+        if let Some(since) = self.since {
+            params.append_pair("since", &unix_timestamp(since));
+        }
+        if let Some(until) = self.until {
+            params.append_pair("until", &unix_timestamp(until));
+        }
+        if let Some(tail) = self.tail {
+            params.append_pair("tail", &tail.to_string());
+        }
+        if self.timestamps {
+            params.append_pair("timestamps", "1");
+        }
+        params.finish()
+    }
+}
+
+/// Options for `Docker::events`.
+#[derive(Debug, Clone, Default)]
+pub struct EventOptions {
+    since: Option<SystemTime>,
+    until: Option<SystemTime>,
+    filters: Filters,
+}
+
+impl EventOptions {
+    /// Resume an event stream which was interrupted, returning every event
+    /// after `last_seen`, which should come from `EventStream::last_seen`.
+    /// Docker's `since` includes events at exactly that time, so we start
+    /// one nanosecond later to avoid returning the last event twice.
+    pub fn resume_from(last_seen: SystemTime) -> Self {
+        EventOptions::default().since(last_seen + Duration::from_nanos(1))
+    }
+
+    /// Also return past events which happened at or after `since`.
+    pub fn since(mut self, since: SystemTime) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Stop returning events at `until`.  Otherwise, the stream runs
+    /// until the connection is closed.
+    pub fn until(mut self, until: SystemTime) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Only return events about the specified type of object.
+    /// `EventType::Unknown` can't be sent to Docker, so it's ignored.
+    pub fn event_type(mut self, event_type: EventType) -> Self {
+        if event_type != EventType::Unknown {
+            self.filters.add("type", &event_type.to_string());
+        }
+        self
+    }
+
+    /// Only return events about the specified container, by name or ID.
+    pub fn container(mut self, container: &str) -> Self {
+        self.filters.add("container", container);
+        self
+    }
+
+    /// Only return events about the specified image, by name or ID.
+    pub fn image(mut self, image: &str) -> Self {
+        self.filters.add("image", image);
+        self
+    }
+
+    /// Only return events about objects with the specified label, either
+    /// `"key"` or `"key=value"`.
+    pub fn label(mut self, label: &str) -> Self {
+        self.filters.add("label", label);
+        self
+    }
+
+    /// Only return events with the specified action.  Use
+    /// `EventAction::Other` to match an action we don't know about.  An
+    /// unknown `HealthStatus` matches every `health_status` event.
+    pub fn event(mut self, event: EventAction) -> Self {
+        match event {
+            EventAction::HealthStatus(HealthStatus::Unknown) => {
+                self.filters.add("event", "health_status")
+            }
+            event => self.filters.add("event", &event.to_string()),
+        }
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        if let Some(since) = self.since {
+            params.append_pair("since", &unix_timestamp(since));
+        }
+        if let Some(until) = self.until {
+            params.append_pair("until", &unix_timestamp(until));
+        }
+        self.filters.append_to(&mut params);
+        params.finish()
+    }
+}
+
+/// Format `time` as seconds since the Unix epoch, with a fractional part,
+/// which is how Docker expects timestamps in query parameters.
+fn unix_timestamp(time: SystemTime) -> String {
+    let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    format!(
+        "{}.{:09}",
+        since_epoch.as_secs(),
+        since_epoch.subsec_nanos()
+    )
+}
+
+/// Options for `Docker::attach_container`.
+#[derive(Debug, Clone)]
+pub struct AttachOptions {
+    stdin: bool,
+    stdout: bool,
+    stderr: bool,
+    logs: bool,
+    detach_keys: Option<String>,
+}
+
+impl Default for AttachOptions {
+    fn default() -> Self {
+        AttachOptions {
+            stdin: false,
+            stdout: true,
+            stderr: true,
+            logs: false,
+            detach_keys: None,
+        }
+    }
+}
+
+impl AttachOptions {
+    /// Should we attach to stdin?  Defaults to false.  The container must
+    /// have been created with `OpenStdin` for this to be useful.
+    pub fn stdin(mut self, stdin: bool) -> Self {
+        self.stdin = stdin;
+        self
+    }
+
+    /// Should we attach to stdout?  Defaults to true.
+    pub fn stdout(mut self, stdout: bool) -> Self {
+        self.stdout = stdout;
+        self
+    }
+
+    /// Should we attach to stderr?  Defaults to true.
+    pub fn stderr(mut self, stderr: bool) -> Self {
+        self.stderr = stderr;
+        self
+    }
+
+    /// Replay the existing logs before streaming new output.
+    pub fn logs(mut self) -> Self {
+        self.logs = true;
+        self
+    }
+
+    /// Override the key sequence which detaches from the container, such
+    /// as `"ctrl-x,x"`.  By default, we use `detachKeys` from the `docker`
+    /// client's `config.json`, or Docker's default of `"ctrl-p,ctrl-q"`.
+    pub fn detach_keys(mut self, keys: &str) -> Self {
+        self.detach_keys = Some(keys.to_owned());
+        self
+    }
+
+    /// Have detach keys been specified?
+    pub(crate) fn has_detach_keys(&self) -> bool {
+        self.detach_keys.is_some()
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        params.append_pair("stream", "1");
+        if self.stdin {
+            params.append_pair("stdin", "1");
+        }
+        if self.stdout {
+            params.append_pair("stdout", "1");
+        }
+        if self.stderr {
+            params.append_pair("stderr", "1");
+        }
+        if self.logs {
+            params.append_pair("logs", "1");
+        }
+        if let Some(ref keys) = self.detach_keys {
+            params.append_pair("detachKeys", keys);
+        }
+        params.finish()
+    }
+}
+
+/// Options for `Docker::stats`.
+#[derive(Debug, Clone)]
+pub struct StatsOptions {
+    stream: bool,
+}
+
+impl Default for StatsOptions {
+    fn default() -> Self {
+        StatsOptions { stream: true }
+    }
+}
+
+impl StatsOptions {
+    /// Should we keep returning new samples until the container exits?
+    /// Defaults to true.  If false, return a single sample.
+    pub fn stream(mut self, stream: bool) -> Self {
+        self.stream = stream;
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        params.append_pair("stream", if self.stream { "1" } else { "0" });
+        params.finish()
+    }
+}
+
+/// Options for `Docker::put_archive`.
+#[derive(Debug, Clone, Default)]
+pub struct PutArchiveOptions {
+    no_overwrite_dir_non_dir: bool,
+    copy_uid_gid: bool,
+}
+
+impl PutArchiveOptions {
+    /// Fail if the archive would replace a directory with a non-directory
+    /// or vice versa.
+    pub fn no_overwrite_dir_non_dir(mut self) -> Self {
+        self.no_overwrite_dir_non_dir = true;
+        self
+    }
+
+    /// Copy the UID and GID from the archive, instead of using the
+    /// container's default user.
+    pub fn copy_uid_gid(mut self) -> Self {
+        self.copy_uid_gid = true;
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        if self.no_overwrite_dir_non_dir {
+            params.append_pair("noOverwriteDirNonDir", "1");
+        }
+        if self.copy_uid_gid {
+            params.append_pair("copyUIDGID", "1");
+        }
+        params.finish()
+    }
+}
+
+/// Options for `Docker::run`.
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    create: ContainerCreateOptions,
+    remove: bool,
+}
+
+impl RunOptions {
+    /// Run a container created using `create`.
+    pub fn new(create: ContainerCreateOptions) -> Self {
+        RunOptions {
+            create,
+            remove: false,
+        }
+    }
+
+    /// Remove the container once it has exited and we have its logs, like
+    /// `docker run --rm`.
+    pub fn remove(mut self) -> Self {
+        self.remove = true;
+        self
+    }
+
+    /// Split into our parts.
+    pub(crate) fn into_parts(self) -> (ContainerCreateOptions, bool) {
+        (self.create, self.remove)
+    }
+}
+
+/// Options for `Docker::create_checkpoint`.
+#[cfg(feature = "checkpoint")]
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CheckpointCreateOptions {
+    #[serde(rename = "CheckpointID")]
+    checkpoint_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    checkpoint_dir: Option<String>,
+    exit: bool,
+}
+
+#[cfg(feature = "checkpoint")]
+impl CheckpointCreateOptions {
+    /// Create a checkpoint named `checkpoint_id`.
+    pub fn new(checkpoint_id: &str) -> Self {
+        CheckpointCreateOptions {
+            checkpoint_id: checkpoint_id.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// Store the checkpoint in `dir` instead of the default location.
+    pub fn checkpoint_dir(mut self, dir: &str) -> Self {
+        self.checkpoint_dir = Some(dir.to_owned());
+        self
+    }
+
+    /// Stop the container after creating the checkpoint.
+    pub fn exit(mut self) -> Self {
+        self.exit = true;
+        self
+    }
+}
+
+/// Options for `Docker::processes`.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessesOptions {
+    ps_args: Option<String>,
+}
+
+impl ProcessesOptions {
+    /// Pass `args` to `ps` instead of the default of `-ef`, for example
+    /// `"-eo pid,comm,etime"`.
+    pub fn ps_args(mut self, args: &str) -> Self {
+        self.ps_args = Some(args.to_owned());
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        if let Some(ref ps_args) = self.ps_args {
+            params.append_pair("ps_args", ps_args);
+        }
+        params.finish()
+    }
+}
+
+/// Options for `Docker::images`.
+#[derive(Debug, Clone, Default)]
+pub struct ImageListOptions {
+    all: bool,
+    digests: bool,
+    filters: Filters,
+}
+
+impl ImageListOptions {
+    /// Return all images, including intermediate images.
+    pub fn all(mut self) -> Self {
+        self.all = true;
+        self
+    }
+
+    /// Return the digests of each image in `RepoDigests`.
+    pub fn digests(mut self) -> Self {
+        self.digests = true;
+        self
+    }
+
+    /// Only return untagged images (if `true`), or only tagged ones.
+    pub fn dangling(mut self, dangling: bool) -> Self {
+        self.filters.add("dangling", &dangling.to_string());
+        self
+    }
+
+    /// Only return images with the specified label, either `"key"` or
+    /// `"key=value"`.
+    pub fn label(mut self, label: &str) -> Self {
+        self.filters.add("label", label);
+        self
+    }
+
+    /// Only return images whose name matches `reference`, such as
+    /// `"alpine"` or `"example/*:1.*"`.
+    pub fn reference(mut self, reference: &str) -> Self {
+        self.filters.add("reference", reference);
+        self
+    }
+
+    /// Only return images created before the image with the specified ID
+    /// or name.
+    pub fn before(mut self, image: &str) -> Self {
+        self.filters.add("before", image);
+        self
+    }
+
+    /// Only return images created after the image with the specified ID
+    /// or name.
+    pub fn since(mut self, image: &str) -> Self {
+        self.filters.add("since", image);
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        if self.all {
+            params.append_pair("all", "1");
+        }
+        if self.digests {
+            params.append_pair("digests", "1");
+        }
+        self.filters.append_to(&mut params);
+        params.finish()
+    }
+}
+
+/// Options for `Docker::remove_image`.
+#[derive(Debug, Clone, Default)]
+pub struct RemoveImageOptions {
+    force: bool,
+    no_prune: bool,
+}
+
+impl RemoveImageOptions {
+    /// Remove the image even if it is used by stopped containers or has
+    /// other tags.
+    pub fn force(mut self) -> Self {
+        self.force = true;
+        self
+    }
+
+    /// Don't delete untagged parent images.
+    pub fn no_prune(mut self) -> Self {
+        self.no_prune = true;
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        if self.force {
+            params.append_pair("force", "1");
+        }
+        if self.no_prune {
+            params.append_pair("noprune", "1");
+        }
+        params.finish()
+    }
+}
+
+/// Options for `Docker::search_images`.
+#[derive(Debug, Clone, Default)]
+pub struct SearchOptions {
+    limit: Option<u64>,
+    filters: Filters,
+}
+
+impl SearchOptions {
+    /// Return at most `limit` results.
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Only return official images, or only unofficial ones.
+    pub fn official(mut self, official: bool) -> Self {
+        self.filters.add("is-official", &official.to_string());
+        self
+    }
+
+    /// Only return images with at least `stars` stars.
+    pub fn stars(mut self, stars: u64) -> Self {
+        self.filters.add("stars", &stars.to_string());
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        if let Some(limit) = self.limit {
+            params.append_pair("limit", &limit.to_string());
+        }
+        self.filters.append_to(&mut params);
+        params.finish()
+    }
+}
+
+/// Options for `Docker::pull_image`.
+#[derive(Debug, Clone, Default)]
+pub struct PullImageOptions {
+    platform: Option<String>,
+}
+
+impl PullImageOptions {
+    /// Pull the image for `platform`, such as `"linux/arm64"`, instead of
+    /// the one for the daemon's native platform.
+    pub fn platform(mut self, platform: &str) -> Self {
+        self.platform = Some(platform.to_owned());
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        if let Some(ref platform) = self.platform {
+            params.append_pair("platform", platform);
+        }
+        params.finish()
+    }
+}
+
+/// Options for `Docker::build_image`.
+#[derive(Debug, Clone, Default)]
+pub struct BuildOptions {
+    dockerfile: Option<String>,
+    tags: Vec<String>,
+    quiet: bool,
+    keep_intermediate: bool,
+    force_rm: bool,
+    build_args: BTreeMap<String, String>,
+    labels: BTreeMap<String, String>,
+    target: Option<String>,
+    cache_from: Vec<String>,
+    pull: bool,
+    no_cache: bool,
+    buildkit: bool,
+    session: SessionProviders,
+}
+
+impl BuildOptions {
+    /// The path to the Dockerfile within the build context.  Defaults to
+    /// `Dockerfile`.
+    pub fn dockerfile(mut self, path: &str) -> Self {
+        self.dockerfile = Some(path.to_owned());
+        self
+    }
+
+    /// Tag the image as `name`, which may include a tag, such as
+    /// `"example/app:1.0"`.  May be called more than once.
+    pub fn tag(mut self, name: &str) -> Self {
+        self.tags.push(name.to_owned());
+        self
+    }
+
+    /// Only report the ID of the new image, not the build output.
+    pub fn quiet(mut self) -> Self {
+        self.quiet = true;
+        self
+    }
+
+    /// Keep intermediate containers after a successful build.
+    pub fn keep_intermediate(mut self) -> Self {
+        self.keep_intermediate = true;
+        self
+    }
+
+    /// Remove intermediate containers even if the build fails.
+    pub fn force_rm(mut self) -> Self {
+        self.force_rm = true;
+        self
+    }
+
+    /// Set the build argument `name`, which can be used by `ARG`
+    /// instructions in the Dockerfile.
+    pub fn build_arg(mut self, name: &str, value: &str) -> Self {
+        self.build_args.insert(name.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Add a label to the image.
+    pub fn label(mut self, name: &str, value: &str) -> Self {
+        self.labels.insert(name.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Build the stage named `target` of a multi-stage Dockerfile.
+    pub fn target(mut self, target: &str) -> Self {
+        self.target = Some(target.to_owned());
+        self
+    }
+
+    /// Use `image` as a source of cached layers.  May be called more than
+    /// once.
+    pub fn cache_from(mut self, image: &str) -> Self {
+        self.cache_from.push(image.to_owned());
+        self
+    }
+
+    /// Always pull newer versions of base images.
+    pub fn pull(mut self) -> Self {
+        self.pull = true;
+        self
+    }
+
+    /// Don't use cached layers.
+    pub fn no_cache(mut self) -> Self {
+        self.no_cache = true;
+        self
+    }
+
+    /// Build with BuildKit instead of the classic builder.  Progress is
+    /// reported using `ProgressEvent::trace`.
+    pub fn buildkit(mut self) -> Self {
+        self.buildkit = true;
+        self
+    }
+
+    /// Make the contents of the file at `path` available to
+    /// `RUN --mount=type=secret,id=<id>` instructions, without storing it
+    /// in the image.  Implies `buildkit`.
+    pub fn secret(mut self, id: &str, path: impl AsRef<Path>) -> Self {
+        self.buildkit = true;
+        self.session
+            .secrets
+            .insert(id.to_owned(), path.as_ref().to_owned());
+        self
+    }
+
+    /// Forward the SSH agent listening on `socket` to
+    /// `RUN --mount=type=ssh,id=<id>` instructions.  Use the ID `"default"`
+    /// for instructions which don't specify one.  To forward your own
+    /// agent, pass the value of `SSH_AUTH_SOCK`.  Implies `buildkit`.
+    pub fn ssh(mut self, id: &str, socket: impl AsRef<Path>) -> Self {
+        self.buildkit = true;
+        self.session
+            .ssh
+            .insert(id.to_owned(), socket.as_ref().to_owned());
+        self
+    }
+
+    /// Are we building with BuildKit?
+    pub(crate) fn uses_buildkit(&self) -> bool {
+        self.buildkit
+    }
+
+    /// The secrets and SSH agents to make available to the build.
+    pub(crate) fn session_providers(&self) -> &SessionProviders {
+        &self.session
+    }
+
+    /// The path to the Dockerfile within the build context.
+    pub(crate) fn dockerfile_path(&self) -> &str {
+        self.dockerfile.as_deref().unwrap_or("Dockerfile")
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        if let Some(ref dockerfile) = self.dockerfile {
+            params.append_pair("dockerfile", dockerfile);
+        }
+        for tag in &self.tags {
+            params.append_pair("t", tag);
+        }
+        if self.quiet {
+            params.append_pair("q", "1");
+        }
+        if self.keep_intermediate {
+            params.append_pair("rm", "0");
+        }
+        if self.force_rm {
+            params.append_pair("forcerm", "1");
+        }
+        if !self.build_args.is_empty() {
+            let json = serde_json::to_string(&self.build_args).expect("could not encode JSON");
+            params.append_pair("buildargs", &json);
+        }
+        if !self.labels.is_empty() {
+            let json = serde_json::to_string(&self.labels).expect("could not encode JSON");
+            params.append_pair("labels", &json);
+        }
+        if let Some(ref target) = self.target {
+            params.append_pair("target", target);
+        }
+        if !self.cache_from.is_empty() {
+            let json = serde_json::to_string(&self.cache_from).expect("could not encode JSON");
+            params.append_pair("cachefrom", &json);
+        }
+        if self.pull {
+            params.append_pair("pull", "1");
+        }
+        if self.no_cache {
+            params.append_pair("nocache", "1");
+        }
+        if self.buildkit {
+            params.append_pair("version", "2");
+        }
+        params.finish()
+    }
+}
+
+/// Options for `Docker::networks`.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkListOptions {
+    filters: Filters,
+}
+
+impl NetworkListOptions {
+    /// Only return networks using the specified driver, such as
+    /// `"bridge"`.
+    pub fn driver(mut self, driver: &str) -> Self {
+        self.filters.add("driver", driver);
+        self
+    }
+
+    /// Only return networks with the specified label, either `"key"` or
+    /// `"key=value"`.
+    pub fn label(mut self, label: &str) -> Self {
+        self.filters.add("label", label);
+        self
+    }
+
+    /// Only return networks whose name contains `name`.
+    pub fn name(mut self, name: &str) -> Self {
+        self.filters.add("name", name);
+        self
+    }
+
+    /// Only return networks with the specified scope: `"local"`,
+    /// `"global"` or `"swarm"`.
+    pub fn scope(mut self, scope: &str) -> Self {
+        self.filters.add("scope", scope);
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        self.filters.append_to(&mut params);
+        params.finish()
+    }
+}
+
+/// Options for `Docker::volumes`.
+#[derive(Debug, Clone, Default)]
+pub struct VolumeListOptions {
+    filters: Filters,
+}
+
+impl VolumeListOptions {
+    /// Only return volumes which aren't used by any container (if `true`),
+    /// or only those which are.
+    pub fn dangling(mut self, dangling: bool) -> Self {
+        self.filters.add("dangling", &dangling.to_string());
+        self
+    }
+
+    /// Only return volumes using the specified driver, such as `"local"`.
+    pub fn driver(mut self, driver: &str) -> Self {
+        self.filters.add("driver", driver);
+        self
+    }
+
+    /// Only return volumes with the specified label, either `"key"` or
+    /// `"key=value"`.
+    pub fn label(mut self, label: &str) -> Self {
+        self.filters.add("label", label);
+        self
+    }
+
+    /// Only return volumes whose name contains `name`.
+    pub fn name(mut self, name: &str) -> Self {
+        self.filters.add("name", name);
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        self.filters.append_to(&mut params);
+        params.finish()
+    }
+}
+
+/// Options for `Docker::create_volume`.  This uses a "builder" pattern, so
+/// most methods will consume the object and return a new one.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateVolumeOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    driver: Option<String>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    driver_opts: HashMap<String, String>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    labels: HashMap<String, String>,
+}
+
+impl CreateVolumeOptions {
+    /// Name the volume.  Otherwise Docker generates a random name.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    /// Use the specified volume driver instead of `"local"`.
+    pub fn driver(mut self, driver: &str) -> Self {
+        self.driver = Some(driver.to_owned());
+        self
+    }
+
+    /// Set a driver-specific option.  For example, the `"local"` driver
+    /// can mount NFS shares using `"type"`, `"o"` and `"device"`.
+    pub fn driver_opt(mut self, name: &str, value: &str) -> Self {
+        self.driver_opts.insert(name.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Set a label on the volume.
+    pub fn label(mut self, name: &str, value: &str) -> Self {
+        self.labels.insert(name.to_owned(), value.to_owned());
+        self
+    }
+}
+
+/// Options for `Docker::prune_volumes`.
+#[derive(Debug, Clone, Default)]
+pub struct PruneVolumesOptions {
+    filters: Filters,
+}
+
+impl PruneVolumesOptions {
+    /// Prune named volumes as well as anonymous ones.  Docker only prunes
+    /// anonymous volumes by default.
+    pub fn all(mut self) -> Self {
+        self.filters.add("all", "true");
+        self
+    }
+
+    /// Only prune volumes with the specified label, either `"key"` or
+    /// `"key=value"`.
+    pub fn label(mut self, label: &str) -> Self {
+        self.filters.add("label", label);
+        self
+    }
+
+    /// Only prune volumes without the specified label.
+    pub fn label_not(mut self, label: &str) -> Self {
+        self.filters.add("label!", label);
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        self.filters.append_to(&mut params);
+        params.finish()
+    }
+}
+
+/// Options for `Docker::prune_networks`.
+#[derive(Debug, Clone, Default)]
+pub struct PruneNetworksOptions {
+    filters: Filters,
+}
+
+impl PruneNetworksOptions {
+    /// Only prune networks created before `timestamp`, which may be a
+    /// Unix timestamp, a date or a duration like `"24h"`.
+    pub fn until(mut self, timestamp: &str) -> Self {
+        self.filters.add("until", timestamp);
+        self
+    }
+
+    /// Only prune networks with the specified label, either `"key"` or
+    /// `"key=value"`.
+    pub fn label(mut self, label: &str) -> Self {
+        self.filters.add("label", label);
+        self
+    }
+
+    /// Only prune networks without the specified label.
+    pub fn label_not(mut self, label: &str) -> Self {
+        self.filters.add("label!", label);
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        self.filters.append_to(&mut params);
+        params.finish()
+    }
+}
+
+/// Options for `Docker::create_network`.  This uses a "builder" pattern,
+/// so most methods will consume the object and return a new one.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateNetworkOptions {
+    name: String,
+    check_duplicate: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    driver: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+    internal: bool,
+    attachable: bool,
+    #[serde(rename = "EnableIPv6")]
+    enable_ipv6: bool,
+    #[serde(rename = "IPAM", skip_serializing_if = "Option::is_none")]
+    ipam: Option<Ipam>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    options: HashMap<String, String>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    labels: HashMap<String, String>,
+}
+
+impl CreateNetworkOptions {
+    /// Create a new network named `name`.  Docker refuses to create a
+    /// network if one with the same name already exists.
+    pub fn new(name: &str) -> Self {
+        CreateNetworkOptions {
+            name: name.to_owned(),
+            check_duplicate: true,
+            ..Default::default()
+        }
+    }
+
+    /// Use the specified network driver, such as `"bridge"` or
+    /// `"overlay"`, instead of the default.
+    pub fn driver(mut self, driver: &str) -> Self {
+        self.driver = Some(driver.to_owned());
+        self
+    }
+
+    /// Don't allow containers on this network to reach the outside world.
+    pub fn internal(mut self) -> Self {
+        self.internal = true;
+        self
+    }
+
+    /// Allow standalone containers to attach to this swarm network.
+    pub fn attachable(mut self) -> Self {
+        self.attachable = true;
+        self
+    }
+
+    /// Create the network with the specified scope, such as `"swarm"` or
+    /// `"local"`, instead of the driver's default.
+    pub fn scope(mut self, scope: &str) -> Self {
+        self.scope = Some(scope.to_owned());
+        self
+    }
+
+    /// Encrypt traffic between swarm nodes on an `"overlay"` network, like
+    /// `docker network create --opt encrypted`.
+    pub fn encrypted(self) -> Self {
+        self.option("encrypted", "")
+    }
+
+    /// Enable IPv6 on this network.  For a dual-stack network, also pass
+    /// an IPv6 subnet like `"fd00:db8::/64"` to `ipam_config`, alongside
+    /// the IPv4 one.
+    pub fn enable_ipv6(mut self) -> Self {
+        self.enable_ipv6 = true;
+        self
+    }
+
+    /// Set a driver-specific option, such as
+    /// `"com.docker.network.bridge.name"`.
+    pub fn option(mut self, name: &str, value: &str) -> Self {
+        self.options.insert(name.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Set a label on the network.
+    pub fn label(mut self, name: &str, value: &str) -> Self {
+        self.labels.insert(name.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Use the specified IPAM driver instead of `"default"`.
+    pub fn ipam_driver(mut self, driver: &str) -> Self {
+        self.ipam.get_or_insert_with(Ipam::default).Driver = driver.to_owned();
+        self
+    }
+
+    /// Add an address range to the network.  Call this more than once to
+    /// give the network several subnets.
+    pub fn ipam_config(mut self, config: IpamConfig) -> Self {
+        self.ipam
+            .get_or_insert_with(Ipam::default)
+            .Config
+            .push(config);
+        self
+    }
+}
+
+/// How a container is attached to a network by `Docker::connect_network`.
+/// This uses a "builder" pattern, so most methods will consume the object
+/// and return a new one.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct EndpointConfig {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    aliases: Vec<String>,
+    #[serde(
+        rename = "IPAMConfig",
+        skip_serializing_if = "EndpointIpamConfig::is_empty"
+    )]
+    ipam_config: EndpointIpamConfig,
+}
+
+impl EndpointConfig {
+    /// Let other containers on the network reach this one as `alias`, like
+    /// `docker network connect --alias`.
+    pub fn alias(mut self, alias: &str) -> Self {
+        self.aliases.push(alias.to_owned());
+        self
+    }
+
+    /// Give the container a static IPv4 address, which must be inside one
+    /// of the network's subnets.
+    pub fn ipv4_address(mut self, address: &str) -> Self {
+        self.ipam_config.IPv4Address = Some(address.to_owned());
+        self
+    }
+
+    /// Give the container a static IPv6 address.
+    pub fn ipv6_address(mut self, address: &str) -> Self {
+        self.ipam_config.IPv6Address = Some(address.to_owned());
+        self
+    }
+
+    /// Add a link-local address for the container.
+    pub fn link_local_ip(mut self, address: &str) -> Self {
+        self.ipam_config.LinkLocalIPs.push(address.to_owned());
+        self
+    }
+}
+
+/// Options for `Docker::swarm_init`.  This uses a "builder" pattern, so
+/// most methods will consume the object and return a new one.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SwarmInitOptions {
+    listen_addr: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    advertise_addr: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_path_addr: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_path_port: Option<u32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    default_addr_pool: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subnet_size: Option<u32>,
+    force_new_cluster: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spec: Option<SwarmSpec>,
+}
+
+impl Default for SwarmInitOptions {
+    fn default() -> Self {
+        SwarmInitOptions {
+            listen_addr: "0.0.0.0:2377".to_owned(),
+            advertise_addr: None,
+            data_path_addr: None,
+            data_path_port: None,
+            default_addr_pool: vec![],
+            subnet_size: None,
+            force_new_cluster: false,
+            spec: None,
+        }
+    }
+}
+
+impl SwarmInitOptions {
+    /// Listen for other managers on `addr`, such as `"0.0.0.0:2377"` or
+    /// `"eth0:2377"`, instead of the default.
+    pub fn listen_addr(mut self, addr: &str) -> Self {
+        self.listen_addr = addr.to_owned();
+        self
+    }
+
+    /// Tell other nodes to reach this one at `addr`, such as
+    /// `"192.168.1.1:2377"`.  Required if the host has several addresses.
+    pub fn advertise_addr(mut self, addr: &str) -> Self {
+        self.advertise_addr = Some(addr.to_owned());
+        self
+    }
+
+    /// Send container traffic over `addr` instead of the advertised
+    /// address.
+    pub fn data_path_addr(mut self, addr: &str) -> Self {
+        self.data_path_addr = Some(addr.to_owned());
+        self
+    }
+
+    /// Send container traffic over the specified UDP port instead of 4789.
+    pub fn data_path_port(mut self, port: u32) -> Self {
+        self.data_path_port = Some(port);
+        self
+    }
+
+    /// Allocate overlay network subnets from `pool`, such as
+    /// `"10.20.0.0/16"`.  Call this more than once to add several pools.
+    pub fn default_addr_pool(mut self, pool: &str) -> Self {
+        self.default_addr_pool.push(pool.to_owned());
+        self
+    }
+
+    /// Give each overlay network a subnet with a prefix of this length,
+    /// instead of 24.
+    pub fn subnet_size(mut self, size: u32) -> Self {
+        self.subnet_size = Some(size);
+        self
+    }
+
+    /// Create a new single-manager cluster from this node's existing state,
+    /// like `docker swarm init --force-new-cluster`.  Used to recover when
+    /// a majority of managers have been lost.
+    pub fn force_new_cluster(mut self) -> Self {
+        self.force_new_cluster = true;
+        self
+    }
+
+    /// Create the swarm with the specified settings instead of the
+    /// defaults.
+    pub fn spec(mut self, spec: SwarmSpec) -> Self {
+        self.spec = Some(spec);
+        self
+    }
+}
+
+/// Options for `Docker::swarm_join`.  This uses a "builder" pattern, so
+/// most methods will consume the object and return a new one.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SwarmJoinOptions {
+    listen_addr: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    advertise_addr: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_path_addr: Option<String>,
+    remote_addrs: Vec<String>,
+    join_token: String,
+}
+
+impl SwarmJoinOptions {
+    /// Join the swarm managed by `remote_addr`, such as
+    /// `"192.168.1.1:2377"`, using a worker or manager `join_token`.
+    pub fn new(remote_addr: &str, join_token: &str) -> Self {
+        SwarmJoinOptions {
+            listen_addr: "0.0.0.0:2377".to_owned(),
+            advertise_addr: None,
+            data_path_addr: None,
+            remote_addrs: vec![remote_addr.to_owned()],
+            join_token: join_token.to_owned(),
+        }
+    }
+
+    /// Also try to reach the swarm through `addr`, if the first manager is
+    /// unavailable.
+    pub fn remote_addr(mut self, addr: &str) -> Self {
+        self.remote_addrs.push(addr.to_owned());
+        self
+    }
+
+    /// Listen for other managers on `addr` instead of `"0.0.0.0:2377"`.
+    /// Only used if this node joins as a manager.
+    pub fn listen_addr(mut self, addr: &str) -> Self {
+        self.listen_addr = addr.to_owned();
+        self
+    }
+
+    /// Tell other nodes to reach this one at `addr`.
+    pub fn advertise_addr(mut self, addr: &str) -> Self {
+        self.advertise_addr = Some(addr.to_owned());
+        self
+    }
+
+    /// Send container traffic over `addr` instead of the advertised
+    /// address.
+    pub fn data_path_addr(mut self, addr: &str) -> Self {
+        self.data_path_addr = Some(addr.to_owned());
+        self
+    }
+}
+
+/// Options for `Docker::swarm_update`.  This uses a "builder" pattern, so
+/// most methods will consume the object and return a new one.
+#[derive(Debug, Clone, Default)]
+pub struct SwarmUpdateOptions {
+    rotate_worker_token: bool,
+    rotate_manager_token: bool,
+    rotate_manager_unlock_key: bool,
+}
+
+impl SwarmUpdateOptions {
+    /// Replace the worker join token, so the old one can no longer be used.
+    pub fn rotate_worker_token(mut self) -> Self {
+        self.rotate_worker_token = true;
+        self
+    }
+
+    /// Replace the manager join token, so the old one can no longer be
+    /// used.
+    pub fn rotate_manager_token(mut self) -> Self {
+        self.rotate_manager_token = true;
+        self
+    }
+
+    /// Replace the key used to unlock managers, if autolock is enabled.
+    pub fn rotate_manager_unlock_key(mut self) -> Self {
+        self.rotate_manager_unlock_key = true;
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        if self.rotate_worker_token {
+            params.append_pair("rotateWorkerToken", "1");
+        }
+        if self.rotate_manager_token {
+            params.append_pair("rotateManagerToken", "1");
+        }
+        if self.rotate_manager_unlock_key {
+            params.append_pair("rotateManagerUnlockKey", "1");
+        }
+        params.finish()
+    }
+}
+
+/// Options for `Docker::services`.
+#[derive(Debug, Clone, Default)]
+pub struct ServiceListOptions {
+    filters: Filters,
+    status: bool,
+}
+
+impl ServiceListOptions {
+    /// Only return services whose ID starts with `id`.
+    pub fn id(mut self, id: &str) -> Self {
+        self.filters.add("id", id);
+        self
+    }
+
+    /// Only return services with the specified label, either `"key"` or
+    /// `"key=value"`.
+    pub fn label(mut self, label: &str) -> Self {
+        self.filters.add("label", label);
+        self
+    }
+
+    /// Only return services with the specified mode: `"replicated"`,
+    /// `"global"`, `"replicated-job"` or `"global-job"`.
+    pub fn mode(mut self, mode: &str) -> Self {
+        self.filters.add("mode", mode);
+        self
+    }
+
+    /// Only return services whose name contains `name`.
+    pub fn name(mut self, name: &str) -> Self {
+        self.filters.add("name", name);
+        self
+    }
+
+    /// Fill in `Service::ServiceStatus` with how many tasks are running.
+    pub fn status(mut self) -> Self {
+        self.status = true;
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        self.filters.append_to(&mut params);
+        if self.status {
+            params.append_pair("status", "1");
+        }
+        params.finish()
+    }
+}
+
+/// Options for `Docker::update_service`.  This uses a "builder" pattern,
+/// so most methods will consume the object and return a new one.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateServiceOptions {
+    auth: Option<RegistryAuth>,
+    registry_auth_from: Option<String>,
+    rollback: bool,
+}
+
+impl UpdateServiceOptions {
+    /// Use these credentials to pull the service's image.
+    pub fn auth(mut self, auth: RegistryAuth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// If no credentials are passed, reuse the ones from `"spec"` (the
+    /// default) or `"previous-spec"`.
+    pub fn registry_auth_from(mut self, source: &str) -> Self {
+        self.registry_auth_from = Some(source.to_owned());
+        self
+    }
+
+    /// Roll the service back to its previous spec, like
+    /// `docker service rollback`.  Docker ignores the spec passed to
+    /// `update_service`, but it must still be valid.
+    pub fn rollback(mut self) -> Self {
+        self.rollback = true;
+        self
+    }
+
+    /// The credentials to send in the `X-Registry-Auth` header, if any.
+    pub(crate) fn registry_auth(&self) -> Option<&RegistryAuth> {
+        self.auth.as_ref()
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        if let Some(ref source) = self.registry_auth_from {
+            params.append_pair("registryAuthFrom", source);
+        }
+        if self.rollback {
+            params.append_pair("rollback", "previous");
+        }
+        params.finish()
+    }
+}
+
+/// Options for `Docker::tasks`.
+#[derive(Debug, Clone, Default)]
+pub struct TaskListOptions {
+    filters: Filters,
+}
+
+impl TaskListOptions {
+    /// Only return tasks the swarm is trying to move to `state`:
+    /// `"running"`, `"shutdown"` or `"accepted"`.
+    pub fn desired_state(mut self, state: &str) -> Self {
+        self.filters.add("desired-state", state);
+        self
+    }
+
+    /// Only return tasks whose ID starts with `id`.
+    pub fn id(mut self, id: &str) -> Self {
+        self.filters.add("id", id);
+        self
+    }
+
+    /// Only return tasks with the specified label, either `"key"` or
+    /// `"key=value"`.
+    pub fn label(mut self, label: &str) -> Self {
+        self.filters.add("label", label);
+        self
+    }
+
+    /// Only return tasks whose name contains `name`.
+    pub fn name(mut self, name: &str) -> Self {
+        self.filters.add("name", name);
+        self
+    }
+
+    /// Only return tasks scheduled on the specified node, by ID or name.
+    pub fn node(mut self, node: &str) -> Self {
+        self.filters.add("node", node);
+        self
+    }
+
+    /// Only return tasks belonging to the specified service, by ID or
+    /// name.
+    pub fn service(mut self, service: &str) -> Self {
+        self.filters.add("service", service);
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        self.filters.append_to(&mut params);
+        params.finish()
+    }
+}
+
+/// Options for `Docker::nodes`.
+#[derive(Debug, Clone, Default)]
+pub struct NodeListOptions {
+    filters: Filters,
+}
+
+impl NodeListOptions {
+    /// Only return nodes whose ID starts with `id`.
+    pub fn id(mut self, id: &str) -> Self {
+        self.filters.add("id", id);
+        self
+    }
+
+    /// Only return nodes whose engine has the specified label, either
+    /// `"key"` or `"key=value"`.
+    pub fn label(mut self, label: &str) -> Self {
+        self.filters.add("label", label);
+        self
+    }
+
+    /// Only return nodes with the specified node label, as set in
+    /// `NodeSpec::Labels`.
+    pub fn node_label(mut self, label: &str) -> Self {
+        self.filters.add("node.label", label);
+        self
+    }
+
+    /// Only return nodes whose membership is `"accepted"` or `"pending"`.
+    pub fn membership(mut self, membership: &str) -> Self {
+        self.filters.add("membership", membership);
+        self
+    }
+
+    /// Only return nodes whose name contains `name`.
+    pub fn name(mut self, name: &str) -> Self {
+        self.filters.add("name", name);
+        self
+    }
+
+    /// Only return nodes with the specified role, `"manager"` or
+    /// `"worker"`.
+    pub fn role(mut self, role: &str) -> Self {
+        self.filters.add("role", role);
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        self.filters.append_to(&mut params);
+        params.finish()
+    }
+}
+
+/// Options for `Docker::secrets`.
+#[derive(Debug, Clone, Default)]
+pub struct SecretListOptions {
+    filters: Filters,
+}
+
+impl SecretListOptions {
+    /// Only return secrets whose ID starts with `id`.
+    pub fn id(mut self, id: &str) -> Self {
+        self.filters.add("id", id);
+        self
+    }
+
+    /// Only return secrets with the specified label, either `"key"` or
+    /// `"key=value"`.
+    pub fn label(mut self, label: &str) -> Self {
+        self.filters.add("label", label);
+        self
+    }
+
+    /// Only return secrets whose name contains `name`.
+    pub fn name(mut self, name: &str) -> Self {
+        self.filters.add("name", name);
+        self
+    }
+
+    /// Only return the secret named exactly `name`.  Call this more than
+    /// once to match several names.
+    pub fn names(mut self, name: &str) -> Self {
+        self.filters.add("names", name);
+        self
+    }
+
+    /// Convert to URL parameters.
+    pub fn to_url_params(&self) -> String {
+        let mut params = form_urlencoded::Serializer::new(String::new());
+        self.filters.append_to(&mut params);
+        params.finish()
+    }
+}
@@ -1,19 +1,35 @@
 use std::fmt::Error;
 use std::fmt::{Display, Formatter};
+use std::time::Duration;
 
-#[derive(Debug)]
+/// A process running inside a container, as reported by `ps`.  The columns
+/// available depend on the `ps` arguments used, so everything except
+/// `command` is optional.  Values we can't parse are still available in
+/// `raw`.
+#[derive(Debug, Clone)]
 pub struct Process {
     pub user: String,
-    pub pid: String,
-    pub cpu: Option<String>,
-    pub memory: Option<String>,
-    pub vsz: Option<String>,
-    pub rss: Option<String>,
+    pub pid: Option<u32>,
+    /// CPU usage, as a percentage.
+    pub cpu: Option<f64>,
+    /// Memory usage, as a percentage.
+    pub memory: Option<f64>,
+    /// Virtual memory size, in bytes.
+    pub vsz: Option<u64>,
+    /// Resident set size, in bytes.
+    pub rss: Option<u64>,
     pub tty: Option<String>,
     pub stat: Option<String>,
+    /// The time the process started.  Depending on how long ago that was,
+    /// `ps` formats this as a time of day, a date, or a year.
     pub start: Option<String>,
-    pub time: Option<String>,
+    /// Total CPU time used.
+    pub time: Option<Duration>,
+    /// Time elapsed since the process started.
+    pub elapsed: Option<Duration>,
     pub command: String,
+    /// The raw values returned by `ps`, paired with their column titles.
+    pub raw: Vec<(String, String)>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -23,81 +39,104 @@ pub struct Top {
     pub Processes: Vec<Vec<String>>,
 }
 
-impl Display for Process {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        let mut s = String::new();
-
-        s.push_str(&*self.user.clone());
+impl Process {
+    /// Build a `Process` from one row of `ps` output.
+    pub(crate) fn from_row(titles: &[String], values: &[String]) -> Process {
+        let mut p = Process {
+            user: String::new(),
+            pid: None,
+            cpu: None,
+            memory: None,
+            vsz: None,
+            rss: None,
+            tty: None,
+            stat: None,
+            start: None,
+            time: None,
+            elapsed: None,
+            command: String::new(),
+            raw: vec![],
+        };
 
-        s.push_str(",");
-        s.push_str(&*self.pid.clone());
-
-        match self.cpu.clone() {
-            Some(v) => {
-                s.push_str(",");
-                s.push_str(&*v);
+        for (key, value) in titles.iter().zip(values.iter()) {
+            match key.as_ref() {
+                "UID" => p.user = value.clone(),
+                "USER" => p.user = value.clone(),
+                "PID" => p.pid = value.parse().ok(),
+                "%CPU" => p.cpu = value.parse().ok(),
+                "%MEM" => p.memory = value.parse().ok(),
+                "VSZ" => p.vsz = parse_kib(value),
+                "RSS" => p.rss = parse_kib(value),
+                "TTY" => p.tty = Some(value.clone()),
+                "STAT" => p.stat = Some(value.clone()),
+                "START" => p.start = Some(value.clone()),
+                "STIME" => p.start = Some(value.clone()),
+                "TIME" => p.time = parse_ps_duration(value),
+                "ELAPSED" => p.elapsed = parse_ps_duration(value),
+                "CMD" => p.command = value.clone(),
+                "COMMAND" => p.command = value.clone(),
+                _ => {}
             }
-            None => {}
+            p.raw.push((key.clone(), value.clone()));
         }
+        p
+    }
 
-        match self.memory.clone() {
-            Some(v) => {
-                s.push_str(",");
-                s.push_str(&*v);
-            }
-            None => {}
-        }
+    /// Look up the raw value of the column with the specified title.
+    pub fn raw_value(&self, title: &str) -> Option<&str> {
+        self.raw
+            .iter()
+            .find(|(t, _)| t == title)
+            .map(|(_, v)| v.as_str())
+    }
+}
 
-        match self.vsz.clone() {
-            Some(v) => {
-                s.push_str(",");
-                s.push_str(&*v);
-            }
-            None => {}
-        }
+/// Parse a size in KiB, as used by `ps`, and convert it to bytes.
+fn parse_kib(value: &str) -> Option<u64> {
+    value.parse::<u64>().ok().map(|kib| kib * 1024)
+}
 
-        match self.rss.clone() {
-            Some(v) => {
-                s.push_str(",");
-                s.push_str(&*v);
-            }
-            None => {}
-        }
+/// Parse a duration in the `[[DD-]HH:]MM:SS` format used by `ps`.
+pub(crate) fn parse_ps_duration(value: &str) -> Option<Duration> {
+    let (days, rest) = match value.find('-') {
+        Some(pos) => (value[..pos].parse::<u64>().ok()?, &value[pos + 1..]),
+        None => (0, value),
+    };
+    let mut secs = 0;
+    for part in rest.split(':') {
+        secs = secs * 60 + part.parse::<u64>().ok()?;
+    }
+    Some(Duration::from_secs(days * 24 * 60 * 60 + secs))
+}
 
-        match self.tty.clone() {
-            Some(v) => {
-                s.push_str(",");
-                s.push_str(&*v);
-            }
-            None => {}
-        }
+impl Display for Process {
+    /// Formats the user, PID, any of the `ps` columns we know about, and
+    /// the command, separated by commas, using the values `ps` returned.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut s = String::new();
 
-        match self.stat.clone() {
-            Some(v) => {
-                s.push_str(",");
-                s.push_str(&*v);
-            }
-            None => {}
-        }
+        s.push_str(&self.user);
 
-        match self.start.clone() {
-            Some(v) => {
-                s.push_str(",");
-                s.push_str(&*v);
-            }
-            None => {}
-        }
+        s.push(',');
+        s.push_str(self.raw_value("PID").unwrap_or_default());
 
-        match self.time.clone() {
-            Some(v) => {
-                s.push_str(",");
-                s.push_str(&*v);
-            }
-            None => {}
+        let optional_fields = [
+            self.raw_value("%CPU"),
+            self.raw_value("%MEM"),
+            self.raw_value("VSZ"),
+            self.raw_value("RSS"),
+            self.raw_value("TTY"),
+            self.raw_value("STAT"),
+            self.raw_value("START").or_else(|| self.raw_value("STIME")),
+            self.raw_value("TIME"),
+        ];
+        for v in optional_fields.iter().flatten() {
+            s.push(',');
+            s.push_str(v);
         }
 
-        s.push_str(",");
-        s.push_str(&*self.command.clone());
+        s.push(',');
+        s.push_str(&self.command);
 
         write!(f, "{}", s)
     }
@@ -0,0 +1,239 @@
+//! Progress messages from long-running image operations.  Pulls, pushes,
+//! builds and loads all report progress using the same kind of
+//! newline-delimited JSON messages, which we decode as `ProgressEvent`.
+
+use futures::stream::{Stream, StreamExt};
+use hyper::Body;
+
+use crate::build::{self, BuildResult, BuildkitStatus};
+use crate::errors::*;
+use crate::image::PushResult;
+use crate::json_lines::decode_json_lines;
+
+/// A progress message from `Docker::pull_image`, `Docker::push_image`,
+/// `Docker::build_image`, `Docker::load_image` or `Docker::import_image`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "RawProgress", into = "RawProgress")]
+pub enum ProgressEvent {
+    /// Output from a build, or a message from a load such as
+    /// `"Loaded image: alpine:latest\n"`.  Usually includes a trailing
+    /// newline.
+    Stream(String),
+    /// What is happening, such as `"Pull complete"`.  Messages about a
+    /// specific layer have an `id`.
+    Status { id: Option<String>, status: String },
+    /// How much of a layer has been transferred, in bytes.
+    Progress {
+        id: Option<String>,
+        status: String,
+        current: u64,
+        total: Option<u64>,
+    },
+    /// Docker reported an error.  The streams returned by `Docker` convert
+    /// these to an `Err`, so you will only see this if you decode progress
+    /// messages yourself.
+    Error {
+        message: String,
+        detail: Option<ErrorDetail>,
+    },
+    /// Extra data, such as the ID of a newly built image.
+    Aux { id: Option<String>, aux: Aux },
+}
+
+impl ProgressEvent {
+    /// Convert messages which report an error into an `Err`.
+    pub(crate) fn into_result(self) -> Result<Self> {
+        match self {
+            ProgressEvent::Error { message, .. } => Err(ErrorKind::ProgressError(message).into()),
+            event => Ok(event),
+        }
+    }
+
+    /// The layer or object this message is about, if any.
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            ProgressEvent::Status { id, .. }
+            | ProgressEvent::Progress { id, .. }
+            | ProgressEvent::Aux { id, .. } => id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The ID of a built image, if this message reports it.
+    pub fn image_id(&self) -> Option<&str> {
+        match self {
+            ProgressEvent::Aux {
+                aux: Aux::Image(result),
+                ..
+            } => Some(&result.ID),
+            _ => None,
+        }
+    }
+
+    /// What was pushed, if this message reports it.
+    pub fn push_result(&self) -> Option<&PushResult> {
+        match self {
+            ProgressEvent::Aux {
+                aux: Aux::Push(result),
+                ..
+            } => Some(result),
+            _ => None,
+        }
+    }
+
+    /// Decode the progress of a BuildKit build, if this message contains
+    /// it.
+    pub fn trace(&self) -> Result<Option<BuildkitStatus>> {
+        match self {
+            ProgressEvent::Aux {
+                id: Some(id),
+                aux: Aux::Trace(encoded),
+            } if id == build::BUILDKIT_TRACE_ID => build::decode_trace(encoded).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// The tag of an image loaded by `Docker::load_image`, such as
+    /// `"alpine:latest"`, or its ID if the archive didn't include a tag.
+    pub fn loaded_image(&self) -> Option<&str> {
+        match self {
+            ProgressEvent::Stream(stream) => {
+                let stream = stream.trim_end();
+                stream
+                    .strip_prefix("Loaded image: ")
+                    .or_else(|| stream.strip_prefix("Loaded image ID: "))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Extra data attached to a `ProgressEvent`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Aux {
+    /// The image which was built.
+    Image(BuildResult),
+    /// What was pushed.
+    Push(PushResult),
+    /// An encoded BuildKit trace.  Use `ProgressEvent::trace` to decode it.
+    Trace(String),
+    /// Anything we don't recognize.
+    Other(serde_json::Value),
+}
+
+/// Details of an error reported in a progress stream.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorDetail {
+    #[serde(default)]
+    pub code: Option<i64>,
+    pub message: String,
+}
+
+/// Decode a stream of progress messages, converting errors into `Err`.
+pub(crate) fn decode_progress(
+    body: Body,
+) -> impl Stream<Item = Result<ProgressEvent>> + Send + Unpin {
+    decode_json_lines("ProgressEvent", body)
+        .map(|event: Result<ProgressEvent>| event.and_then(ProgressEvent::into_result))
+}
+
+/// A progress message as it appears on the wire.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawProgress {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stream: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    progress_detail: Option<ProgressDetail>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    aux: Option<Aux>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error_detail: Option<ErrorDetail>,
+}
+
+/// How much of a layer has been transferred.  Both fields are missing for
+/// messages which aren't about a transfer.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ProgressDetail {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    current: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    total: Option<u64>,
+}
+
+impl From<RawProgress> for ProgressEvent {
+    fn from(raw: RawProgress) -> Self {
+        if let Some(message) = raw.error {
+            return ProgressEvent::Error {
+                message,
+                detail: raw.error_detail,
+            };
+        }
+        if let Some(aux) = raw.aux {
+            return ProgressEvent::Aux { id: raw.id, aux };
+        }
+        if let Some(stream) = raw.stream {
+            return ProgressEvent::Stream(stream);
+        }
+        let status = raw.status.unwrap_or_default();
+        match raw.progress_detail {
+            Some(ProgressDetail {
+                current: Some(current),
+                total,
+            }) => ProgressEvent::Progress {
+                id: raw.id,
+                status,
+                current,
+                total,
+            },
+            _ => ProgressEvent::Status { id: raw.id, status },
+        }
+    }
+}
+
+impl From<ProgressEvent> for RawProgress {
+    fn from(event: ProgressEvent) -> Self {
+        match event {
+            ProgressEvent::Stream(stream) => RawProgress {
+                stream: Some(stream),
+                ..RawProgress::default()
+            },
+            ProgressEvent::Status { id, status } => RawProgress {
+                id,
+                status: Some(status),
+                ..RawProgress::default()
+            },
+            ProgressEvent::Progress {
+                id,
+                status,
+                current,
+                total,
+            } => RawProgress {
+                id,
+                status: Some(status),
+                progress_detail: Some(ProgressDetail {
+                    current: Some(current),
+                    total,
+                }),
+                ..RawProgress::default()
+            },
+            ProgressEvent::Error { message, detail } => RawProgress {
+                error: Some(message),
+                error_detail: detail,
+                ..RawProgress::default()
+            },
+            ProgressEvent::Aux { id, aux } => RawProgress {
+                id,
+                aux: Some(aux),
+                ..RawProgress::default()
+            },
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! Parsing image references like `alpine:3.12`,
+//! `registry.example.com:5000/team/app:1.0` or
+//! `alpine@sha256:185518070891758909c9f839cf4ca393ee977ac378609f700f60a771a2dfe321`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::errors::*;
+
+/// The registry used for images which don't name one.
+pub const DEFAULT_REGISTRY: &str = "docker.io";
+
+/// A parsed reference to an image, optionally pinned to a tag or digest.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ImageReference {
+    registry: Option<String>,
+    repository: String,
+    tag: Option<String>,
+    digest: Option<String>,
+}
+
+impl ImageReference {
+    /// Parse an image reference.
+    pub fn parse(reference: &str) -> Result<ImageReference> {
+        let invalid = |problem: &str| -> Error {
+            format!("invalid image reference {:?}: {}", reference, problem).into()
+        };
+
+        let (rest, digest) = match reference.find('@') {
+            Some(pos) => (&reference[..pos], Some(&reference[pos + 1..])),
+            None => (reference, None),
+        };
+        if let Some(digest) = digest {
+            if !is_valid_digest(digest) {
+                return Err(invalid("bad digest"));
+            }
+        }
+
+        // A tag follows the last `:`, unless that `:` is part of a registry
+        // port like `localhost:5000/app`.
+        let (name, tag) = match rest.rfind(':') {
+            Some(pos) if !rest[pos + 1..].contains('/') => (&rest[..pos], Some(&rest[pos + 1..])),
+            _ => (rest, None),
+        };
+        if let Some(tag) = tag {
+            if !is_valid_tag(tag) {
+                return Err(invalid("bad tag"));
+            }
+        }
+
+        // The first component is a registry if it looks like a hostname.
+        let (registry, repository) = match name.find('/') {
+            Some(pos) if is_registry(&name[..pos]) => (Some(&name[..pos]), &name[pos + 1..]),
+            _ => (None, name),
+        };
+        if repository.is_empty() || !repository.split('/').all(is_valid_component) {
+            return Err(invalid("bad repository name"));
+        }
+
+        Ok(ImageReference {
+            registry: registry.map(str::to_owned),
+            repository: repository.to_owned(),
+            tag: tag.map(str::to_owned),
+            digest: digest.map(str::to_owned),
+        })
+    }
+
+    /// The registry holding this image, such as `"docker.io"` or
+    /// `"registry.example.com:5000"`.
+    pub fn registry(&self) -> &str {
+        self.registry.as_deref().unwrap_or(DEFAULT_REGISTRY)
+    }
+
+    /// The repository within the registry, such as `"alpine"` or
+    /// `"team/app"`.
+    pub fn repository(&self) -> &str {
+        &self.repository
+    }
+
+    /// The tag, if one was given.
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+
+    /// The digest, such as `"sha256:..."`, if one was given.
+    pub fn digest(&self) -> Option<&str> {
+        self.digest.as_deref()
+    }
+
+    /// The image name without a tag or digest, including the registry if
+    /// one was given, such as `"registry.example.com/team/app"`.
+    pub fn name(&self) -> String {
+        match self.registry {
+            Some(ref registry) => format!("{}/{}", registry, self.repository),
+            None => self.repository.clone(),
+        }
+    }
+
+    /// What to pass to Docker as the `tag` when pulling this image: the
+    /// digest if there is one, otherwise the tag, defaulting to `latest`.
+    pub fn tag_or_digest(&self) -> &str {
+        self.digest
+            .as_deref()
+            .or(self.tag.as_deref())
+            .unwrap_or("latest")
+    }
+}
+
+impl FromStr for ImageReference {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        ImageReference::parse(s)
+    }
+}
+
+impl fmt::Display for ImageReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())?;
+        if let Some(ref tag) = self.tag {
+            write!(f, ":{}", tag)?;
+        }
+        if let Some(ref digest) = self.digest {
+            write!(f, "@{}", digest)?;
+        }
+        Ok(())
+    }
+}
+
+/// Does the first component of a name refer to a registry?
+fn is_registry(component: &str) -> bool {
+    component.contains('.') || component.contains(':') || component == "localhost"
+}
+
+/// Repository components are lowercase letters and digits, separated by
+/// `.`, `_`, `__` or runs of `-`.
+fn is_valid_component(component: &str) -> bool {
+    let alphanumeric = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit();
+    let starts_and_ends_ok =
+        component.starts_with(alphanumeric) && component.ends_with(alphanumeric);
+    starts_and_ends_ok
+        && component
+            .chars()
+            .all(|c| alphanumeric(c) || c == '.' || c == '_' || c == '-')
+        && !component.contains("..")
+        && !component.contains("___")
+}
+
+/// Tags are up to 128 letters, digits, `_`, `.` and `-`, not starting with
+/// `.` or `-`.
+fn is_valid_tag(tag: &str) -> bool {
+    tag.len() <= 128
+        && tag.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+        && tag
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '-')
+}
+
+/// Digests look like `algorithm:hex`, with at least 32 hex digits.
+fn is_valid_digest(digest: &str) -> bool {
+    match digest.find(':') {
+        Some(pos) => {
+            let (algorithm, hex) = (&digest[..pos], &digest[pos + 1..]);
+            !algorithm.is_empty()
+                && algorithm
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "+._-".contains(c))
+                && hex.len() >= 32
+                && hex.chars().all(|c| c.is_ascii_hexdigit())
+        }
+        None => false,
+    }
+}
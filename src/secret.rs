@@ -0,0 +1,108 @@
+//! Docker swarm secrets.
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::errors::*;
+use crate::image::null_to_default;
+use crate::service::Driver;
+use crate::swarm::ObjectVersion;
+#[cfg(feature = "chrono")]
+use crate::timestamps;
+
+/// A secret, as returned by `Docker::secrets` and `Docker::inspect_secret`.
+/// Docker never returns the secret's data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct Secret {
+    pub ID: String,
+    /// Pass this to `Docker::update_secret` along with a changed `Spec`.
+    pub Version: ObjectVersion,
+    /// An RFC 3339 timestamp.
+    pub CreatedAt: String,
+    /// An RFC 3339 timestamp.
+    pub UpdatedAt: String,
+    pub Spec: SecretSpec,
+}
+
+#[cfg(feature = "chrono")]
+impl Secret {
+    /// When this secret was created.
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        timestamps::parse_rfc3339(&self.CreatedAt)
+    }
+
+    /// When this secret was last updated.
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        timestamps::parse_rfc3339(&self.UpdatedAt)
+    }
+}
+
+/// The contents and settings of a secret.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct SecretSpec {
+    pub Name: String,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Labels: HashMap<String, String>,
+    /// The secret's data, base64-encoded.  Use `SecretSpec::new` and
+    /// `SecretSpec::data` instead of accessing this directly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Data: Option<String>,
+    /// A secret store plugin which holds the secret, instead of the swarm.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Driver: Option<Driver>,
+    /// The templating driver to render the secret with, such as
+    /// `"golang"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Templating: Option<Driver>,
+}
+
+impl SecretSpec {
+    /// A secret named `name` containing `data`.
+    pub fn new(name: &str, data: &[u8]) -> SecretSpec {
+        SecretSpec {
+            Name: name.to_owned(),
+            Data: Some(base64::encode(data)),
+            ..SecretSpec::default()
+        }
+    }
+
+    /// Set a label on the secret.
+    pub fn label(mut self, name: &str, value: &str) -> SecretSpec {
+        self.Labels.insert(name.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Decode the secret's data, if we have it.
+    pub fn data(&self) -> Result<Option<Vec<u8>>> {
+        match self.Data {
+            Some(ref encoded) => base64::decode(encoded)
+                .map(Some)
+                .chain_err(|| format!("could not decode data of secret {:?}", self.Name)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Don't include secrets in debug output.
+impl fmt::Debug for SecretSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretSpec")
+            .field("Name", &self.Name)
+            .field("Labels", &self.Labels)
+            .field("Data", &self.Data.as_ref().map(|_| "<redacted>"))
+            .field("Driver", &self.Driver)
+            .field("Templating", &self.Templating)
+            .finish()
+    }
+}
+
+/// The response to `Docker::create_secret`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct CreateSecretResponse {
+    pub ID: String,
+}
@@ -0,0 +1,675 @@
+//! Docker swarm services.
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::container::UnspecifiedObject;
+use crate::errors::*;
+use crate::image::null_to_default;
+use crate::swarm::ObjectVersion;
+#[cfg(feature = "chrono")]
+use crate::timestamps;
+
+/// A service, as returned by `Docker::services` and `Docker::service_info`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct Service {
+    pub ID: String,
+    /// Pass this to `Docker::update_service` along with a changed `Spec`.
+    pub Version: ObjectVersion,
+    /// An RFC 3339 timestamp.
+    pub CreatedAt: String,
+    /// An RFC 3339 timestamp.
+    pub UpdatedAt: String,
+    pub Spec: ServiceSpec,
+    /// The spec before the last update, used to roll back.
+    #[serde(default)]
+    pub PreviousSpec: Option<ServiceSpec>,
+    #[serde(default)]
+    pub Endpoint: Option<Endpoint>,
+    /// The progress of the last rolling update, if there has been one.
+    #[serde(default)]
+    pub UpdateStatus: Option<UpdateStatus>,
+    /// How many tasks are running.  Only filled in by `Docker::services`
+    /// with `ServiceListOptions::status` set.
+    #[serde(default)]
+    pub ServiceStatus: Option<ServiceStatus>,
+}
+
+#[cfg(feature = "chrono")]
+impl Service {
+    /// When this service was created.
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        timestamps::parse_rfc3339(&self.CreatedAt)
+    }
+
+    /// When this service was last updated.
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        timestamps::parse_rfc3339(&self.UpdatedAt)
+    }
+}
+
+/// What a service should run, and how.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ServiceSpec {
+    #[serde(default)]
+    pub Name: String,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Labels: HashMap<String, String>,
+    /// What each task of the service runs.
+    pub TaskTemplate: TaskSpec,
+    /// Defaults to a single replica.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Mode: Option<ServiceMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub UpdateConfig: Option<UpdateConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub RollbackConfig: Option<UpdateConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub EndpointSpec: Option<EndpointSpec>,
+}
+
+impl ServiceSpec {
+    /// A service named `name` which runs one replica of `image`.
+    pub fn new(name: &str, image: &str) -> ServiceSpec {
+        ServiceSpec {
+            Name: name.to_owned(),
+            TaskTemplate: TaskSpec {
+                ContainerSpec: Some(ContainerSpec {
+                    Image: image.to_owned(),
+                    ..ContainerSpec::default()
+                }),
+                ..TaskSpec::default()
+            },
+            ..ServiceSpec::default()
+        }
+    }
+
+    /// How many replicas the service should run, or `None` if it isn't a
+    /// replicated service.
+    pub fn replicas(&self) -> Option<u64> {
+        self.Mode.as_ref().and_then(ServiceMode::replicas)
+    }
+
+    /// Change how many replicas the service should run.  Fails if it
+    /// isn't a replicated service.
+    pub fn set_replicas(&mut self, replicas: u64) -> Result<()> {
+        match self.Mode {
+            None => self.Mode = Some(ServiceMode::replicated(replicas)),
+            Some(ServiceMode {
+                Replicated: Some(ref mut replicated),
+                ..
+            }) => replicated.Replicas = Some(replicas),
+            Some(_) => {
+                return Err(format!("service {:?} is not a replicated service", self.Name).into())
+            }
+        }
+        Ok(())
+    }
+}
+
+/// What each task of a service runs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct TaskSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ContainerSpec: Option<ContainerSpec>,
+    /// Used instead of `ContainerSpec` by plugin services.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub PluginSpec: Option<serde_json::Value>,
+    /// Used instead of `ContainerSpec` by network attachment tasks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub NetworkAttachmentSpec: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Resources: Option<ResourceRequirements>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub RestartPolicy: Option<TaskRestartPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Placement: Option<Placement>,
+    /// Increment this to restart every task, even if nothing else changed.
+    #[serde(default)]
+    pub ForceUpdate: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Runtime: Option<String>,
+    /// The networks to attach each task to.
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub Networks: Vec<NetworkAttachmentConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub LogDriver: Option<Driver>,
+}
+
+/// The container run by each task of a service.  Fields Docker defines but
+/// we don't model in detail are kept as JSON, so that specs survive being
+/// decoded and sent back in `Docker::update_service`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ContainerSpec {
+    /// The image to run, usually pinned to a digest by Docker.
+    pub Image: String,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Labels: HashMap<String, String>,
+    /// Overrides the image's entrypoint.
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub Command: Vec<String>,
+    /// Overrides the image's command.
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub Args: Vec<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub Hostname: String,
+    /// Environment variables, as `"NAME=value"`.
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub Env: Vec<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub Dir: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub User: String,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub Groups: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Privileges: Option<serde_json::Value>,
+    #[serde(default)]
+    pub TTY: bool,
+    #[serde(default)]
+    pub OpenStdin: bool,
+    #[serde(default)]
+    pub ReadOnly: bool,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub Mounts: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub StopSignal: String,
+    /// How long to wait for the container to stop before killing it, in
+    /// nanoseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub StopGracePeriod: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub HealthCheck: Option<serde_json::Value>,
+    /// Extra `/etc/hosts` entries, as `"IP hostname"`.
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub Hosts: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub DNSConfig: Option<serde_json::Value>,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub Secrets: Vec<serde_json::Value>,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub Configs: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub Isolation: String,
+    /// Run an init process in the container?
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Init: Option<bool>,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub Sysctls: HashMap<String, String>,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub CapabilityAdd: Vec<String>,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub CapabilityDrop: Vec<String>,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub Ulimits: Vec<serde_json::Value>,
+}
+
+/// The resources a task may use, and the resources it needs to be
+/// scheduled.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ResourceRequirements {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Limits: Option<Resources>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Reservations: Option<Resources>,
+}
+
+/// An amount of CPU and memory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct Resources {
+    /// CPU, in billionths of a CPU.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub NanoCPUs: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub MemoryBytes: Option<i64>,
+    /// The maximum number of processes.  Only used in `Limits`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Pids: Option<i64>,
+    /// Resources like GPUs.  Only used in `Reservations`.
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub GenericResources: Vec<serde_json::Value>,
+}
+
+/// When the tasks of a service are restarted.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct TaskRestartPolicy {
+    /// `"none"`, `"on-failure"` or `"any"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Condition: Option<String>,
+    /// How long to wait between restarts, in nanoseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Delay: Option<i64>,
+    /// Give up after this many restarts.  `0` means never give up.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub MaxAttempts: Option<u64>,
+    /// The window over which restarts are counted, in nanoseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Window: Option<i64>,
+}
+
+/// Where the tasks of a service may be scheduled.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct Placement {
+    /// Such as `"node.role == manager"`.
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub Constraints: Vec<String>,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub Preferences: Vec<serde_json::Value>,
+    /// The most replicas to run on any one node.  `0` means no limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub MaxReplicas: Option<u64>,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub Platforms: Vec<serde_json::Value>,
+}
+
+/// A network which the tasks of a service are attached to.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct NetworkAttachmentConfig {
+    /// The network's name or ID.
+    pub Target: String,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub Aliases: Vec<String>,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub DriverOpts: HashMap<String, String>,
+}
+
+/// A driver and its options, such as a log driver.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct Driver {
+    pub Name: String,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub Options: HashMap<String, String>,
+}
+
+/// How many tasks a service runs.  Exactly one of these is set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ServiceMode {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Replicated: Option<ReplicatedService>,
+    /// Run one task on every node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Global: Option<UnspecifiedObject>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ReplicatedJob: Option<ReplicatedJob>,
+    /// Run one task to completion on every node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub GlobalJob: Option<UnspecifiedObject>,
+}
+
+impl ServiceMode {
+    /// Run `replicas` copies of the task.
+    pub fn replicated(replicas: u64) -> ServiceMode {
+        ServiceMode {
+            Replicated: Some(ReplicatedService {
+                Replicas: Some(replicas),
+            }),
+            ..ServiceMode::default()
+        }
+    }
+
+    /// Run one copy of the task on every node.
+    pub fn global() -> ServiceMode {
+        ServiceMode {
+            Global: Some(UnspecifiedObject::new()),
+            ..ServiceMode::default()
+        }
+    }
+
+    /// How many replicas to run, if this is a replicated service.
+    pub fn replicas(&self) -> Option<u64> {
+        self.Replicated
+            .as_ref()
+            .map(|replicated| replicated.Replicas.unwrap_or(1))
+    }
+}
+
+/// A service which runs a fixed number of tasks.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ReplicatedService {
+    /// Defaults to 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Replicas: Option<u64>,
+}
+
+/// A service which runs tasks until a number of them complete.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ReplicatedJob {
+    /// How many tasks to run at once.  Defaults to 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub MaxConcurrent: Option<u64>,
+    /// How many tasks must complete.  Defaults to `MaxConcurrent`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub TotalCompletions: Option<u64>,
+}
+
+/// How a service is updated or rolled back.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct UpdateConfig {
+    /// How many tasks to update at once.  `0` means all of them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Parallelism: Option<u64>,
+    /// How long to wait between batches, in nanoseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Delay: Option<i64>,
+    /// `"continue"`, `"pause"` or `"rollback"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub FailureAction: Option<String>,
+    /// How long to watch each task for failure after updating it, in
+    /// nanoseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Monitor: Option<i64>,
+    /// The fraction of tasks which may fail before `FailureAction` is
+    /// taken.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub MaxFailureRatio: Option<f64>,
+    /// `"stop-first"` or `"start-first"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Order: Option<String>,
+}
+
+/// How a service is exposed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct EndpointSpec {
+    /// `"vip"` for a virtual IP, or `"dnsrr"` for DNS round robin.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Mode: Option<String>,
+    #[serde(
+        deserialize_with = "null_to_default",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub Ports: Vec<EndpointPortConfig>,
+}
+
+/// A port published by a service.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct EndpointPortConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Name: Option<String>,
+    /// `"tcp"`, `"udp"` or `"sctp"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Protocol: Option<String>,
+    /// The port inside the container.
+    pub TargetPort: u16,
+    /// The port on the swarm.  Docker picks one if this is missing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub PublishedPort: Option<u16>,
+    /// `"ingress"` to publish on every node, or `"host"` to publish only
+    /// on nodes running a task.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub PublishMode: Option<String>,
+}
+
+/// How a service is actually exposed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct Endpoint {
+    #[serde(default)]
+    pub Spec: EndpointSpec,
+    /// The published ports, including ones Docker picked.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Ports: Vec<EndpointPortConfig>,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub VirtualIPs: Vec<VirtualIp>,
+}
+
+/// The virtual IP of a service on a network.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct VirtualIp {
+    pub NetworkID: String,
+    /// Such as `"10.255.0.5/16"`.
+    pub Addr: String,
+}
+
+/// The progress of a rolling update or rollback.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct UpdateStatus {
+    #[serde(default)]
+    pub State: Option<UpdateState>,
+    /// An RFC 3339 timestamp.
+    #[serde(default)]
+    pub StartedAt: Option<String>,
+    /// An RFC 3339 timestamp.
+    #[serde(default)]
+    pub CompletedAt: Option<String>,
+    #[serde(default)]
+    pub Message: String,
+}
+
+/// The state of a rolling update or rollback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateState {
+    Updating,
+    /// The update was paused, usually because too many tasks failed.
+    Paused,
+    Completed,
+    RollbackStarted,
+    RollbackPaused,
+    RollbackCompleted,
+    /// A state we don't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+/// How long `Docker::watch_service_update` waits for a rolling update to
+/// start.
+pub(crate) const UPDATE_START_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// What `Docker::watch_service_update` should do after polling a service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum UpdateStep {
+    /// Nothing new has happened, so poll again.
+    Wait,
+    /// Report this status and keep watching.
+    Report(UpdateStatus),
+    /// Report this status, roll the update back, and keep watching.
+    Rollback(UpdateStatus),
+    /// Report this status and stop watching.
+    Finish(UpdateStatus),
+    /// The update paused because tasks failed.
+    Fail(UpdateStatus),
+    /// No rolling update started, so stop watching.
+    NoUpdate,
+}
+
+/// Follows the `UpdateStatus` of a service for
+/// `Docker::watch_service_update`.
+///
+/// Docker keeps the status of the last update after it finishes, so we
+/// compare each status with the one the service had before the update we're
+/// watching, and ignore it until it changes.
+#[derive(Debug, Default)]
+pub(crate) struct UpdateWatch {
+    rollback_on_failure: bool,
+    /// Have we polled the service yet?
+    polled: bool,
+    /// The status before the update we're watching, which belongs to an
+    /// earlier update.
+    baseline: Option<UpdateStatus>,
+    /// Have we seen a status belonging to the update we're watching?
+    started: bool,
+    /// The last status we reported.
+    last: Option<UpdateStatus>,
+    /// Have we asked Docker to roll back?
+    rolled_back: bool,
+}
+
+impl UpdateWatch {
+    pub(crate) fn new(baseline: Option<UpdateStatus>, rollback_on_failure: bool) -> UpdateWatch {
+        UpdateWatch {
+            rollback_on_failure,
+            baseline,
+            ..UpdateWatch::default()
+        }
+    }
+
+    /// Have we seen the service yet?
+    pub(crate) fn has_polled(&self) -> bool {
+        self.polled
+    }
+
+    /// Decide what to do about `status`, seen `elapsed` after we started
+    /// watching.
+    pub(crate) fn observe(
+        &mut self,
+        status: Option<&UpdateStatus>,
+        elapsed: Duration,
+    ) -> UpdateStep {
+        self.polled = true;
+        let status = match status {
+            Some(status) if self.started || self.baseline.as_ref() != Some(status) => status,
+            _ if !self.started && elapsed >= UPDATE_START_TIMEOUT => return UpdateStep::NoUpdate,
+            _ => return UpdateStep::Wait,
+        };
+        self.started = true;
+
+        if self.last.as_ref() == Some(status) {
+            return UpdateStep::Wait;
+        }
+        self.last = Some(status.clone());
+        let status = status.clone();
+        match status.State {
+            Some(UpdateState::Completed) | Some(UpdateState::RollbackCompleted) => {
+                UpdateStep::Finish(status)
+            }
+            Some(UpdateState::Paused) if self.rollback_on_failure && !self.rolled_back => {
+                self.rolled_back = true;
+                UpdateStep::Rollback(status)
+            }
+            Some(UpdateState::Paused) | Some(UpdateState::RollbackPaused) => {
+                UpdateStep::Fail(status)
+            }
+            _ => UpdateStep::Report(status),
+        }
+    }
+}
+
+/// How many tasks a service is running.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ServiceStatus {
+    pub RunningTasks: u64,
+    pub DesiredTasks: u64,
+    /// Only used by jobs.
+    #[serde(default)]
+    pub CompletedTasks: u64,
+}
+
+/// The response to `Docker::create_service`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct CreateServiceResponse {
+    pub ID: String,
+    /// Such as a warning that the image couldn't be pinned to a digest.
+    #[serde(default)]
+    pub Warning: Option<String>,
+}
+
+/// The response to `Docker::update_service`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct UpdateServiceResponse {
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Warnings: Vec<String>,
+}
@@ -0,0 +1,382 @@
+//! BuildKit sessions.  During a BuildKit build, the daemon calls back into
+//! the client using gRPC, over a connection which we open to `/session` and
+//! which Docker then hijacks.  This means that we're the gRPC server, even
+//! though we opened the connection.
+//!
+//! We only need a handful of methods, so rather than pull in a full gRPC
+//! stack, we speak just enough of the protocol on top of `h2`.
+
+use bytes::{Buf, Bytes, BytesMut};
+use futures::future::{self, AbortHandle};
+use h2::{server::SendResponse, RecvStream, SendStream};
+use hyper::http::{HeaderMap, HeaderValue, Request, Response};
+use hyper::upgrade::Upgraded;
+use log::warn;
+use prost::Message;
+use std::collections::hash_map::RandomState;
+use std::collections::BTreeMap;
+use std::hash::{BuildHasher, Hasher};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use crate::errors::*;
+
+/// The standard gRPC health check, which BuildKit uses to detect when a
+/// session has gone away.
+const HEALTH_CHECK: &str = "/grpc.health.v1.Health/Check";
+
+/// A `HealthCheckResponse` with a status of `SERVING`.
+const HEALTH_SERVING: &[u8] = &[0x08, 0x01];
+
+/// Used by `RUN --mount=type=secret`.
+const GET_SECRET: &str = "/moby.buildkit.secrets.v1.Secrets/GetSecret";
+
+/// Used by `RUN --mount=type=ssh`.
+const SSH_CHECK_AGENT: &str = "/moby.sshforward.v1.SSH/CheckAgent";
+const SSH_FORWARD_AGENT: &str = "/moby.sshforward.v1.SSH/ForwardAgent";
+
+/// The request metadata which says which SSH agent to forward.
+const SSH_ID_HEADER: &str = "buildkit.ssh.id";
+
+/// The SSH agent used when a Dockerfile doesn't specify an `id`.
+const DEFAULT_SSH_ID: &str = "default";
+
+/// gRPC status codes we return.
+const GRPC_OK: u32 = 0;
+const GRPC_NOT_FOUND: u32 = 5;
+const GRPC_UNIMPLEMENTED: u32 = 12;
+const GRPC_INTERNAL: u32 = 13;
+
+/// The secrets and SSH agents which a build may use.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SessionProviders {
+    /// Secret IDs and the files containing them.
+    pub(crate) secrets: BTreeMap<String, PathBuf>,
+    /// SSH IDs and the agent sockets to forward.
+    pub(crate) ssh: BTreeMap<String, PathBuf>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct GetSecretRequest {
+    #[prost(string, tag = "1")]
+    id: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct GetSecretResponse {
+    #[prost(bytes, tag = "1")]
+    data: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct CheckAgentRequest {
+    #[prost(string, tag = "1")]
+    id: String,
+}
+
+/// A chunk of data sent to or from a forwarded SSH agent.
+#[derive(Clone, PartialEq, Message)]
+struct BytesMessage {
+    #[prost(bytes, tag = "1")]
+    data: Vec<u8>,
+}
+
+/// Generate a unique ID for a new session.
+pub(crate) fn new_session_id() -> String {
+    // `RandomState` is randomly seeded, which is all the randomness we need.
+    let mut id = String::new();
+    for round in 0..2u32 {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u32(round);
+        hasher.write_u32(process::id());
+        if let Ok(elapsed) = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+            hasher.write_u128(elapsed.as_nanos());
+        }
+        id.push_str(&format!("{:016x}", hasher.finish()));
+    }
+    id
+}
+
+/// The gRPC methods which we tell Docker we support.
+pub(crate) fn exposed_methods(providers: &SessionProviders) -> Vec<&'static str> {
+    let mut methods = vec![HEALTH_CHECK];
+    if !providers.secrets.is_empty() {
+        methods.push(GET_SECRET);
+    }
+    if !providers.ssh.is_empty() {
+        methods.push(SSH_CHECK_AGENT);
+        methods.push(SSH_FORWARD_AGENT);
+    }
+    methods
+}
+
+/// A running session.  Dropping this closes the session.
+pub(crate) struct SessionHandle {
+    abort: AbortHandle,
+}
+
+impl Drop for SessionHandle {
+    fn drop(&mut self) {
+        self.abort.abort();
+    }
+}
+
+/// Serve a session on `io` in the background.
+pub(crate) fn spawn(io: Upgraded, providers: SessionProviders) -> SessionHandle {
+    let (serve, abort) = future::abortable(serve(io, Arc::new(providers)));
+    tokio::spawn(async move {
+        if let Ok(Err(err)) = serve.await {
+            warn!("BuildKit session failed: {}", err);
+        }
+    });
+    SessionHandle { abort }
+}
+
+/// Serve gRPC requests from Docker on `io` until the connection is closed.
+async fn serve(io: Upgraded, providers: Arc<SessionProviders>) -> Result<()> {
+    let mut connection = h2::server::handshake(io)
+        .await
+        .chain_err(|| "could not start BuildKit session")?;
+    while let Some(request) = connection.accept().await {
+        let (request, respond) = request.chain_err(|| "error in BuildKit session")?;
+        let providers = providers.clone();
+        tokio::spawn(async move {
+            let path = request.uri().path().to_owned();
+            if let Err(err) = handle(&providers, request, respond).await {
+                warn!("error handling BuildKit session request {}: {}", path, err);
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Handle a single gRPC request.
+async fn handle(
+    providers: &SessionProviders,
+    request: Request<RecvStream>,
+    mut respond: SendResponse<Bytes>,
+) -> Result<()> {
+    let (parts, body) = request.into_parts();
+    let mut messages = MessageReader::new(body);
+
+    let response = Response::builder()
+        .status(200)
+        .header("content-type", "application/grpc")
+        .body(())
+        .chain_err(|| "error building response")?;
+    let mut stream = respond
+        .send_response(response, false)
+        .chain_err(|| "could not send response")?;
+
+    let status = match parts.uri.path() {
+        HEALTH_CHECK => {
+            messages.next().await?;
+            send_message(&mut stream, HEALTH_SERVING).await?;
+            GRPC_OK
+        }
+        GET_SECRET => {
+            let request = GetSecretRequest::decode(messages.expect().await?)
+                .chain_err(|| "could not decode GetSecret request")?;
+            match providers.secrets.get(&request.id) {
+                Some(path) => match tokio::fs::read(path).await {
+                    Ok(data) => {
+                        send_message(&mut stream, &encode(&GetSecretResponse { data })).await?;
+                        GRPC_OK
+                    }
+                    Err(err) => {
+                        warn!("could not read secret {}: {}", path.display(), err);
+                        GRPC_INTERNAL
+                    }
+                },
+                None => GRPC_NOT_FOUND,
+            }
+        }
+        SSH_CHECK_AGENT => {
+            let request = CheckAgentRequest::decode(messages.expect().await?)
+                .chain_err(|| "could not decode CheckAgent request")?;
+            if providers.ssh.contains_key(ssh_id(&request.id)) {
+                send_message(&mut stream, &[]).await?;
+                GRPC_OK
+            } else {
+                GRPC_NOT_FOUND
+            }
+        }
+        SSH_FORWARD_AGENT => {
+            let id = parts
+                .headers
+                .get(SSH_ID_HEADER)
+                .and_then(|id| id.to_str().ok())
+                .unwrap_or("");
+            match providers.ssh.get(ssh_id(id)) {
+                Some(socket) => {
+                    forward_agent(&mut messages, &mut stream, socket).await?;
+                    GRPC_OK
+                }
+                None => GRPC_NOT_FOUND,
+            }
+        }
+        _ => GRPC_UNIMPLEMENTED,
+    };
+
+    let mut trailers = HeaderMap::new();
+    trailers.insert("grpc-status", HeaderValue::from(status));
+    stream
+        .send_trailers(trailers)
+        .chain_err(|| "could not send response")
+}
+
+/// Map an empty SSH ID to the default.
+fn ssh_id(id: &str) -> &str {
+    if id.is_empty() {
+        DEFAULT_SSH_ID
+    } else {
+        id
+    }
+}
+
+/// Copy data between BuildKit and the SSH agent listening on `socket`.
+#[cfg(unix)]
+async fn forward_agent(
+    messages: &mut MessageReader,
+    stream: &mut SendStream<Bytes>,
+    socket: &Path,
+) -> Result<()> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let mkerr = || format!("could not forward SSH agent {}", socket.display());
+    let agent = tokio::net::UnixStream::connect(socket)
+        .await
+        .chain_err(mkerr)?;
+    let (mut agent_read, mut agent_write) = tokio::io::split(agent);
+    let to_agent = async {
+        while let Some(message) = messages.next().await? {
+            let message =
+                BytesMessage::decode(message).chain_err(|| "could not decode SSH message")?;
+            agent_write
+                .write_all(&message.data)
+                .await
+                .chain_err(mkerr)?;
+        }
+        agent_write.shutdown().await.chain_err(mkerr)
+    };
+    let from_agent = async {
+        let mut buf = vec![0; 32 * 1024];
+        loop {
+            let count = agent_read.read(&mut buf).await.chain_err(mkerr)?;
+            if count == 0 {
+                return Ok(());
+            }
+            let data = buf[..count].to_vec();
+            send_message(stream, &encode(&BytesMessage { data })).await?;
+        }
+    };
+    future::try_join(to_agent, from_agent).await?;
+    Ok(())
+}
+
+/// SSH agents listen on Unix sockets, so we can't forward them elsewhere.
+#[cfg(not(unix))]
+async fn forward_agent(
+    _messages: &mut MessageReader,
+    _stream: &mut SendStream<Bytes>,
+    _socket: &Path,
+) -> Result<()> {
+    Err("SSH agent forwarding is only supported on Unix".into())
+}
+
+/// Reads length-prefixed gRPC messages from a request body.
+struct MessageReader {
+    body: RecvStream,
+    buf: BytesMut,
+}
+
+impl MessageReader {
+    fn new(body: RecvStream) -> MessageReader {
+        MessageReader {
+            body,
+            buf: BytesMut::new(),
+        }
+    }
+
+    /// Read the next message, or `None` if the request has ended.
+    async fn next(&mut self) -> Result<Option<Bytes>> {
+        loop {
+            if let Some(message) = unframe(&mut self.buf)? {
+                return Ok(Some(message));
+            }
+            match self.body.data().await {
+                Some(chunk) => {
+                    let chunk = chunk.chain_err(|| "error reading gRPC request")?;
+                    let _ = self.body.flow_control().release_capacity(chunk.len());
+                    self.buf.extend_from_slice(&chunk);
+                }
+                None if self.buf.is_empty() => return Ok(None),
+                None => return Err("gRPC request ended early".into()),
+            }
+        }
+    }
+
+    /// Read the single message sent to a unary method.
+    async fn expect(&mut self) -> Result<Bytes> {
+        self.next()
+            .await?
+            .ok_or_else(|| "gRPC request had no message".into())
+    }
+}
+
+/// Remove a complete message from the front of `buf`, if there is one.
+pub(crate) fn unframe(buf: &mut BytesMut) -> Result<Option<Bytes>> {
+    if buf.len() < 5 {
+        return Ok(None);
+    }
+    if buf[0] != 0 {
+        return Err("compressed gRPC messages are not supported".into());
+    }
+    let len = u32::from_be_bytes([buf[1], buf[2], buf[3], buf[4]]) as usize;
+    if buf.len() < 5 + len {
+        return Ok(None);
+    }
+    buf.advance(5);
+    Ok(Some(buf.split_to(len).freeze()))
+}
+
+/// Add the gRPC length prefix to `message`.
+pub(crate) fn frame(message: &[u8]) -> Bytes {
+    let mut framed = Vec::with_capacity(5 + message.len());
+    framed.push(0);
+    framed.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    framed.extend_from_slice(message);
+    Bytes::from(framed)
+}
+
+/// Send a single message as part of a response, waiting for Docker to
+/// give us enough flow-control capacity.
+async fn send_message(stream: &mut SendStream<Bytes>, message: &[u8]) -> Result<()> {
+    let mut data = frame(message);
+    while !data.is_empty() {
+        stream.reserve_capacity(data.len());
+        let capacity = match future::poll_fn(|cx| stream.poll_capacity(cx)).await {
+            Some(capacity) => capacity.chain_err(|| "could not send response")?,
+            None => return Err("BuildKit session closed the response stream".into()),
+        };
+        if capacity == 0 {
+            continue;
+        }
+        let chunk = data.split_to(capacity.min(data.len()));
+        stream
+            .send_data(chunk, false)
+            .chain_err(|| "could not send response")?;
+    }
+    Ok(())
+}
+
+/// Encode a protobuf message.
+fn encode<M: Message>(message: &M) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(message.encoded_len());
+    message
+        .encode(&mut buf)
+        .expect("Vec should always have enough capacity");
+    buf
+}
@@ -1,51 +1,61 @@
-use hyper;
+//! Resource usage statistics for running containers.
 
-use hyper::{Body, Response};
-
-use std::io::{BufRead, BufReader};
-use std::iter;
-
-use serde_json;
-
-use crate::errors::*;
-
-pub struct StatsReader {
-    buf: BufReader<Response<Body>>,
-}
-
-impl StatsReader {
-    pub fn new(r: Response<Body>) -> StatsReader {
-        StatsReader {
-            buf: BufReader::new(r),
-        }
-    }
-}
-
-impl iter::Iterator for StatsReader {
-    type Item = Result<Stats>;
-
-    fn next(&mut self) -> Option<Result<Stats>> {
-        let mut line = String::new();
-        if let Err(err) = self.buf.read_line(&mut line) {
-            return Some(Err(err.into()));
-        }
-        Some(
-            serde_json::from_str::<Stats>(&line).chain_err(|| ErrorKind::ParseError("Stats", line)),
-        )
-    }
-}
+use std::collections::HashMap;
 
+/// A single resource usage sample, as returned by `Docker::stats`.  Many
+/// sections are empty or missing when a container is not running, or
+/// depend on whether the host uses cgroups v1 or v2, so most fields default
+/// to zero.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Stats {
+    /// When this sample was taken.
     pub read: String,
-    pub network: Network,
+    /// When the previous sample was taken.
+    #[serde(default)]
+    pub preread: Option<String>,
+    /// The name of the container, starting with `/`.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The ID of the container.
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Network statistics for very old daemons which only reported a
+    /// single interface.
+    #[serde(default)]
+    pub network: Option<NetworkStats>,
+    /// Network statistics for each interface.
+    #[serde(default)]
+    pub networks: Option<HashMap<String, NetworkStats>>,
+    #[serde(default)]
     pub memory_stats: MemoryStats,
+    #[serde(default)]
     pub cpu_stats: CpuStats,
+    /// CPU usage as of the previous sample, used to compute percentages.
+    #[serde(default)]
+    pub precpu_stats: CpuStats,
+    #[serde(default)]
     pub blkio_stats: BlkioStats,
+    #[serde(default)]
+    pub pids_stats: PidsStats,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Network {
+impl Stats {
+    /// The percentage of the host's CPU time used by this container since
+    /// `previous` was sampled, calculated the same way as `docker stats`.
+    /// 100% corresponds to one fully-used CPU.
+    pub fn cpu_percent(&self, previous: &Stats) -> f64 {
+        self.cpu_stats.percent_since(&previous.cpu_stats)
+    }
+
+    /// The percentage of the container's memory limit which is in use, not
+    /// counting the page cache, calculated the same way as `docker stats`.
+    pub fn memory_percent(&self) -> f64 {
+        self.memory_stats.percent()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NetworkStats {
     pub rx_dropped: u64,
     pub rx_bytes: u64,
     pub rx_errors: u64,
@@ -56,83 +66,115 @@ pub struct Network {
     pub tx_bytes: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct MemoryStats {
-    pub max_usage: u64,
     pub usage: u64,
-    pub failcnt: u64,
+    /// Only reported on cgroups v1.
+    pub max_usage: Option<u64>,
+    pub failcnt: Option<u64>,
     pub limit: u64,
-    pub stats: MemoryStat,
+    /// Detailed counters from the kernel.  The available keys differ
+    /// between cgroups v1 (`cache`, `total_rss`, ...) and v2 (`anon`,
+    /// `inactive_file`, ...).
+    pub stats: HashMap<String, u64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MemoryStat {
-    pub total_pgmajfault: u64,
-    pub cache: u64,
-    pub mapped_file: u64,
-    pub total_inactive_file: u64,
-    pub pgpgout: u64,
-    pub rss: u64,
-    pub total_mapped_file: u64,
-    pub writeback: u64,
-    pub unevictable: u64,
-    pub pgpgin: u64,
-    pub total_unevictable: u64,
-    pub pgmajfault: u64,
-    pub total_rss: u64,
-    pub total_rss_huge: u64,
-    pub total_writeback: u64,
-    pub total_inactive_anon: u64,
-    pub rss_huge: u64,
-    pub hierarchical_memory_limit: u64,
-    pub hierarchical_memsw_limit: u64,
-    pub total_pgfault: u64,
-    pub total_active_file: u64,
-    pub active_anon: u64,
-    pub total_active_anon: u64,
-    pub total_pgpgout: u64,
-    pub total_cache: u64,
-    pub inactive_anon: u64,
-    pub active_file: u64,
-    pub pgfault: u64,
-    pub inactive_file: u64,
-    pub total_pgpgin: u64,
-    pub swap: u64,
-    pub total_swap: u64,
+impl MemoryStats {
+    /// The memory in use, not counting inactive page cache which the
+    /// kernel could reclaim.
+    pub fn used(&self) -> u64 {
+        // cgroups v1 reports `total_inactive_file`, and v2 `inactive_file`.
+        let cache = self
+            .stats
+            .get("total_inactive_file")
+            .or_else(|| self.stats.get("inactive_file"))
+            .copied()
+            .unwrap_or(0);
+        if cache < self.usage {
+            self.usage - cache
+        } else {
+            self.usage
+        }
+    }
+
+    /// `used` as a percentage of `limit`, or 0 if there is no limit.
+    pub fn percent(&self) -> f64 {
+        if self.limit == 0 {
+            0.0
+        } else {
+            self.used() as f64 / self.limit as f64 * 100.0
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct CpuStats {
     pub cpu_usage: CpuUsage,
-    pub system_cpu_usage: u64,
+    /// Missing when the container is not running.
+    pub system_cpu_usage: Option<u64>,
+    pub online_cpus: Option<u32>,
     pub throttling_data: ThrottlingData,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl CpuStats {
+    /// The CPU percentage used between `previous` and `self`.  To use the
+    /// previous sample reported by Docker, pass `&stats.precpu_stats`.
+    pub fn percent_since(&self, previous: &CpuStats) -> f64 {
+        let cpu_delta = self
+            .cpu_usage
+            .total_usage
+            .saturating_sub(previous.cpu_usage.total_usage);
+        let system_delta = match (self.system_cpu_usage, previous.system_cpu_usage) {
+            (Some(now), Some(before)) => now.saturating_sub(before),
+            _ => 0,
+        };
+        if cpu_delta == 0 || system_delta == 0 {
+            return 0.0;
+        }
+        let cpus = self.online_cpus.map(u64::from).unwrap_or_else(|| {
+            self.cpu_usage
+                .percpu_usage
+                .as_ref()
+                .map(|percpu| percpu.len() as u64)
+                .unwrap_or(0)
+        });
+        cpu_delta as f64 / system_delta as f64 * cpus as f64 * 100.0
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct CpuUsage {
-    pub percpu_usage: Vec<u64>,
+    /// Only reported on cgroups v1.
+    pub percpu_usage: Option<Vec<u64>>,
     pub usage_in_usermode: u64,
     pub total_usage: u64,
     pub usage_in_kernelmode: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ThrottlingData {
     pub periods: u64,
     pub throttled_periods: u64,
     pub throttled_time: u64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Block I/O statistics.  On cgroups v2, only `io_service_bytes_recursive`
+/// and `io_serviced_recursive` are reported, and any of these may be `null`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct BlkioStats {
-    pub io_service_bytes_recursive: Vec<BlkioStat>,
-    pub io_serviced_recursive: Vec<BlkioStat>,
-    pub io_queue_recursive: Vec<BlkioStat>,
-    pub io_service_time_recursive: Vec<BlkioStat>,
-    pub io_wait_time_recursive: Vec<BlkioStat>,
-    pub io_merged_recursive: Vec<BlkioStat>,
-    pub io_time_recursive: Vec<BlkioStat>,
-    pub sectors_recursive: Vec<BlkioStat>,
+    pub io_service_bytes_recursive: Option<Vec<BlkioStat>>,
+    pub io_serviced_recursive: Option<Vec<BlkioStat>>,
+    pub io_queue_recursive: Option<Vec<BlkioStat>>,
+    pub io_service_time_recursive: Option<Vec<BlkioStat>>,
+    pub io_wait_time_recursive: Option<Vec<BlkioStat>>,
+    pub io_merged_recursive: Option<Vec<BlkioStat>>,
+    pub io_time_recursive: Option<Vec<BlkioStat>>,
+    pub sectors_recursive: Option<Vec<BlkioStat>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -142,3 +184,10 @@ pub struct BlkioStat {
     pub op: String,
     pub value: u64,
 }
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PidsStats {
+    pub current: Option<u64>,
+    pub limit: Option<u64>,
+}
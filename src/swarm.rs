@@ -0,0 +1,220 @@
+//! Docker swarm mode clusters.
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use crate::image::null_to_default;
+#[cfg(feature = "chrono")]
+use crate::timestamps;
+
+/// The version of a swarm object.  Updates must pass the version they were
+/// based on, so that Docker can refuse updates which would overwrite
+/// someone else's changes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ObjectVersion {
+    pub Index: u64,
+}
+
+/// A swarm, as returned by `Docker::swarm_info`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct Swarm {
+    pub ID: String,
+    pub Version: ObjectVersion,
+    /// An RFC 3339 timestamp.
+    pub CreatedAt: String,
+    /// An RFC 3339 timestamp.
+    pub UpdatedAt: String,
+    pub Spec: SwarmSpec,
+    #[serde(default)]
+    pub TLSInfo: Option<TlsInfo>,
+    /// Is the root CA being rotated?
+    #[serde(default)]
+    pub RootRotationInProgress: bool,
+    /// The UDP port used for container traffic.
+    #[serde(default)]
+    pub DataPathPort: u32,
+    /// The address pools from which overlay subnets are allocated.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub DefaultAddrPool: Vec<String>,
+    #[serde(default)]
+    pub SubnetSize: u32,
+    /// The tokens used to join the swarm.
+    #[serde(default)]
+    pub JoinTokens: JoinTokens,
+}
+
+#[cfg(feature = "chrono")]
+impl Swarm {
+    /// When this swarm was created.
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        timestamps::parse_rfc3339(&self.CreatedAt)
+    }
+
+    /// When this swarm was last updated.
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        timestamps::parse_rfc3339(&self.UpdatedAt)
+    }
+}
+
+/// The tokens which let other nodes join a swarm.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct JoinTokens {
+    #[serde(default)]
+    pub Worker: String,
+    #[serde(default)]
+    pub Manager: String,
+}
+
+/// The certificates trusted by a swarm.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct TlsInfo {
+    /// The root CA certificate, in PEM format.
+    #[serde(default)]
+    pub TrustRoot: String,
+    #[serde(default)]
+    pub CertIssuerSubject: String,
+    #[serde(default)]
+    pub CertIssuerPublicKey: String,
+}
+
+/// The settings of a swarm, which can be changed with
+/// `Docker::swarm_update`.  Sections left as `None` keep their current
+/// values.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct SwarmSpec {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub Name: String,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Labels: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Orchestration: Option<OrchestrationConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Raft: Option<RaftConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub Dispatcher: Option<DispatcherConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub CAConfig: Option<CaConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub EncryptionConfig: Option<EncryptionConfig>,
+    /// Defaults for the tasks of every service, such as the log driver.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub TaskDefaults: Option<serde_json::Value>,
+}
+
+/// How the swarm schedules tasks.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct OrchestrationConfig {
+    /// How many old tasks to keep for each service slot.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub TaskHistoryRetentionLimit: Option<i64>,
+}
+
+/// Settings for the Raft consensus between managers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct RaftConfig {
+    /// How many log entries between snapshots.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub SnapshotInterval: Option<u64>,
+    /// How many snapshots to keep beyond the current one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub KeepOldSnapshots: Option<u64>,
+    /// How many log entries to keep after a snapshot, for slow followers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub LogEntriesForSlowFollowers: Option<u64>,
+    /// How many ticks without a heartbeat before starting an election.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ElectionTick: Option<u64>,
+    /// How many ticks between heartbeats from the leader.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub HeartbeatTick: Option<u64>,
+}
+
+/// Settings for how managers talk to workers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct DispatcherConfig {
+    /// How often workers report to managers, in nanoseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub HeartbeatPeriod: Option<i64>,
+}
+
+impl DispatcherConfig {
+    /// How often workers report to managers.
+    pub fn heartbeat_period(&self) -> Option<Duration> {
+        self.HeartbeatPeriod.and_then(nanos_to_duration)
+    }
+}
+
+/// Settings for the swarm's certificate authority.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct CaConfig {
+    /// How long node certificates are valid, in nanoseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub NodeCertExpiry: Option<i64>,
+    /// External CAs which issue node certificates.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub ExternalCAs: Vec<ExternalCa>,
+    /// A new root CA certificate to rotate to, in PEM format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub SigningCACert: Option<String>,
+    /// The key for `SigningCACert`, in PEM format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub SigningCAKey: Option<String>,
+    /// Increment this to rotate the root CA even if nothing else changed.
+    #[serde(default)]
+    pub ForceRotate: u64,
+}
+
+impl CaConfig {
+    /// How long node certificates are valid.
+    pub fn node_cert_expiry(&self) -> Option<Duration> {
+        self.NodeCertExpiry.and_then(nanos_to_duration)
+    }
+}
+
+/// An external certificate authority.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ExternalCa {
+    /// Only `"cfssl"` is supported.
+    pub Protocol: String,
+    pub URL: String,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Options: HashMap<String, String>,
+    /// The CA's root certificate, in PEM format.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub CACert: String,
+}
+
+/// Settings for encrypting swarm data at rest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct EncryptionConfig {
+    /// Must managers be unlocked with a key after restarting?
+    #[serde(default)]
+    pub AutoLockManagers: bool,
+}
+
+/// Convert a duration in nanoseconds, as used by swarm specs, into a
+/// `Duration`.
+fn nanos_to_duration(nanos: i64) -> Option<Duration> {
+    u64::try_from(nanos).ok().map(Duration::from_nanos)
+}
+
+/// The response to `Docker::swarm_unlock_key`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub(crate) struct UnlockKey {
+    pub(crate) UnlockKey: String,
+}
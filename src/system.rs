@@ -1,27 +1,323 @@
+use hyper::http::HeaderMap;
+use serde::de::{Deserialize, Deserializer};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::container::Container;
+use crate::image::{null_to_default, Image};
+use crate::volume::Volume;
+
+/// Information about the Docker host, as returned by `Docker::system_info`.
+/// Most fields are missing from older daemons, so they default to empty
+/// values.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(non_snake_case)]
 pub struct SystemInfo {
+    #[serde(default)]
+    pub ID: String,
     pub Containers: u64,
+    #[serde(default)]
+    pub ContainersRunning: u64,
+    #[serde(default)]
+    pub ContainersPaused: u64,
+    #[serde(default)]
+    pub ContainersStopped: u64,
     pub Images: u64,
     pub Driver: String,
+    #[serde(deserialize_with = "null_to_default", default)]
     pub DriverStatus: Vec<(String, String)>,
+    /// Only reported by very old daemons.
+    #[serde(default)]
     pub ExecutionDriver: String,
-    pub KernelVersion: String,
-    pub NCPU: u64,
-    pub MemTotal: u64,
-    pub Name: String,
-    pub ID: String,
-    pub Debug: u64, // bool
+    #[serde(default)]
+    pub Plugins: PluginsInfo,
+    #[serde(deserialize_with = "bool_or_int", default)]
+    pub MemoryLimit: bool,
+    #[serde(deserialize_with = "bool_or_int", default)]
+    pub SwapLimit: bool,
+    #[serde(deserialize_with = "bool_or_int", default)]
+    pub CpuCfsPeriod: bool,
+    #[serde(deserialize_with = "bool_or_int", default)]
+    pub CpuCfsQuota: bool,
+    #[serde(deserialize_with = "bool_or_int", default)]
+    pub CPUShares: bool,
+    #[serde(deserialize_with = "bool_or_int", default)]
+    pub CPUSet: bool,
+    #[serde(deserialize_with = "bool_or_int", default)]
+    pub PidsLimit: bool,
+    #[serde(deserialize_with = "bool_or_int", default)]
+    pub OomKillDisable: bool,
+    #[serde(deserialize_with = "bool_or_int", default)]
+    pub IPv4Forwarding: bool,
+    #[serde(deserialize_with = "bool_or_int", default)]
+    pub Debug: bool,
     pub NFd: u64,
     pub NGoroutines: u64,
+    /// The current time on the Docker host, as an RFC 3339 timestamp.
+    #[serde(default)]
+    pub SystemTime: String,
+    #[serde(default)]
+    pub LoggingDriver: String,
+    /// `"cgroupfs"` or `"systemd"`.
+    #[serde(default)]
+    pub CgroupDriver: String,
+    /// `"1"` or `"2"`.
+    #[serde(default)]
+    pub CgroupVersion: String,
     pub NEventsListener: u64,
-    pub InitPath: String,
-    pub InitSha1: String,
+    pub KernelVersion: String,
+    pub OperatingSystem: String,
+    #[serde(default)]
+    pub OSVersion: String,
+    /// `"linux"` or `"windows"`.
+    #[serde(default)]
+    pub OSType: String,
+    /// Such as `"x86_64"` or `"aarch64"`.
+    #[serde(default)]
+    pub Architecture: String,
+    pub NCPU: u64,
+    /// Total memory, in bytes.
+    pub MemTotal: u64,
     pub IndexServerAddress: String,
-    pub MemoryLimit: u64,    // bool
-    pub SwapLimit: u64,      // bool
-    pub IPv4Forwarding: u64, // bool
+    #[serde(default)]
+    pub RegistryConfig: Option<RegistryConfig>,
+    #[serde(default)]
+    pub HttpProxy: String,
+    #[serde(default)]
+    pub HttpsProxy: String,
+    #[serde(default)]
+    pub NoProxy: String,
+    pub Name: String,
     pub Labels: Option<Vec<String>>,
+    #[serde(deserialize_with = "bool_or_int", default)]
+    pub ExperimentalBuild: bool,
+    #[serde(default)]
+    pub ServerVersion: String,
+    /// The container runtimes available, by name.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Runtimes: HashMap<String, Runtime>,
+    #[serde(default)]
+    pub DefaultRuntime: String,
+    #[serde(default)]
+    pub Swarm: Option<SwarmInfo>,
+    #[serde(default)]
+    pub LiveRestoreEnabled: bool,
+    /// Such as `"name=seccomp,profile=default"`.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub SecurityOptions: Vec<String>,
     pub DockerRootDir: String,
-    pub OperatingSystem: String,
+    /// Only reported by very old daemons.
+    #[serde(default)]
+    pub InitPath: String,
+    /// Only reported by very old daemons.
+    #[serde(default)]
+    pub InitSha1: String,
+    /// Problems with the daemon's configuration.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Warnings: Vec<String>,
+}
+
+/// The plugins available on a Docker host, by type.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct PluginsInfo {
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Volume: Vec<String>,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Network: Vec<String>,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Authorization: Vec<String>,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Log: Vec<String>,
+}
+
+/// How a Docker host talks to registries.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct RegistryConfig {
+    /// Networks where registries may use plain HTTP.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub InsecureRegistryCIDRs: Vec<String>,
+    /// Registries, by name.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub IndexConfigs: HashMap<String, IndexInfo>,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Mirrors: Vec<String>,
+}
+
+/// How a Docker host talks to a specific registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct IndexInfo {
+    pub Name: String,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Mirrors: Vec<String>,
+    pub Secure: bool,
+    pub Official: bool,
+}
+
+/// A container runtime, such as `runc`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Runtime {
+    #[serde(default)]
+    pub path: String,
+    #[serde(rename = "runtimeArgs", deserialize_with = "null_to_default", default)]
+    pub runtime_args: Vec<String>,
+}
+
+/// The Docker host's part in a swarm.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct SwarmInfo {
+    #[serde(default)]
+    pub NodeID: String,
+    #[serde(default)]
+    pub NodeAddr: String,
+    /// `"inactive"`, `"pending"`, `"active"`, `"error"` or `"locked"`.
+    #[serde(default)]
+    pub LocalNodeState: String,
+    /// Is this node a manager?
+    #[serde(default)]
+    pub ControlAvailable: bool,
+    #[serde(default)]
+    pub Error: String,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub RemoteManagers: Vec<PeerNode>,
+    /// Only reported to managers.
+    #[serde(default)]
+    pub Nodes: Option<u64>,
+    /// Only reported to managers.
+    #[serde(default)]
+    pub Managers: Option<u64>,
+}
+
+/// A swarm manager.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct PeerNode {
+    pub NodeID: String,
+    pub Addr: String,
+}
+
+/// What `Docker::ping_info` learned about the daemon.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PingInfo {
+    /// The newest API version the daemon supports, such as `"1.43"`.
+    pub api_version: Option<String>,
+    /// The default builder: `"1"` for the classic builder, `"2"` for
+    /// BuildKit.
+    pub builder_version: Option<String>,
+    /// The daemon's swarm status, such as `"inactive"`, `"active/worker"`
+    /// or `"active/manager"`.
+    pub swarm: Option<String>,
+    /// `"linux"` or `"windows"`.
+    pub os_type: Option<String>,
+    /// Are experimental features enabled?
+    pub experimental: bool,
+    /// How long the ping took.
+    pub latency: Duration,
+}
+
+impl PingInfo {
+    /// Read the headers of a `/_ping` response.
+    pub(crate) fn from_headers(headers: &HeaderMap, latency: Duration) -> PingInfo {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_owned)
+        };
+        PingInfo {
+            api_version: header("Api-Version"),
+            builder_version: header("Builder-Version"),
+            swarm: header("Swarm"),
+            os_type: header("OSType"),
+            experimental: header("Docker-Experimental").as_deref() == Some("true"),
+            latency,
+        }
+    }
+
+    /// Does the daemon use BuildKit by default?
+    pub fn uses_buildkit(&self) -> bool {
+        self.builder_version.as_deref() == Some("2")
+    }
+
+    /// Is the daemon a swarm manager?
+    pub fn is_swarm_manager(&self) -> bool {
+        self.swarm.as_deref() == Some("active/manager")
+    }
+}
+
+/// Old daemons report some flags as `0` or `1` instead of booleans.
+fn bool_or_int<'de, D>(de: D) -> std::result::Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum BoolOrInt {
+        Bool(bool),
+        Int(u64),
+    }
+    Ok(match BoolOrInt::deserialize(de)? {
+        BoolOrInt::Bool(value) => value,
+        BoolOrInt::Int(value) => value != 0,
+    })
+}
+
+/// How much disk space Docker is using, as returned by `Docker::system_df`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct DiskUsage {
+    /// The total size of all image layers, in bytes.
+    pub LayersSize: u64,
+    /// Every image, with `SharedSize` and `Containers` filled in.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Images: Vec<Image>,
+    /// Every container, with `SizeRw` and `SizeRootFs` filled in.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Containers: Vec<Container>,
+    /// Every volume, with `UsageData` filled in.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Volumes: Vec<Volume>,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub BuildCache: Vec<BuildCache>,
+}
+
+impl DiskUsage {
+    /// The total size of the build cache, in bytes.
+    pub fn build_cache_size(&self) -> u64 {
+        self.BuildCache.iter().map(|cache| cache.Size).sum()
+    }
+
+    /// The total size of all volumes whose size Docker knows, in bytes.
+    pub fn volumes_size(&self) -> u64 {
+        self.Volumes
+            .iter()
+            .filter_map(|volume| volume.UsageData.and_then(|usage| usage.size()))
+            .sum()
+    }
+}
+
+/// A BuildKit build cache record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct BuildCache {
+    pub ID: String,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Parents: Vec<String>,
+    /// Such as `"regular"`, `"source.local"` or `"exec.cachemount"`.
+    pub Type: String,
+    #[serde(default)]
+    pub Description: String,
+    pub InUse: bool,
+    pub Shared: bool,
+    pub Size: u64,
+    /// An RFC 3339 timestamp.
+    pub CreatedAt: String,
+    /// An RFC 3339 timestamp.
+    #[serde(default)]
+    pub LastUsedAt: Option<String>,
+    #[serde(default)]
+    pub UsageCount: u64,
 }
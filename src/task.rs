@@ -0,0 +1,133 @@
+//! Docker swarm tasks, which are the individual containers run by a
+//! service.
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+use crate::image::null_to_default;
+use crate::service::TaskSpec;
+use crate::swarm::ObjectVersion;
+#[cfg(feature = "chrono")]
+use crate::timestamps;
+
+/// A task, as returned by `Docker::tasks` and `Docker::task_info`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct Task {
+    pub ID: String,
+    pub Version: ObjectVersion,
+    /// An RFC 3339 timestamp.
+    pub CreatedAt: String,
+    /// An RFC 3339 timestamp.
+    pub UpdatedAt: String,
+    #[serde(default)]
+    pub Name: String,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Labels: HashMap<String, String>,
+    pub Spec: TaskSpec,
+    pub ServiceID: String,
+    /// Which replica of a replicated service this task is.
+    #[serde(default)]
+    pub Slot: Option<u64>,
+    /// The node the task was scheduled on.  Empty until it is scheduled.
+    #[serde(default)]
+    pub NodeID: String,
+    pub Status: TaskStatus,
+    /// The state the swarm is trying to move the task to.
+    pub DesiredState: TaskState,
+}
+
+impl Task {
+    /// The ID of the container running this task, once it has one.
+    pub fn container_id(&self) -> Option<&str> {
+        self.Status
+            .ContainerStatus
+            .as_ref()
+            .map(|status| status.ContainerID.as_str())
+            .filter(|id| !id.is_empty())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Task {
+    /// When this task was created.
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        timestamps::parse_rfc3339(&self.CreatedAt)
+    }
+
+    /// When this task was last updated.
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        timestamps::parse_rfc3339(&self.UpdatedAt)
+    }
+}
+
+/// What is happening to a task.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct TaskStatus {
+    /// An RFC 3339 timestamp.
+    #[serde(default)]
+    pub Timestamp: String,
+    pub State: TaskState,
+    /// Such as `"started"`.
+    #[serde(default)]
+    pub Message: String,
+    /// Why the task failed, if it did.
+    #[serde(default)]
+    pub Err: Option<String>,
+    #[serde(default)]
+    pub ContainerStatus: Option<TaskContainerStatus>,
+}
+
+/// The container running a task.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct TaskContainerStatus {
+    #[serde(default)]
+    pub ContainerID: String,
+    #[serde(default)]
+    pub PID: i64,
+    #[serde(default)]
+    pub ExitCode: i64,
+}
+
+/// The state of a task.  Tasks move through these states in order, except
+/// that they may stop at any point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskState {
+    New,
+    Allocated,
+    Pending,
+    Assigned,
+    Accepted,
+    Preparing,
+    Ready,
+    Starting,
+    Running,
+    Complete,
+    Shutdown,
+    Failed,
+    Rejected,
+    Remove,
+    Orphaned,
+    /// A state we don't know about.
+    #[serde(other)]
+    Unknown,
+}
+
+impl TaskState {
+    /// Has the task stopped for good?
+    pub fn is_terminal(self) -> bool {
+        matches!(
+            self,
+            TaskState::Complete
+                | TaskState::Shutdown
+                | TaskState::Failed
+                | TaskState::Rejected
+                | TaskState::Remove
+                | TaskState::Orphaned
+        )
+    }
+}
@@ -1,32 +1,80 @@
 #[cfg(test)]
-use crate::container::{Container, ContainerInfo};
+use crate::archive::{split_container_path, tar_build_context, tar_path, untar_to};
 #[cfg(test)]
-use crate::filesystem::FilesystemChange;
+use crate::attach::decode_attach_stream;
 #[cfg(test)]
-use crate::image::Image;
+use crate::auth::{AuthResponse, RegistryAuth};
 #[cfg(test)]
-use crate::process::Top;
-//#[cfg(test)]
-//use crate::stats::{Stats, StatsReader};
+use crate::build::{BuildkitStatus, Vertex, VertexLog};
 #[cfg(test)]
-use crate::system::SystemInfo;
-//#[cfg(test)]
-//use crate::util::MemoryStream;
+use crate::config::{parse_helper_output, CliConfig};
+#[cfg(test)]
+use crate::container::{
+    Container, ContainerInfo, ContainerStatus, ContainerWaitResponse, CreateContainerResponse,
+    DeviceRequest, HealthStatus, NetworkSettings, RestartPolicy, State,
+};
+#[cfg(test)]
+use crate::docker::{decode_path_stat, query_params};
+#[cfg(test)]
+use crate::dockerignore::DockerIgnore;
+#[cfg(test)]
+use crate::errors::Result;
+#[cfg(test)]
+use crate::event::{EventAction, EventMessage, EventStream, EventType};
+#[cfg(test)]
+use crate::filesystem::{ChangeKind, FilesystemChange};
+#[cfg(test)]
+use crate::image::{Image, ImageInfo, RemovedImage, SearchResult};
+#[cfg(test)]
+use crate::json_lines::decode_json_lines;
+#[cfg(test)]
+use crate::logs::{decode_log_stream, LogDecoder, LogLine, LogStream, ServiceLogLine};
+#[cfg(test)]
+use crate::network::{IpamConfig, Network, PruneNetworksResponse};
+#[cfg(test)]
+use crate::node::{Node, NodeAvailability, NodeRole, NodeState};
+#[cfg(test)]
+use crate::options::{
+    AttachOptions, BindPropagation, BuildOptions, ContainerCreateOptions, ContainerListOptions,
+    CreateNetworkOptions, CreateVolumeOptions, EndpointConfig, EventOptions, ImageListOptions,
+    LogOptions, MountSpec, NetworkListOptions, NodeListOptions, Protocol, PruneContainersOptions,
+    PruneNetworksOptions, PruneVolumesOptions, PullImageOptions, RemoveImageOptions, SearchOptions,
+    SecretListOptions, ServiceListOptions, Signal, SwarmInitOptions, SwarmJoinOptions,
+    SwarmUpdateOptions, TaskListOptions, UpdateServiceOptions, VolumeListOptions,
+};
+#[cfg(test)]
+use crate::process::{parse_ps_duration, Process, Top};
+#[cfg(test)]
+use crate::progress::{decode_progress, ProgressEvent};
+#[cfg(test)]
+use crate::reference::ImageReference;
+#[cfg(test)]
+use crate::secret::{Secret, SecretSpec};
+#[cfg(test)]
+use crate::service::{
+    Service, ServiceMode, ServiceSpec, UpdateState, UpdateStatus, UpdateStep, UpdateWatch,
+    UPDATE_START_TIMEOUT,
+};
+#[cfg(test)]
+use crate::session;
+#[cfg(test)]
+use crate::stats::Stats;
+#[cfg(test)]
+use crate::swarm::{ObjectVersion, Swarm, UnlockKey};
+#[cfg(test)]
+use crate::system::{DiskUsage, PingInfo, SystemInfo};
+#[cfg(test)]
+use crate::task::{Task, TaskState};
 #[cfg(test)]
 use crate::version::Version;
-//#[cfg(test)]
-//use hyper::client::response::Response;
-//#[cfg(test)]
-//use hyper::http::h1::{Http11Message, HttpWriter};
-//#[cfg(test)]
-//use hyper::Url;
 #[cfg(test)]
-use serde_json;
-//#[cfg(test)]
-//use std::io::Write;
+use crate::volume::{PruneVolumesResponse, Volume, VolumeList, VolumeUsageData};
+#[cfg(test)]
+use std::time::Duration;
 
 #[test]
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 fn get_containers() {
     let response = get_containers_response();
     let _: Vec<Container> = match serde_json::from_str(&response) {
@@ -38,55 +86,394 @@ fn get_containers() {
     };
 }
 
-/*
+#[test]
+#[cfg(test)]
+fn get_containers_with_networks_and_mounts() {
+    let response = get_containers_response_with_networks_and_mounts();
+    let containers: Vec<Container> = serde_json::from_str(&response).unwrap();
+    let container = &containers[0];
+    assert_eq!(container.State, Some(ContainerStatus::Running));
+    assert!(container.ImageID.starts_with("sha256:"));
+    assert_eq!(container.Ports[0].PublicPort, Some(8080));
+    let network = &container.NetworkSettings.Networks["bridge"];
+    assert_eq!(network.IPAddress, "172.17.0.2");
+    assert_eq!(container.Mounts[0].Type.as_deref(), Some("volume"));
+    assert_eq!(container.Mounts[0].Name.as_deref(), Some("data"));
+    assert_eq!(container.Mounts[0].Destination, "/data");
+}
+
 #[test]
 #[cfg(test)]
 fn get_stats_single() {
     let response = get_stats_single_event(1);
+    let stats: Stats = serde_json::from_str(&response).unwrap();
+    assert_eq!(stats.memory_stats.stats["cache"], 178946048);
+}
 
-    print!("{}", response);
-    let _: Stats = match serde_json::from_str(&response) {
-        Ok(body) => body,
-        Err(_) => {
-            assert!(false);
-            return;
+#[test]
+#[cfg(test)]
+fn stats_percentages() {
+    let previous: Stats = serde_json::from_value(serde_json::json!({
+        "read": "2020-04-09T15:36:26Z",
+        "cpu_stats": {
+            "cpu_usage": { "total_usage": 1_000_000_000u64 },
+            "system_cpu_usage": 10_000_000_000u64,
+            "online_cpus": 4,
+        },
+    }))
+    .unwrap();
+    let current: Stats = serde_json::from_value(serde_json::json!({
+        "read": "2020-04-09T15:36:27Z",
+        "cpu_stats": {
+            "cpu_usage": { "total_usage": 1_500_000_000u64 },
+            "system_cpu_usage": 14_000_000_000u64,
+            "online_cpus": 4,
+        },
+        "memory_stats": {
+            "usage": 300,
+            "limit": 1000,
+            "stats": { "inactive_file": 100 },
+        },
+    }))
+    .unwrap();
+    assert!((current.cpu_percent(&previous) - 50.0).abs() < 1e-9);
+    assert_eq!(current.memory_stats.used(), 200);
+    assert!((current.memory_percent() - 20.0).abs() < 1e-9);
+
+    // No time has passed, and there's no memory limit.
+    assert_eq!(previous.cpu_percent(&previous), 0.0);
+    assert_eq!(previous.memory_percent(), 0.0);
+}
+
+#[test]
+#[cfg(test)]
+fn decode_pull_progress() {
+    use futures::StreamExt;
+    use hyper::Body;
+
+    let params = PullImageOptions::default()
+        .platform("linux/arm64")
+        .to_url_params();
+    assert_eq!(params, "platform=linux%2Farm64");
+    assert_eq!(
+        query_params(&[("fromImage", "alpine"), ("tag", "3.12")], &params),
+        "fromImage=alpine&tag=3.12&platform=linux%2Farm64"
+    );
+    let params = PullImageOptions::default().to_url_params();
+    assert_eq!(
+        query_params(&[("fromImage", "alpine"), ("tag", "3.12")], &params),
+        "fromImage=alpine&tag=3.12"
+    );
+
+    let body = Body::from(get_pull_image_response());
+    let messages: Vec<Result<ProgressEvent>> =
+        futures::executor::block_on(decode_progress(body).collect());
+    assert_eq!(messages.len(), 4);
+    assert_eq!(
+        messages[1].as_ref().unwrap(),
+        &ProgressEvent::Progress {
+            id: Some("a3ed95caeb02".to_owned()),
+            status: "Downloading".to_owned(),
+            current: 32,
+            total: Some(2065),
         }
+    );
+    assert_eq!(
+        messages[2].as_ref().unwrap(),
+        &ProgressEvent::Status {
+            id: Some("a3ed95caeb02".to_owned()),
+            status: "Pull complete".to_owned(),
+        }
+    );
+    let err = messages[3].as_ref().unwrap_err();
+    assert!(err.to_string().contains("manifest unknown"));
+}
+
+#[test]
+#[cfg(test)]
+fn decode_push_result() {
+    let aux = r#"{"progressDetail":{},"aux":{"Tag":"1.0","Digest":"sha256:abc","Size":527}}"#;
+    let progress: ProgressEvent = serde_json::from_str(aux).unwrap();
+    assert_eq!(progress.push_result().unwrap().Digest, "sha256:abc");
+}
+
+#[cfg(test)]
+fn decode_auth_header(header: &str) -> serde_json::Value {
+    serde_json::from_slice(&base64::decode_config(header, base64::URL_SAFE).unwrap()).unwrap()
+}
+
+#[test]
+#[cfg(test)]
+fn registry_auth_header() {
+    let auth = RegistryAuth::new("alice", "s3cret").server_address("registry.example.com");
+    assert_eq!(
+        decode_auth_header(&auth.to_header().unwrap()),
+        serde_json::json!({
+            "username": "alice",
+            "password": "s3cret",
+            "serveraddress": "registry.example.com",
+        })
+    );
+    assert!(!format!("{:?}", auth).contains("s3cret"));
+    assert_eq!(RegistryAuth::default().to_header().unwrap(), "e30=");
+}
+
+#[test]
+#[cfg(test)]
+fn load_config_auths() {
+    let config: CliConfig = serde_json::from_str(
+        r#"{
+            "auths": {
+                "https://index.docker.io/v1/": {"auth": "YWxpY2U6czNjcjp0"},
+                "registry.example.com": {"identitytoken": "tok"}
+            }
+        }"#,
+    )
+    .unwrap();
+    let hub = config.saved_auth("docker.io").unwrap().unwrap();
+    assert_eq!(
+        decode_auth_header(&hub.to_header().unwrap()),
+        serde_json::json!({
+            "username": "alice",
+            "password": "s3cr:t",
+            "serveraddress": "https://index.docker.io/v1/",
+        })
+    );
+    let example = config
+        .saved_auth("https://registry.example.com/v2/")
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        decode_auth_header(&example.to_header().unwrap()),
+        serde_json::json!({
+            "serveraddress": "registry.example.com",
+            "identitytoken": "tok",
+        })
+    );
+    assert!(config.saved_auth("quay.io").unwrap().is_none());
+    assert_eq!(config.credential_helper("docker.io"), None);
+}
+
+#[test]
+#[cfg(test)]
+fn credential_helpers() {
+    let config: CliConfig = serde_json::from_str(
+        r#"{
+            "credsStore": "osxkeychain",
+            "credHelpers": {"123456789012.dkr.ecr.us-east-1.amazonaws.com": "ecr-login"}
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(config.credential_helper("docker.io"), Some("osxkeychain"));
+    assert_eq!(
+        config.credential_helper("123456789012.dkr.ecr.us-east-1.amazonaws.com"),
+        Some("ecr-login")
+    );
+    let output =
+        br#"{"ServerURL":"https://index.docker.io/v1/","Username":"<token>","Secret":"tok"}"#;
+    let auth = parse_helper_output(output, "https://index.docker.io/v1/").unwrap();
+    assert_eq!(
+        decode_auth_header(&auth.to_header().unwrap()),
+        serde_json::json!({
+            "serveraddress": "https://index.docker.io/v1/",
+            "identitytoken": "tok",
+        })
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn auth_response_identity_token() {
+    let response: AuthResponse =
+        serde_json::from_str(r#"{"Status":"Login Succeeded","IdentityToken":""}"#).unwrap();
+    assert_eq!(response.Status, "Login Succeeded");
+    assert!(response.identity_token().is_none());
+    let response: AuthResponse =
+        serde_json::from_str(r#"{"Status":"Login Succeeded","IdentityToken":"tok"}"#).unwrap();
+    assert!(response.identity_token().is_some());
+    let response: AuthResponse = serde_json::from_str(r#"{"Status":"Login Succeeded"}"#).unwrap();
+    assert_eq!(response.IdentityToken, None);
+}
+
+#[test]
+#[cfg(test)]
+fn decode_build_messages() {
+    use futures::StreamExt;
+    use hyper::Body;
+
+    let opts = BuildOptions::default()
+        .dockerfile("docker/Dockerfile")
+        .tag("example/app:1.0")
+        .tag("example/app:latest")
+        .force_rm();
+    assert_eq!(
+        opts.to_url_params(),
+        "dockerfile=docker%2FDockerfile&t=example%2Fapp%3A1.0&t=example%2Fapp%3Alatest&forcerm=1"
+    );
+
+    let opts = BuildOptions::default()
+        .build_arg("VERSION", "1.2")
+        .label("org.example.ci", "true")
+        .target("runtime")
+        .cache_from("example/app:cache")
+        .pull()
+        .no_cache();
+    let params = url::form_urlencoded::parse(opts.to_url_params().as_bytes())
+        .into_owned()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        params,
+        vec![
+            ("buildargs".to_owned(), r#"{"VERSION":"1.2"}"#.to_owned()),
+            (
+                "labels".to_owned(),
+                r#"{"org.example.ci":"true"}"#.to_owned()
+            ),
+            ("target".to_owned(), "runtime".to_owned()),
+            (
+                "cachefrom".to_owned(),
+                r#"["example/app:cache"]"#.to_owned()
+            ),
+            ("pull".to_owned(), "1".to_owned()),
+            ("nocache".to_owned(), "1".to_owned()),
+        ]
+    );
+
+    let body = Body::from(get_build_image_response());
+    let messages: Vec<Result<ProgressEvent>> =
+        futures::executor::block_on(decode_progress(body).collect());
+    assert_eq!(messages.len(), 4);
+    assert_eq!(
+        messages[0].as_ref().unwrap(),
+        &ProgressEvent::Stream("Step 1/2 : FROM alpine\n".to_owned())
+    );
+    assert_eq!(
+        messages[2].as_ref().unwrap().image_id(),
+        Some("sha256:4a4b5d0b3e1f")
+    );
+    let err = messages[3].as_ref().unwrap_err();
+    assert!(err.to_string().contains("returned a non-zero code: 1"));
+}
+
+#[test]
+#[cfg(test)]
+fn decode_buildkit_trace() {
+    use prost::Message;
+
+    let params = BuildOptions::default().buildkit().to_url_params();
+    assert_eq!(params, "version=2");
+
+    let status = BuildkitStatus {
+        vertexes: vec![Vertex {
+            digest: "sha256:aaaa".to_owned(),
+            name: "[1/2] FROM docker.io/library/alpine".to_owned(),
+            cached: true,
+            ..Vertex::default()
+        }],
+        logs: vec![VertexLog {
+            vertex: "sha256:aaaa".to_owned(),
+            stream: 1,
+            msg: b"hello\n".to_vec(),
+            ..VertexLog::default()
+        }],
+        ..BuildkitStatus::default()
     };
+    let mut encoded = vec![];
+    status.encode(&mut encoded).unwrap();
+    let json = format!(
+        r#"{{"id":"moby.buildkit.trace","aux":"{}"}}"#,
+        base64::encode(&encoded)
+    );
+    let message: ProgressEvent = serde_json::from_str(&json).unwrap();
+    assert_eq!(message.image_id(), None);
+    assert_eq!(message.trace().unwrap(), Some(status));
+
+    let json = r#"{"id":"moby.image.id","aux":{"ID":"sha256:4a4b5d0b3e1f"}}"#;
+    let message: ProgressEvent = serde_json::from_str(json).unwrap();
+    assert_eq!(message.image_id(), Some("sha256:4a4b5d0b3e1f"));
+    assert_eq!(message.trace().unwrap(), None);
+}
+
+#[test]
+#[cfg(test)]
+fn buildkit_session_providers() {
+    use bytes::BytesMut;
+
+    let opts = BuildOptions::default()
+        .secret("npmrc", "/home/me/.npmrc")
+        .ssh("default", "/tmp/ssh-agent.sock");
+    assert!(opts.uses_buildkit());
+    assert_eq!(opts.to_url_params(), "version=2");
+    let methods = session::exposed_methods(opts.session_providers());
+    assert!(methods.contains(&"/moby.buildkit.secrets.v1.Secrets/GetSecret"));
+    assert!(methods.contains(&"/moby.sshforward.v1.SSH/ForwardAgent"));
+    let methods = session::exposed_methods(BuildOptions::default().session_providers());
+    assert_eq!(methods, vec!["/grpc.health.v1.Health/Check"]);
+
+    let mut buf = BytesMut::new();
+    buf.extend_from_slice(&session::frame(b"one"));
+    buf.extend_from_slice(&session::frame(b""));
+    buf.extend_from_slice(&session::frame(b"three")[..4]);
+    assert_eq!(&session::unframe(&mut buf).unwrap().unwrap()[..], b"one");
+    assert_eq!(&session::unframe(&mut buf).unwrap().unwrap()[..], b"");
+    assert!(session::unframe(&mut buf).unwrap().is_none());
+    buf.extend_from_slice(&session::frame(b"three")[4..]);
+    assert_eq!(&session::unframe(&mut buf).unwrap().unwrap()[..], b"three");
 }
 
 #[test]
 #[cfg(test)]
 fn get_stats_streaming() {
-    let url = Url::parse("http://localhost/who/cares").unwrap();
-    let stream = MemoryStream::with_input(get_stats_response().as_bytes());
-    let message = Http11Message::with_stream(Box::new(stream));
-    let response = Response::with_message(url, Box::new(message)).unwrap();
-    let mut reader = StatsReader::new(response);
+    use futures::StreamExt;
+    use hyper::Body;
 
-    let stats = reader.next().unwrap().unwrap();
-    assert_eq!(stats.read, "2015-04-09T07:02:08.480022081Z".to_string());
+    let body = Body::from(get_stats_response());
+    let mut stream = decode_json_lines::<Stats>("Stats", body);
 
-    let stats = reader.next().unwrap().unwrap();
-    assert_eq!(stats.read, "2015-04-09T07:02:08.480022082Z".to_string());
+    futures::executor::block_on(async {
+        let stats = stream.next().await.unwrap().unwrap();
+        assert_eq!(stats.read, "2015-04-09T07:02:08.480022081Z".to_string());
 
-    let stats = reader.next().unwrap().unwrap();
-    assert_eq!(stats.read, "2015-04-09T07:02:08.480022083Z".to_string());
+        let stats = stream.next().await.unwrap().unwrap();
+        assert_eq!(stats.read, "2015-04-09T07:02:08.480022082Z".to_string());
 
-    assert!(reader.next().unwrap().is_err());
+        let stats = stream.next().await.unwrap().unwrap();
+        assert_eq!(stats.read, "2015-04-09T07:02:08.480022083Z".to_string());
+
+        assert!(stream.next().await.is_none());
+    });
 }
-*/
 
 #[test]
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 fn get_system_info() {
     let response = get_system_info_response();
-    let _: SystemInfo = match serde_json::from_str(&response) {
+    let info: SystemInfo = match serde_json::from_str(&response) {
         Ok(body) => body,
         Err(_) => {
             assert!(false);
             return;
         }
     };
+    assert!(info.MemoryLimit);
+    assert!(!info.Debug);
+    assert!(info.Swarm.is_none());
+
+    let response = get_modern_system_info_response();
+    let info: SystemInfo = serde_json::from_str(&response).unwrap();
+    assert_eq!(info.ContainersRunning, 1);
+    assert_eq!(info.CgroupVersion, "2");
+    assert_eq!(info.SecurityOptions.len(), 3);
+    assert_eq!(info.Plugins.Volume, vec!["local"]);
+    assert_eq!(info.Runtimes["runc"].path, "runc");
+    assert_eq!(info.DefaultRuntime, "runc");
+    let registries = info.RegistryConfig.unwrap();
+    assert!(registries.IndexConfigs["docker.io"].Official);
+    let swarm = info.Swarm.unwrap();
+    assert_eq!(swarm.LocalNodeState, "active");
+    assert!(swarm.ControlAvailable);
+    assert_eq!(swarm.Managers, Some(1));
 }
 
 #[test]
@@ -95,17 +482,463 @@ fn get_images() {
     let response = get_images_response();
     let images: Vec<Image> = serde_json::from_str(&response).unwrap();
     assert_eq!(3, images.len());
+
+    let params = ImageListOptions::default()
+        .all()
+        .digests()
+        .dangling(false)
+        .reference("alpine")
+        .to_url_params();
+    let params = url::form_urlencoded::parse(params.as_bytes())
+        .into_owned()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        params,
+        vec![
+            ("all".to_owned(), "1".to_owned()),
+            ("digests".to_owned(), "1".to_owned()),
+            (
+                "filters".to_owned(),
+                r#"{"dangling":["false"],"reference":["alpine"]}"#.to_owned()
+            ),
+        ]
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn get_image_info() {
+    let response = get_image_info_response();
+    let info: ImageInfo = serde_json::from_str(&response).unwrap();
+    assert_eq!(info.Architecture, "amd64");
+    assert_eq!(info.Os, "linux");
+    assert_eq!(info.RepoDigests.len(), 1);
+    assert_eq!(info.RootFS.Layers.len(), 2);
+    let config = info.Config.unwrap();
+    assert_eq!(config.Cmd, Some(vec!["/bin/sh".to_owned()]));
+    assert!(config.Labels.is_empty());
+}
+
+#[test]
+#[cfg(test)]
+fn search_images() {
+    let params = SearchOptions::default()
+        .limit(5)
+        .official(true)
+        .stars(100)
+        .to_url_params();
+    let params = url::form_urlencoded::parse(params.as_bytes())
+        .into_owned()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        params,
+        vec![
+            ("limit".to_owned(), "5".to_owned()),
+            (
+                "filters".to_owned(),
+                r#"{"is-official":["true"],"stars":["100"]}"#.to_owned()
+            ),
+        ]
+    );
+
+    let response = r#"[{"star_count":9012,"is_official":true,"name":"alpine","is_automated":false,"description":"A minimal Docker image based on Alpine Linux"}]"#;
+    let results: Vec<SearchResult> = serde_json::from_str(response).unwrap();
+    assert_eq!(results[0].name, "alpine");
+    assert!(results[0].is_official);
+    assert_eq!(results[0].star_count, 9012);
+}
+
+#[test]
+#[cfg(test)]
+fn list_networks() {
+    let params = NetworkListOptions::default()
+        .driver("bridge")
+        .scope("local")
+        .to_url_params();
+    let params = url::form_urlencoded::parse(params.as_bytes())
+        .into_owned()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        params,
+        vec![(
+            "filters".to_owned(),
+            r#"{"driver":["bridge"],"scope":["local"]}"#.to_owned()
+        )]
+    );
+
+    let response = get_networks_response();
+    let networks: Vec<Network> = serde_json::from_str(&response).unwrap();
+    assert_eq!(networks.len(), 2);
+    assert_eq!(networks[0].Name, "bridge");
+    assert_eq!(networks[0].Driver, "bridge");
+    let ipam = networks[0].IPAM.as_ref().unwrap();
+    assert_eq!(ipam.Config[0].Subnet.as_deref(), Some("172.17.0.0/16"));
+    assert_eq!(
+        networks[0].Options["com.docker.network.bridge.name"],
+        "docker0"
+    );
+    assert!(networks[1].Containers.is_empty());
+    assert!(networks[1].Labels.is_empty());
+}
+
+#[test]
+#[cfg(test)]
+fn get_network_info() {
+    let response = get_network_info_response();
+    let network: Network = serde_json::from_str(&response).unwrap();
+    assert_eq!(network.Name, "net01");
+    assert_eq!(network.Containers.len(), 1);
+    let container =
+        &network.Containers["19a4d5d687db25203351ed79d478946f861258f018fe384f229f2efa4b23513c"];
+    assert_eq!(container.Name, "test");
+    assert_eq!(container.IPv4Address, "172.19.0.2/16");
+    let config = &network.IPAM.as_ref().unwrap().Config[0];
+    assert_eq!(config.Gateway.as_deref(), Some("172.19.0.1"));
+    assert_eq!(config.IPRange.as_deref(), Some("172.19.0.0/24"));
+    assert_eq!(network.Labels["com.example.some-label"], "some-value");
+    assert_eq!(network.Peers[0].IP, "10.0.0.2");
+}
+
+#[test]
+#[cfg(test)]
+fn create_network() {
+    let opts = CreateNetworkOptions::new("isolated")
+        .driver("bridge")
+        .internal()
+        .label("com.example.test", "1")
+        .ipam_config(
+            IpamConfig::new("172.28.0.0/16")
+                .ip_range("172.28.5.0/24")
+                .gateway("172.28.5.254"),
+        );
+    let json = serde_json::to_value(&opts).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "Name": "isolated",
+            "CheckDuplicate": true,
+            "Driver": "bridge",
+            "Internal": true,
+            "Attachable": false,
+            "EnableIPv6": false,
+            "IPAM": {
+                "Config": [{
+                    "Subnet": "172.28.0.0/16",
+                    "IPRange": "172.28.5.0/24",
+                    "Gateway": "172.28.5.254",
+                }],
+            },
+            "Labels": { "com.example.test": "1" },
+        })
+    );
+
+    let opts = CreateNetworkOptions::new("dual-stack")
+        .enable_ipv6()
+        .ipam_config(IpamConfig::new("172.29.0.0/16"))
+        .ipam_config(IpamConfig::new("fd00:db8::/64").gateway("fd00:db8::1"));
+    let json = serde_json::to_value(&opts).unwrap();
+    assert_eq!(json["EnableIPv6"], true);
+    assert_eq!(
+        json["IPAM"]["Config"],
+        serde_json::json!([
+            { "Subnet": "172.29.0.0/16" },
+            { "Subnet": "fd00:db8::/64", "Gateway": "fd00:db8::1" },
+        ])
+    );
+
+    let opts = CreateNetworkOptions::new("services")
+        .driver("overlay")
+        .scope("swarm")
+        .attachable()
+        .encrypted();
+    let json = serde_json::to_value(&opts).unwrap();
+    assert_eq!(json["Driver"], "overlay");
+    assert_eq!(json["Scope"], "swarm");
+    assert_eq!(json["Attachable"], true);
+    assert_eq!(json["Options"], serde_json::json!({ "encrypted": "" }));
+}
+
+#[test]
+#[cfg(test)]
+fn network_endpoint_config() {
+    let json = serde_json::to_value(EndpointConfig::default()).unwrap();
+    assert_eq!(json, serde_json::json!({}));
+
+    let config = EndpointConfig::default()
+        .alias("db")
+        .alias("postgres")
+        .ipv4_address("172.28.5.10")
+        .link_local_ip("169.254.0.10");
+    let json = serde_json::to_value(&config).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "Aliases": ["db", "postgres"],
+            "IPAMConfig": {
+                "IPv4Address": "172.28.5.10",
+                "LinkLocalIPs": ["169.254.0.10"],
+            },
+        })
+    );
+
+    let json =
+        serde_json::to_value(EndpointConfig::default().ipv6_address("fd00:db8::10")).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({ "IPAMConfig": { "IPv6Address": "fd00:db8::10" } })
+    );
+
+    let settings: NetworkSettings = serde_json::from_value(serde_json::json!({
+        "Ports": null,
+        "SandboxID": "",
+        "SandboxKey": "",
+        "Networks": {
+            "dual-stack": {
+                "IPAddress": "172.29.0.2",
+                "IPPrefixLen": 16,
+                "GlobalIPv6Address": "fd00:db8::2",
+                "GlobalIPv6PrefixLen": 64,
+                "IPv6Gateway": "fd00:db8::1",
+            },
+        },
+    }))
+    .unwrap();
+    assert_eq!(settings.ip_address("dual-stack"), Some("172.29.0.2"));
+    assert_eq!(settings.ipv6_address("dual-stack"), Some("fd00:db8::2"));
+    assert_eq!(settings.Networks["dual-stack"].GlobalIPv6PrefixLen, 64);
+}
+
+#[test]
+#[cfg(test)]
+fn list_volumes() {
+    let params = VolumeListOptions::default()
+        .dangling(true)
+        .label("com.example.app")
+        .to_url_params();
+    let filters: Vec<_> = url::form_urlencoded::parse(params.as_bytes()).collect();
+    assert_eq!(
+        filters[0].1,
+        r#"{"dangling":["true"],"label":["com.example.app"]}"#
+    );
+
+    let response = r#"{"Volumes":[{"CreatedAt":"2017-07-19T12:00:26Z","Name":"tardis","Driver":"local","Mountpoint":"/var/lib/docker/volumes/tardis","Labels":{"com.example.some-label":"some-value"},"Scope":"local","Options":null},{"Name":"old","Driver":"local","Mountpoint":"/var/lib/docker/volumes/old/_data","Labels":null}],"Warnings":[]}"#;
+    let list: VolumeList = serde_json::from_str(response).unwrap();
+    assert_eq!(list.Volumes.len(), 2);
+    let volume = &list.Volumes[0];
+    assert_eq!(volume.Name, "tardis");
+    assert_eq!(volume.Mountpoint, "/var/lib/docker/volumes/tardis");
+    assert_eq!(volume.Labels["com.example.some-label"], "some-value");
+    assert_eq!(volume.Scope, "local");
+    assert!(volume.Options.is_empty());
+    assert_eq!(list.Volumes[1].CreatedAt, None);
+    assert!(volume.UsageData.is_none());
+
+    let volume: Volume = serde_json::from_str(r#"{"Name":"tardis","Driver":"local","Mountpoint":"/var/lib/docker/volumes/tardis/_data","UsageData":{"Size":4096,"RefCount":2}}"#).unwrap();
+    let usage = volume.UsageData.unwrap();
+    assert_eq!(usage.size(), Some(4096));
+    assert_eq!(usage.ref_count(), Some(2));
+    let usage: VolumeUsageData = serde_json::from_str(r#"{"Size":-1,"RefCount":-1}"#).unwrap();
+    assert_eq!(usage.size(), None);
+    assert_eq!(usage.ref_count(), None);
+
+    let opts = CreateVolumeOptions::default()
+        .name("nfs-data")
+        .driver_opt("type", "nfs")
+        .driver_opt("o", "addr=10.0.0.10,rw")
+        .driver_opt("device", ":/exports/data")
+        .label("com.example.test", "1");
+    let json = serde_json::to_value(&opts).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "Name": "nfs-data",
+            "DriverOpts": {
+                "type": "nfs",
+                "o": "addr=10.0.0.10,rw",
+                "device": ":/exports/data",
+            },
+            "Labels": { "com.example.test": "1" },
+        })
+    );
+    let json = serde_json::to_value(CreateVolumeOptions::default()).unwrap();
+    assert_eq!(json, serde_json::json!({}));
+}
+
+#[test]
+#[cfg(test)]
+fn decode_load_progress() {
+    use futures::StreamExt;
+    use hyper::Body;
+
+    let body = Body::from(
+        [
+            r#"{"status":"Loading layer","progressDetail":{"current":32768,"total":5851648},"progress":"[>    ]  32.77kB/5.852MB","id":"50644c29ef5a"}"#,
+            r#"{"stream":"Loaded image: alpine:latest\n"}"#,
+            r#"{"stream":"Loaded image ID: sha256:a24bb4013296\n"}"#,
+            r#"{"errorDetail":{"message":"unexpected EOF"},"error":"unexpected EOF"}"#,
+        ]
+        .join("\r\n"),
+    );
+    let messages: Vec<Result<ProgressEvent>> =
+        futures::executor::block_on(decode_progress(body).collect());
+    assert_eq!(messages.len(), 4);
+    assert_eq!(messages[0].as_ref().unwrap().loaded_image(), None);
+    assert_eq!(
+        messages[1].as_ref().unwrap().loaded_image(),
+        Some("alpine:latest")
+    );
+    assert_eq!(
+        messages[2].as_ref().unwrap().loaded_image(),
+        Some("sha256:a24bb4013296")
+    );
+    assert!(messages[3].is_err());
+}
+
+#[test]
+#[cfg(test)]
+fn parse_image_references() {
+    let digest = "sha256:185518070891758909c9f839cf4ca393ee977ac378609f700f60a771a2dfe321";
+
+    let alpine: ImageReference = "alpine".parse().unwrap();
+    assert_eq!(alpine.registry(), "docker.io");
+    assert_eq!(alpine.repository(), "alpine");
+    assert_eq!(alpine.tag(), None);
+    assert_eq!(alpine.tag_or_digest(), "latest");
+
+    let pinned = ImageReference::parse(&format!("alpine:3.12@{}", digest)).unwrap();
+    assert_eq!(pinned.tag(), Some("3.12"));
+    assert_eq!(pinned.digest(), Some(digest));
+    assert_eq!(pinned.tag_or_digest(), digest);
+    assert_eq!(pinned.to_string(), format!("alpine:3.12@{}", digest));
+
+    let private = ImageReference::parse("localhost:5000/team/app:1.0").unwrap();
+    assert_eq!(private.registry(), "localhost:5000");
+    assert_eq!(private.repository(), "team/app");
+    assert_eq!(private.name(), "localhost:5000/team/app");
+    assert_eq!(private.tag(), Some("1.0"));
+
+    let port_only = ImageReference::parse("registry.example.com:5000/app").unwrap();
+    assert_eq!(port_only.registry(), "registry.example.com:5000");
+    assert_eq!(port_only.tag(), None);
+
+    assert!(ImageReference::parse("Alpine").is_err());
+    assert!(ImageReference::parse("alpine:").is_err());
+    assert!(ImageReference::parse("alpine@sha256:abc").is_err());
+    assert!(ImageReference::parse("").is_err());
+}
+
+#[test]
+#[cfg(test)]
+fn remove_image() {
+    let params = RemoveImageOptions::default()
+        .force()
+        .no_prune()
+        .to_url_params();
+    assert_eq!(params, "force=1&noprune=1");
+
+    let response = r#"[{"Untagged":"example/app:1.0"},{"Deleted":"sha256:3e1f"}]"#;
+    let removed: Vec<RemovedImage> = serde_json::from_str(response).unwrap();
+    assert_eq!(
+        removed,
+        vec![
+            RemovedImage::Untagged("example/app:1.0".to_owned()),
+            RemovedImage::Deleted("sha256:3e1f".to_owned()),
+        ]
+    );
 }
 
 #[test]
 #[cfg(test)]
 fn get_container_info() {
     let response = get_container_info_response();
-    serde_json::from_str::<ContainerInfo>(&response).unwrap();
+    let info: ContainerInfo = serde_json::from_str(&response).unwrap();
+    let settings = &info.NetworkSettings;
+    assert_eq!(
+        settings.ip_address("railshello_default"),
+        Some("172.24.0.3")
+    );
+    assert_eq!(settings.ip_address("bridge"), None);
+    let endpoint = &settings.Networks["railshello_default"];
+    assert_eq!(endpoint.Gateway, "172.24.0.1");
+    assert_eq!(endpoint.MacAddress, "02:42:ac:18:00:03");
+    assert_eq!(endpoint.Aliases, vec!["web", "774758ca1db8"]);
+    assert!(endpoint.IPAMConfig.is_none());
+    assert!(endpoint.Links.is_empty());
 }
 
 #[test]
 #[cfg(test)]
+fn restart_policy() {
+    let info: ContainerInfo = serde_json::from_str(&get_container_info_response()).unwrap();
+    assert_eq!(info.HostConfig.RestartPolicy, Some(RestartPolicy::No));
+
+    let policy = RestartPolicy::OnFailure { max_retries: 5 };
+    let json = serde_json::to_value(policy).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({ "Name": "on-failure", "MaximumRetryCount": 5 })
+    );
+    assert_eq!(
+        serde_json::from_value::<RestartPolicy>(json).unwrap(),
+        policy
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn container_status() {
+    let status: ContainerStatus = serde_json::from_str(r#""exited""#).unwrap();
+    assert_eq!(status, ContainerStatus::Exited);
+    let status: ContainerStatus = serde_json::from_str(r#""hibernating""#).unwrap();
+    assert_eq!(status, ContainerStatus::Unknown("hibernating".to_owned()));
+    assert_eq!(serde_json::to_string(&status).unwrap(), r#""hibernating""#);
+    assert_eq!(ContainerStatus::Removing.to_string(), "removing");
+}
+
+#[test]
+#[cfg(test)]
+fn get_container_health() {
+    let response = get_container_state_with_health_response();
+    let state: State = serde_json::from_str(&response).unwrap();
+    let health = state.Health.unwrap();
+    assert_eq!(state.Status, ContainerStatus::Running);
+    assert_eq!(health.Status, HealthStatus::Unhealthy);
+    assert_eq!(health.FailingStreak, 3);
+    assert_eq!(health.Log.unwrap()[0].ExitCode, 1);
+}
+
+#[test]
+#[cfg(all(test, feature = "chrono"))]
+fn parse_timestamps() {
+    use chrono::{TimeZone, Utc};
+
+    let info: ContainerInfo = serde_json::from_str(&get_container_info_response()).unwrap();
+    assert_eq!(
+        info.Created,
+        Utc.with_ymd_and_hms(2016, 10, 25, 11, 59, 37).unwrap()
+            + chrono::Duration::nanoseconds(858_589_354)
+    );
+    assert_eq!(
+        info.State.StartedAt,
+        Some(
+            Utc.with_ymd_and_hms(2016, 10, 25, 11, 59, 38).unwrap()
+                + chrono::Duration::nanoseconds(261_828_009)
+        )
+    );
+    assert_eq!(info.State.FinishedAt, None);
+    let state = serde_json::to_value(&info.State).unwrap();
+    assert_eq!(state["StartedAt"], "2016-10-25T11:59:38.261828009Z");
+    assert_eq!(state["FinishedAt"], "0001-01-01T00:00:00Z");
+
+    let images: Vec<Image> = serde_json::from_str(&get_images_response()).unwrap();
+    assert_eq!(images[0].Created, Utc.timestamp_opt(1428533761, 0).unwrap());
+    let image = serde_json::to_value(&images[0]).unwrap();
+    assert_eq!(image["Created"], 1428533761);
+}
+
+#[test]
+#[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 fn get_processes() {
     let response = get_processes_response();
     let _: Top = match serde_json::from_str(&response) {
@@ -119,100 +952,1337 @@ fn get_processes() {
 
 #[test]
 #[cfg(test)]
+fn parse_process_fields() {
+    let titles: Vec<String> = [
+        "USER", "PID", "%CPU", "%MEM", "VSZ", "RSS", "TIME", "COMMAND",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    let values: Vec<String> = [
+        "root", "4586", "1.5", "0.2", "4324", "812", "01:02:03", "sh",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
+    let p = Process::from_row(&titles, &values);
+    assert_eq!(p.pid, Some(4586));
+    assert_eq!(p.cpu, Some(1.5));
+    assert_eq!(p.vsz, Some(4324 * 1024));
+    assert_eq!(p.time, Some(Duration::from_secs(3723)));
+    assert_eq!(p.raw_value("RSS"), Some("812"));
+    assert_eq!(p.to_string(), "root,4586,1.5,0.2,4324,812,01:02:03,sh");
+
+    // Columns we don't know about aren't included.
+    let titles: Vec<String> = ["UID", "PID", "PPID", "C", "STIME", "TTY", "TIME", "CMD"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let values: Vec<String> = ["root", "1", "0", "0", "10:00", "?", "00:00:00", "sh"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let p = Process::from_row(&titles, &values);
+    assert_eq!(p.to_string(), "root,1,?,10:00,00:00:00,sh");
+
+    assert_eq!(
+        parse_ps_duration("2-00:00:01"),
+        Some(Duration::from_secs(2 * 86400 + 1))
+    );
+    assert_eq!(parse_ps_duration("05:07"), Some(Duration::from_secs(307)));
+    assert_eq!(parse_ps_duration("?"), None);
+}
+
+#[test]
+#[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 fn get_filesystem_changes() {
     let response = get_filesystem_changes_response();
-    let _: Vec<FilesystemChange> = match serde_json::from_str(&response) {
+    let changes: Vec<FilesystemChange> = match serde_json::from_str(&response) {
         Ok(body) => body,
         Err(_) => {
             assert!(false);
             return;
         }
     };
+    let kinds = changes.iter().map(|c| c.Kind).collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![
+            ChangeKind::Modified,
+            ChangeKind::Added,
+            ChangeKind::Deleted,
+            ChangeKind::Unknown(7),
+        ]
+    );
 }
 
 #[test]
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 fn get_version() {
     let response = get_version_response();
-    let _: Version = match serde_json::from_str(&response) {
+    let version: Version = match serde_json::from_str(&response) {
         Ok(body) => body,
         Err(_) => {
             assert!(false);
             return;
         }
     };
+    assert_eq!(version.api_version(), Some((1, 20)));
+    assert!(version.Components.is_empty());
+
+    let response = get_modern_version_response();
+    let version: Version = serde_json::from_str(&response).unwrap();
+    assert_eq!(version.api_version(), Some((1, 43)));
+    assert!(version.supports_api(1, 41));
+    assert!(!version.supports_api(1, 44));
+    assert!(!version.supports_api(2, 0));
+    assert_eq!(version.MinAPIVersion.as_deref(), Some("1.12"));
+    assert_eq!(
+        version.Platform.as_ref().unwrap().Name,
+        "Docker Engine - Community"
+    );
+    let runc = version.component("runc").unwrap();
+    assert_eq!(runc.Version, "1.1.7");
+    assert_eq!(runc.Details["GitCommit"], "v1.1.7-0-g860f061");
+    assert!(version.component("podman").is_none());
 }
 
+#[test]
 #[cfg(test)]
+fn create_container() {
+    let opts = ContainerCreateOptions::new("alpine:latest")
+        .cmd(&["echo", "hello"])
+        .env("FOO", "bar")
+        .label("com.example.role", "test")
+        .expose("80/tcp")
+        .bind("/tmp:/data:ro");
+    let json = serde_json::to_value(&opts).unwrap();
+    assert_eq!(json["Image"], "alpine:latest");
+    assert_eq!(json["Cmd"], serde_json::json!(["echo", "hello"]));
+    assert_eq!(json["Env"], serde_json::json!(["FOO=bar"]));
+    assert_eq!(json["Labels"]["com.example.role"], "test");
+    assert_eq!(json["ExposedPorts"], serde_json::json!({ "80/tcp": {} }));
+    assert_eq!(
+        json["HostConfig"]["Binds"],
+        serde_json::json!(["/tmp:/data:ro"])
+    );
+
+    let opts = ContainerCreateOptions::new("nginx")
+        .publish(8080, 80, Protocol::Tcp)
+        .publish_on("127.0.0.1", 0, 53, Protocol::Udp);
+    let json = serde_json::to_value(&opts).unwrap();
+    assert_eq!(
+        json["ExposedPorts"],
+        serde_json::json!({ "80/tcp": {}, "53/udp": {} })
+    );
+    assert_eq!(
+        json["HostConfig"]["PortBindings"],
+        serde_json::json!({
+            "80/tcp": [{ "HostIp": "", "HostPort": "8080" }],
+            "53/udp": [{ "HostIp": "127.0.0.1", "HostPort": "" }],
+        })
+    );
+
+    let opts = ContainerCreateOptions::new("alpine:latest")
+        .name("test-runner")
+        .platform("linux/arm64");
+    assert_eq!(
+        opts.to_url_params(),
+        "name=test-runner&platform=linux%2Farm64"
+    );
+    let json = serde_json::to_value(&opts).unwrap();
+    assert!(json.get("Name").is_none());
+
+    let opts = ContainerCreateOptions::new("alpine:latest").auto_remove();
+    let json = serde_json::to_value(&opts).unwrap();
+    assert_eq!(json["HostConfig"]["AutoRemove"], true);
+
+    let opts = ContainerCreateOptions::new("alpine:latest")
+        .device("/dev/ttyUSB0", "/dev/ttyUSB0", "rwm")
+        .cap_add("SYS_RAWIO")
+        .cap_drop("MKNOD")
+        .security_opt("no-new-privileges")
+        .cgroup_parent("/hil")
+        .shm_size(256 * 1024 * 1024);
+    let json = serde_json::to_value(&opts).unwrap();
+    let host_config = &json["HostConfig"];
+    assert_eq!(
+        host_config["Devices"],
+        serde_json::json!([{
+            "PathOnHost": "/dev/ttyUSB0",
+            "PathInContainer": "/dev/ttyUSB0",
+            "CgroupPermissions": "rwm",
+        }])
+    );
+    assert_eq!(host_config["CapAdd"], serde_json::json!(["SYS_RAWIO"]));
+    assert_eq!(host_config["CapDrop"], serde_json::json!(["MKNOD"]));
+    assert_eq!(host_config["Privileged"], false);
+    assert_eq!(
+        host_config["SecurityOpt"],
+        serde_json::json!(["no-new-privileges"])
+    );
+    assert_eq!(host_config["CgroupParent"], "/hil");
+    assert_eq!(host_config["ShmSize"], 268_435_456);
+
+    let opts = ContainerCreateOptions::new("nvidia/cuda")
+        .device_request(DeviceRequest::gpus(-1))
+        .device_request(DeviceRequest {
+            Driver: "nvidia".to_owned(),
+            ..DeviceRequest::gpu_ids(&["0", "1"])
+        });
+    let json = serde_json::to_value(&opts).unwrap();
+    assert_eq!(
+        json["HostConfig"]["DeviceRequests"],
+        serde_json::json!([
+            {
+                "Driver": "",
+                "Count": -1,
+                "DeviceIDs": [],
+                "Capabilities": [["gpu"]],
+                "Options": {},
+            },
+            {
+                "Driver": "nvidia",
+                "Count": 0,
+                "DeviceIDs": ["0", "1"],
+                "Capabilities": [["gpu"]],
+                "Options": {},
+            },
+        ])
+    );
+    let parsed: DeviceRequest = serde_json::from_str(
+        r#"{"Driver":"","Count":-1,"DeviceIDs":null,"Capabilities":[["gpu"]],"Options":null}"#,
+    )
+    .unwrap();
+    assert_eq!(parsed, DeviceRequest::gpus(-1));
+
+    let opts = ContainerCreateOptions::new("postgres")
+        .mount(MountSpec::Bind {
+            source: "/srv/config".to_owned(),
+            target: "/etc/postgresql".to_owned(),
+            read_only: true,
+            propagation: Some(BindPropagation::RSlave),
+        })
+        .mount(MountSpec::Volume {
+            source: Some("pgdata".to_owned()),
+            target: "/var/lib/postgresql/data".to_owned(),
+            read_only: false,
+            driver: None,
+            driver_opts: vec![("type".to_owned(), "tmpfs".to_owned())]
+                .into_iter()
+                .collect(),
+        })
+        .mount(MountSpec::Tmpfs {
+            target: "/tmp".to_owned(),
+            size: Some(64 * 1024 * 1024),
+            mode: Some(0o1777),
+        });
+    let json = serde_json::to_value(&opts).unwrap();
+    assert_eq!(
+        json["HostConfig"]["Mounts"],
+        serde_json::json!([
+            {
+                "Type": "bind",
+                "Source": "/srv/config",
+                "Target": "/etc/postgresql",
+                "ReadOnly": true,
+                "BindOptions": { "Propagation": "rslave" },
+            },
+            {
+                "Type": "volume",
+                "Source": "pgdata",
+                "Target": "/var/lib/postgresql/data",
+                "ReadOnly": false,
+                "VolumeOptions": {
+                    "DriverConfig": { "Name": "local", "Options": { "type": "tmpfs" } },
+                },
+            },
+            {
+                "Type": "tmpfs",
+                "Target": "/tmp",
+                "ReadOnly": false,
+                "TmpfsOptions": { "SizeBytes": 67108864, "Mode": 1023 },
+            },
+        ])
+    );
+
+    let response = get_create_container_response();
+    let created: CreateContainerResponse = serde_json::from_str(&response).unwrap();
+    assert_eq!(created.Id, "e90e34656806");
+}
+
+#[test]
+#[cfg(test)]
+fn wait_container() {
+    let response = get_wait_container_response();
+    let status: ContainerWaitResponse = serde_json::from_str(&response).unwrap();
+    assert_eq!(status.StatusCode, 137);
+    assert!(status.Error.is_none());
+}
+
+#[test]
+#[cfg(test)]
+fn container_list_filters() {
+    let opts = ContainerListOptions::default()
+        .status(ContainerStatus::Running)
+        .health(HealthStatus::Unhealthy)
+        .label("com.example.app")
+        .exited(0);
+    let params = opts.to_url_params();
+    let params: Vec<_> = url::form_urlencoded::parse(params.as_bytes()).collect();
+    assert_eq!(params[0], ("all".into(), "1".into()));
+    assert_eq!(params[1].0, "filters");
+    assert_eq!(
+        params[1].1,
+        r#"{"exited":["0"],"health":["unhealthy"],"label":["com.example.app"],"status":["running"]}"#
+    );
+
+    let opts = ContainerListOptions::default().health(HealthStatus::Unknown);
+    assert_eq!(opts.to_url_params(), "");
+}
+
+#[test]
+#[cfg(test)]
+fn prune_containers_filters() {
+    let opts = PruneContainersOptions::default()
+        .until("24h")
+        .label("env=test")
+        .label("temporary");
+    let params = opts.to_url_params();
+    let filters: Vec<_> = url::form_urlencoded::parse(params.as_bytes()).collect();
+    assert_eq!(filters.len(), 1);
+    assert_eq!(filters[0].0, "filters");
+    assert_eq!(
+        filters[0].1,
+        r#"{"label":["env=test","temporary"],"until":["24h"]}"#
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn prune_networks() {
+    let params = PruneNetworksOptions::default()
+        .label_not("keep")
+        .to_url_params();
+    let filters: Vec<_> = url::form_urlencoded::parse(params.as_bytes()).collect();
+    assert_eq!(filters[0].1, r#"{"label!":["keep"]}"#);
+
+    let response: PruneNetworksResponse =
+        serde_json::from_str(r#"{"NetworksDeleted":["isolated"]}"#).unwrap();
+    assert_eq!(response.NetworksDeleted, vec!["isolated".to_owned()]);
+    let response: PruneNetworksResponse =
+        serde_json::from_str(r#"{"NetworksDeleted":null}"#).unwrap();
+    assert!(response.NetworksDeleted.is_empty());
+}
+
+#[test]
+#[cfg(test)]
+fn prune_volumes() {
+    let params = PruneVolumesOptions::default()
+        .all()
+        .label("env=test")
+        .to_url_params();
+    let filters: Vec<_> = url::form_urlencoded::parse(params.as_bytes()).collect();
+    assert_eq!(filters[0].1, r#"{"all":["true"],"label":["env=test"]}"#);
+
+    let response: PruneVolumesResponse =
+        serde_json::from_str(r#"{"VolumesDeleted":["tardis"],"SpaceReclaimed":4096}"#).unwrap();
+    assert_eq!(response.VolumesDeleted, vec!["tardis".to_owned()]);
+    assert_eq!(response.SpaceReclaimed, 4096);
+}
+
+#[test]
+#[cfg(test)]
+fn decode_events() {
+    use futures::StreamExt;
+    use hyper::Body;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let params = EventOptions::default()
+        .since(UNIX_EPOCH + Duration::from_secs(1_600_000_000))
+        .event_type(EventType::Container)
+        .event(EventAction::Start)
+        .event(EventAction::HealthStatus(HealthStatus::Unhealthy))
+        .event_type(EventType::Unknown)
+        .to_url_params();
+    let params = url::form_urlencoded::parse(params.as_bytes())
+        .into_owned()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        params,
+        vec![
+            ("since".to_owned(), "1600000000.000000000".to_owned()),
+            (
+                "filters".to_owned(),
+                r#"{"event":["start","health_status: unhealthy"],"type":["container"]}"#.to_owned()
+            ),
+        ]
+    );
+
+    let body = Body::from(get_events_response());
+    let events: Vec<Result<EventMessage>> =
+        futures::executor::block_on(decode_json_lines("EventMessage", body).collect());
+    assert_eq!(events.len(), 2);
+    let event = events[0].as_ref().unwrap();
+    assert_eq!(event.Type, EventType::Container);
+    assert_eq!(event.Action, EventAction::Start);
+    assert_eq!(event.Actor.ID, "ede54ee1afda");
+    assert_eq!(event.attribute("name"), Some("my-container"));
+    assert_eq!(event.timeNano, 1461943101381709551);
+    assert_eq!(events[1].as_ref().unwrap().Action, EventAction::Die);
+
+    for (raw, action) in [
+        ("oom", EventAction::Oom),
+        (
+            "health_status: unhealthy",
+            EventAction::HealthStatus(HealthStatus::Unhealthy),
+        ),
+        (
+            "exec_start: sh -c true",
+            EventAction::ExecStart("sh -c true".to_owned()),
+        ),
+        (
+            "something_new",
+            EventAction::Other("something_new".to_owned()),
+        ),
+    ] {
+        assert_eq!(EventAction::from(raw.to_owned()), action);
+        assert_eq!(action.to_string(), raw);
+    }
+    let event_type: EventType = serde_json::from_str(r#""swarm-thing""#).unwrap();
+    assert_eq!(event_type, EventType::Unknown);
+
+    let body = Body::from(get_events_response());
+    let mut stream = EventStream::new(Box::pin(decode_json_lines("EventMessage", body)));
+    assert_eq!(stream.last_seen(), None);
+    futures::executor::block_on(stream.next()).unwrap().unwrap();
+    let last_seen = UNIX_EPOCH + Duration::from_nanos(1461943101381709551);
+    assert_eq!(stream.last_seen(), Some(last_seen));
+    futures::executor::block_on(stream.next()).unwrap().unwrap();
+    let last_seen = stream.last_seen().unwrap();
+    assert_eq!(
+        EventOptions::resume_from(last_seen).to_url_params(),
+        "since=1461943105.079144138"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn get_system_df() {
+    let response = get_system_df_response();
+    let usage: DiskUsage = serde_json::from_str(&response).unwrap();
+    assert_eq!(usage.LayersSize, 1092588);
+    assert_eq!(usage.Images[0].SharedSize, 0);
+    assert_eq!(usage.Images[0].Containers, 1);
+    assert_eq!(usage.Containers[0].SizeRw, Some(0));
+    assert_eq!(usage.Containers[0].SizeRootFs, Some(1092588));
+    assert_eq!(usage.volumes_size(), 2048);
+    assert_eq!(usage.BuildCache[0].Type, "regular");
+    assert_eq!(usage.build_cache_size(), 51);
+
+    let images: Vec<Image> = serde_json::from_str(&get_images_response()).unwrap();
+    assert_eq!(images[0].SharedSize, -1);
+}
+
+#[test]
+#[cfg(test)]
+fn swarm_init_and_join() {
+    let opts = SwarmInitOptions::default()
+        .advertise_addr("192.168.1.1:2377")
+        .default_addr_pool("10.20.0.0/16")
+        .subnet_size(26);
+    assert_eq!(
+        serde_json::to_value(opts).unwrap(),
+        serde_json::json!({
+            "ListenAddr": "0.0.0.0:2377",
+            "AdvertiseAddr": "192.168.1.1:2377",
+            "DefaultAddrPool": ["10.20.0.0/16"],
+            "SubnetSize": 26,
+            "ForceNewCluster": false,
+        })
+    );
+
+    let opts = SwarmJoinOptions::new("192.168.1.1:2377", "SWMTKN-1-abc")
+        .remote_addr("192.168.1.2:2377")
+        .advertise_addr("192.168.1.3");
+    assert_eq!(
+        serde_json::to_value(opts).unwrap(),
+        serde_json::json!({
+            "ListenAddr": "0.0.0.0:2377",
+            "AdvertiseAddr": "192.168.1.3",
+            "RemoteAddrs": ["192.168.1.1:2377", "192.168.1.2:2377"],
+            "JoinToken": "SWMTKN-1-abc",
+        })
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn get_swarm_info() {
+    let response = get_swarm_info_response();
+    let swarm: Swarm = serde_json::from_str(&response).unwrap();
+    assert_eq!(swarm.Version, ObjectVersion { Index: 373531 });
+    assert!(swarm.JoinTokens.Worker.starts_with("SWMTKN-1-"));
+    let raft = swarm.Spec.Raft.as_ref().unwrap();
+    assert_eq!(raft.SnapshotInterval, Some(10000));
+    let dispatcher = swarm.Spec.Dispatcher.as_ref().unwrap();
+    assert_eq!(dispatcher.heartbeat_period(), Some(Duration::from_secs(5)));
+    let ca = swarm.Spec.CAConfig.as_ref().unwrap();
+    assert_eq!(
+        ca.node_cert_expiry(),
+        Some(Duration::from_secs(90 * 24 * 60 * 60))
+    );
+    assert_eq!(ca.ExternalCAs[0].Protocol, "cfssl");
+    assert!(
+        !swarm
+            .Spec
+            .EncryptionConfig
+            .as_ref()
+            .unwrap()
+            .AutoLockManagers
+    );
+
+    // Sending the spec back shouldn't lose anything we decoded.
+    let json = serde_json::to_value(&swarm.Spec).unwrap();
+    assert_eq!(json["Raft"]["ElectionTick"], 3);
+    assert_eq!(json["Dispatcher"]["HeartbeatPeriod"], 5000000000u64);
+    assert_eq!(json["TaskDefaults"]["LogDriver"]["Name"], "json-file");
+
+    let key: UnlockKey = serde_json::from_str(
+        r#"{"UnlockKey":"SWMKEY-1-7c37Cc8654o6p38HnroywCi19pllOnGtbdZEgtKxZu8"}"#,
+    )
+    .unwrap();
+    assert!(key.UnlockKey.starts_with("SWMKEY-1-"));
+
+    let opts = SwarmUpdateOptions::default()
+        .rotate_worker_token()
+        .rotate_manager_unlock_key();
+    assert_eq!(
+        opts.to_url_params(),
+        "rotateWorkerToken=1&rotateManagerUnlockKey=1"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn list_services() {
+    let response = get_services_response();
+    let services: Vec<Service> = serde_json::from_str(&response).unwrap();
+    assert_eq!(services.len(), 1);
+    let service = &services[0];
+    assert_eq!(service.Spec.Name, "hopeful_cori");
+    assert_eq!(service.Spec.replicas(), Some(1));
+    let container = service.Spec.TaskTemplate.ContainerSpec.as_ref().unwrap();
+    assert!(container.Image.starts_with("redis:"));
+    assert_eq!(
+        service.Spec.UpdateConfig.as_ref().unwrap().FailureAction,
+        Some("pause".to_owned())
+    );
+    let endpoint = service.Endpoint.as_ref().unwrap();
+    assert_eq!(endpoint.Ports[0].PublishedPort, Some(30001));
+    assert_eq!(endpoint.VirtualIPs[0].Addr, "10.255.0.2/16");
+    assert_eq!(
+        service.UpdateStatus.as_ref().unwrap().State,
+        Some(UpdateState::RollbackCompleted)
+    );
+    assert_eq!(service.ServiceStatus.unwrap().RunningTasks, 1);
+
+    // Specs we decode must survive being sent back to Docker.
+    let json = serde_json::to_value(&service.Spec).unwrap();
+    assert_eq!(json["TaskTemplate"]["RestartPolicy"]["Condition"], "any");
+    assert_eq!(
+        json["TaskTemplate"]["ContainerSpec"]["Secrets"][0]["SecretName"],
+        "app-key"
+    );
+    assert_eq!(json["EndpointSpec"]["Ports"][0]["TargetPort"], 6379);
+
+    assert_eq!(ServiceMode::global().replicas(), None);
+    assert_eq!(
+        serde_json::to_value(ServiceMode::replicated(3)).unwrap(),
+        serde_json::json!({ "Replicated": { "Replicas": 3 } })
+    );
+
+    let opts = ServiceListOptions::default().mode("global").status();
+    assert_eq!(
+        opts.to_url_params(),
+        "filters=%7B%22mode%22%3A%5B%22global%22%5D%7D&status=1"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn create_service_spec() {
+    let mut spec = ServiceSpec::new("web", "nginx:alpine");
+    spec.Mode = Some(ServiceMode::replicated(2));
+    assert_eq!(
+        serde_json::to_value(&spec).unwrap(),
+        serde_json::json!({
+            "Name": "web",
+            "Labels": {},
+            "TaskTemplate": {
+                "ContainerSpec": {
+                    "Image": "nginx:alpine",
+                    "Labels": {},
+                    "TTY": false,
+                    "OpenStdin": false,
+                    "ReadOnly": false,
+                },
+                "ForceUpdate": 0,
+            },
+            "Mode": { "Replicated": { "Replicas": 2 } },
+        })
+    );
+
+    spec.set_replicas(5).unwrap();
+    assert_eq!(spec.replicas(), Some(5));
+    let mut spec = ServiceSpec::new("web", "nginx:alpine");
+    spec.set_replicas(3).unwrap();
+    assert_eq!(spec.replicas(), Some(3));
+    spec.Mode = Some(ServiceMode::global());
+    assert!(spec.set_replicas(3).is_err());
+
+    let opts = UpdateServiceOptions::default()
+        .registry_auth_from("previous-spec")
+        .rollback();
+    assert_eq!(
+        opts.to_url_params(),
+        "registryAuthFrom=previous-spec&rollback=previous"
+    );
+}
+
+#[cfg(test)]
+fn update_status(state: UpdateState, started_at: &str) -> UpdateStatus {
+    UpdateStatus {
+        State: Some(state),
+        StartedAt: Some(started_at.to_owned()),
+        CompletedAt: None,
+        Message: format!("update {:?}", state),
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn watch_update_ignores_stale_status() {
+    let old = update_status(UpdateState::Completed, "2016-06-07T21:05:51Z");
+    let early = Duration::from_secs(1);
+
+    // The status left over from an earlier update doesn't end the watch.
+    let mut watch = UpdateWatch::new(Some(old.clone()), false);
+    assert_eq!(watch.observe(Some(&old), early), UpdateStep::Wait);
+    assert_eq!(watch.observe(Some(&old), early), UpdateStep::Wait);
+    assert_eq!(
+        watch.observe(Some(&old), UPDATE_START_TIMEOUT),
+        UpdateStep::NoUpdate
+    );
+
+    // Once the new update starts, we follow it to completion.
+    let mut watch = UpdateWatch::new(Some(old.clone()), false);
+    let updating = update_status(UpdateState::Updating, "2016-06-08T10:00:00Z");
+    let completed = update_status(UpdateState::Completed, "2016-06-08T10:00:00Z");
+    assert_eq!(watch.observe(Some(&old), early), UpdateStep::Wait);
+    assert_eq!(
+        watch.observe(Some(&updating), early),
+        UpdateStep::Report(updating.clone())
+    );
+    assert_eq!(watch.observe(Some(&updating), early), UpdateStep::Wait);
+    assert_eq!(
+        watch.observe(Some(&completed), UPDATE_START_TIMEOUT),
+        UpdateStep::Finish(completed.clone())
+    );
+
+    // An update which finishes between polls has a new `StartedAt`.
+    let mut watch = UpdateWatch::new(Some(old.clone()), false);
+    assert_eq!(watch.observe(Some(&old), early), UpdateStep::Wait);
+    assert_eq!(
+        watch.observe(Some(&completed), early),
+        UpdateStep::Finish(completed.clone())
+    );
+
+    // So does one which finishes before our first poll.
+    let mut watch = UpdateWatch::new(Some(old), false);
+    assert_eq!(
+        watch.observe(Some(&completed), early),
+        UpdateStep::Finish(completed.clone())
+    );
+
+    // As does the first update of a new service.
+    let mut watch = UpdateWatch::new(None, false);
+    assert_eq!(
+        watch.observe(Some(&completed), early),
+        UpdateStep::Finish(completed)
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn watch_update_paused() {
+    let started_at = "2016-06-08T10:00:00Z";
+    let updating = update_status(UpdateState::Updating, started_at);
+    let paused = update_status(UpdateState::Paused, started_at);
+    let rolling_back = update_status(UpdateState::RollbackStarted, started_at);
+    let rolled_back = update_status(UpdateState::RollbackCompleted, started_at);
+    let early = Duration::from_secs(1);
+
+    let mut watch = UpdateWatch::new(None, true);
+    assert_eq!(
+        watch.observe(Some(&updating), early),
+        UpdateStep::Report(updating.clone())
+    );
+    assert_eq!(
+        watch.observe(Some(&paused), early),
+        UpdateStep::Rollback(paused.clone())
+    );
+    assert_eq!(watch.observe(Some(&paused), early), UpdateStep::Wait);
+    assert_eq!(
+        watch.observe(Some(&rolling_back), early),
+        UpdateStep::Report(rolling_back)
+    );
+    assert_eq!(
+        watch.observe(Some(&rolled_back), early),
+        UpdateStep::Finish(rolled_back)
+    );
+
+    let mut watch = UpdateWatch::new(None, false);
+    assert_eq!(
+        watch.observe(Some(&updating), early),
+        UpdateStep::Report(updating)
+    );
+    assert_eq!(
+        watch.observe(Some(&paused), early),
+        UpdateStep::Fail(paused)
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn watch_update_timeout() {
+    let mut watch = UpdateWatch::new(None, false);
+    assert!(!watch.has_polled());
+    assert_eq!(
+        watch.observe(None, Duration::from_secs(0)),
+        UpdateStep::Wait
+    );
+    assert!(watch.has_polled());
+    assert_eq!(
+        watch.observe(None, Duration::from_secs(5)),
+        UpdateStep::Wait
+    );
+    assert_eq!(
+        watch.observe(None, UPDATE_START_TIMEOUT),
+        UpdateStep::NoUpdate
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn decode_service_logs() {
+    let mut decoder = LogDecoder::default()
+        .with_timestamps(true)
+        .with_details(true);
+    decoder.extend(b"2020-10-04T12:00:00.000000000Z com.docker.swarm.node.id=n1,com.docker.swarm.service.id=s1,com.docker.swarm.task.id=t1,env=a%2Cb hello world\n");
+    let line = ServiceLogLine::from_log_line(decoder.next_line().unwrap());
+    assert_eq!(line.task_id(), Some("t1"));
+    assert_eq!(line.node_id(), Some("n1"));
+    assert_eq!(line.service_id(), Some("s1"));
+    assert_eq!(line.details["env"], "a,b");
+    assert_eq!(
+        line.line.timestamp.as_deref(),
+        Some("2020-10-04T12:00:00.000000000Z")
+    );
+    assert_eq!(line.line.bytes, b"hello world\n");
+
+    // Lines with no details start with a space.
+    let line = ServiceLogLine::from_log_line(LogLine {
+        stream: LogStream::Stderr,
+        timestamp: None,
+        bytes: b" oops".to_vec(),
+    });
+    assert!(line.details.is_empty());
+    assert_eq!(line.line.bytes, b"oops");
+}
+
+#[test]
+#[cfg(test)]
+fn list_tasks() {
+    let response = get_tasks_response();
+    let tasks: Vec<Task> = serde_json::from_str(&response).unwrap();
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks[0].Status.State, TaskState::Running);
+    assert_eq!(tasks[0].DesiredState, TaskState::Running);
+    assert_eq!(tasks[0].Slot, Some(1));
+    assert_eq!(tasks[0].NodeID, "60gvrl6tm78dmak4yl7srz94v");
+    assert_eq!(
+        tasks[0].container_id(),
+        Some("e5d62702a1b48d01c3e02ca1e0212a250801fa8d67caca0b6f35919ebc12f035")
+    );
+    assert!(!tasks[0].Status.State.is_terminal());
+    assert_eq!(tasks[1].Status.State, TaskState::Failed);
+    assert!(tasks[1].Status.State.is_terminal());
+    assert_eq!(tasks[1].DesiredState, TaskState::Shutdown);
+    assert_eq!(tasks[1].container_id(), None);
+
+    let opts = TaskListOptions::default()
+        .service("web")
+        .desired_state("running");
+    assert_eq!(
+        opts.to_url_params(),
+        "filters=%7B%22desired-state%22%3A%5B%22running%22%5D%2C%22service%22%3A%5B%22web%22%5D%7D"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn list_nodes() {
+    let response = get_nodes_response();
+    let nodes: Vec<Node> = serde_json::from_str(&response).unwrap();
+    let node = &nodes[0];
+    assert_eq!(node.Spec.Role, NodeRole::Manager);
+    assert_eq!(node.Spec.Availability, NodeAvailability::Active);
+    assert_eq!(node.Spec.Labels["zone"], "us-east-1a");
+    assert_eq!(node.Status.State, NodeState::Ready);
+    assert!(node.ManagerStatus.as_ref().unwrap().Leader);
+    let description = node.Description.as_ref().unwrap();
+    assert_eq!(description.Hostname, "bf3067039e47");
+    assert_eq!(description.Platform.as_ref().unwrap().OS, "linux");
+    let engine = description.Engine.as_ref().unwrap();
+    assert_eq!(engine.EngineVersion, "24.0.6");
+    assert_eq!(engine.Plugins[0].Type, "Log");
+
+    assert_eq!(
+        serde_json::to_value(&node.Spec).unwrap(),
+        serde_json::json!({
+            "Name": "node-name",
+            "Labels": { "zone": "us-east-1a" },
+            "Role": "manager",
+            "Availability": "active",
+        })
+    );
+
+    let opts = NodeListOptions::default().role("worker");
+    assert_eq!(
+        opts.to_url_params(),
+        "filters=%7B%22role%22%3A%5B%22worker%22%5D%7D"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn secrets() {
+    let spec = SecretSpec::new("app-key", b"hunter2").label("env", "prod");
+    assert_eq!(
+        serde_json::to_value(&spec).unwrap(),
+        serde_json::json!({
+            "Name": "app-key",
+            "Labels": { "env": "prod" },
+            "Data": "aHVudGVyMg==",
+        })
+    );
+    assert_eq!(spec.data().unwrap(), Some(b"hunter2".to_vec()));
+    assert!(!format!("{:?}", spec).contains("aHVudGVyMg"));
+
+    let secret: Secret = serde_json::from_str(
+        r#"{"ID":"blt1owaxmitz71s9v5zh81zun","Version":{"Index":85},"CreatedAt":"2017-07-20T13:55:28.678958722Z","UpdatedAt":"2017-07-20T13:55:28.678958722Z","Spec":{"Name":"mysql-passwd","Labels":{"some.label":"some.value"},"Driver":{"Name":"secret-bucket","Options":{"OptionA":"value for driver option A"}}}}"#,
+    )
+    .unwrap();
+    assert_eq!(secret.Spec.Name, "mysql-passwd");
+    assert_eq!(secret.Spec.data().unwrap(), None);
+    assert_eq!(secret.Spec.Driver.unwrap().Name, "secret-bucket");
+
+    let opts = SecretListOptions::default().names("app-key");
+    assert_eq!(
+        opts.to_url_params(),
+        "filters=%7B%22names%22%3A%5B%22app-key%22%5D%7D"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn ping_info_headers() {
+    use hyper::http::{HeaderMap, HeaderValue};
+
+    let mut headers = HeaderMap::new();
+    headers.insert("Api-Version", HeaderValue::from_static("1.43"));
+    headers.insert("Builder-Version", HeaderValue::from_static("2"));
+    headers.insert("Swarm", HeaderValue::from_static("active/manager"));
+    headers.insert("OSType", HeaderValue::from_static("linux"));
+    headers.insert("Docker-Experimental", HeaderValue::from_static("false"));
+    let info = PingInfo::from_headers(&headers, Duration::from_millis(3));
+    assert_eq!(info.api_version.as_deref(), Some("1.43"));
+    assert_eq!(info.os_type.as_deref(), Some("linux"));
+    assert!(info.uses_buildkit());
+    assert!(info.is_swarm_manager());
+    assert!(!info.experimental);
+    assert_eq!(info.latency, Duration::from_millis(3));
+
+    let info = PingInfo::from_headers(&HeaderMap::new(), Duration::from_millis(3));
+    assert_eq!(info.api_version, None);
+    assert!(!info.uses_buildkit());
+    assert!(!info.is_swarm_manager());
+}
+
+#[test]
+#[cfg(test)]
+fn attach_detach_keys() {
+    let config: CliConfig =
+        serde_json::from_str(r#"{"auths":{},"detachKeys":"ctrl-x,x"}"#).unwrap();
+    assert_eq!(config.detach_keys.as_deref(), Some("ctrl-x,x"));
+
+    let opts = AttachOptions::default().stdin(true).detach_keys("ctrl-x,x");
+    assert_eq!(
+        opts.to_url_params(),
+        "stream=1&stdin=1&stdout=1&stderr=1&detachKeys=ctrl-x%2Cx"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn signal_names() {
+    assert_eq!(Signal::Term.to_string(), "SIGTERM");
+    assert_eq!(Signal::Hup.to_string(), "SIGHUP");
+    assert_eq!(Signal::Number(9).to_string(), "9");
+}
+
+#[test]
+#[cfg(test)]
+fn decode_multiplexed_logs() {
+    let mut data = vec![1, 0, 0, 0, 0, 0, 0, 6];
+    data.extend(b"hello\n");
+    data.extend(&[2, 0, 0, 0, 0, 0, 0, 4]);
+    data.extend(b"oops");
+
+    // Feed the data in awkward pieces to make sure we buffer correctly.
+    let mut decoder = LogDecoder::default();
+    let mut lines = vec![];
+    for chunk in data.chunks(5) {
+        decoder.extend(chunk);
+        while let Some(line) = decoder.next_line() {
+            lines.push(line);
+        }
+    }
+    assert!(decoder.finish().is_none());
+    assert_eq!(
+        lines,
+        vec![
+            LogLine {
+                stream: LogStream::Stdout,
+                timestamp: None,
+                bytes: b"hello\n".to_vec(),
+            },
+            LogLine {
+                stream: LogStream::Stderr,
+                timestamp: None,
+                bytes: b"oops".to_vec(),
+            },
+        ]
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn decode_raw_tty_logs() {
+    let mut decoder = LogDecoder::default();
+    decoder.extend(b"plain tty output\n");
+    let line = decoder.next_line().unwrap();
+    assert_eq!(line.stream, LogStream::Stdout);
+    assert_eq!(line.bytes, b"plain tty output\n".to_vec());
+    assert!(decoder.finish().is_none());
+
+    // Short output which can't be the start of a header is returned
+    // straight away, instead of waiting for a whole header's worth.
+    let mut decoder = LogDecoder::default();
+    decoder.extend(b"ok\n");
+    assert_eq!(decoder.next_line().unwrap().bytes, b"ok\n".to_vec());
+
+    // Output which might be a header is held until we can tell.
+    let mut decoder = LogDecoder::default();
+    decoder.extend(&[1, 0]);
+    assert!(decoder.next_line().is_none());
+    decoder.extend(&[0, 0, 0, 0, 0, 1, b'x']);
+    assert_eq!(decoder.next_line().unwrap().bytes, b"x".to_vec());
+
+    // Older daemons say `raw-stream` for multiplexed streams too, so we
+    // still look for headers.
+    let mut decoder = LogDecoder::for_content_type(Some("application/vnd.docker.raw-stream"));
+    decoder.extend(&[1, 0, 0, 0, 0, 0, 0, 1, b'x']);
+    let line = decoder.next_line().unwrap();
+    assert_eq!(line.stream, LogStream::Stdout);
+    assert_eq!(line.bytes, b"x".to_vec());
+}
+
+#[test]
+#[cfg(test)]
+fn attach_returns_short_tty_output() {
+    use futures::{FutureExt, StreamExt};
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    /// A connection which sends a shell prompt and then waits forever.
+    struct Prompt(Option<&'static [u8]>);
+
+    impl tokio::io::AsyncRead for Prompt {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<std::io::Result<usize>> {
+            match self.0.take() {
+                Some(data) => {
+                    buf[..data.len()].copy_from_slice(data);
+                    Poll::Ready(Ok(data.len()))
+                }
+                None => Poll::Pending,
+            }
+        }
+    }
+
+    for content_type in &[Some("application/vnd.docker.raw-stream"), None] {
+        let decoder = LogDecoder::for_content_type(*content_type);
+        let mut lines = decode_attach_stream(decoder, Prompt(Some(b"$ ")));
+        let line = lines.next().now_or_never().unwrap().unwrap().unwrap();
+        assert_eq!(line.bytes, b"$ ".to_vec());
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn decode_logs_with_timestamps() {
+    let mut data = vec![1, 0, 0, 0, 0, 0, 0, 37];
+    data.extend(b"2020-04-09T15:36:26.144000000Z hello\n");
+    let mut decoder = LogDecoder::default().with_timestamps(true);
+    decoder.extend(&data);
+    let line = decoder.next_line().unwrap();
+    assert_eq!(
+        line.timestamp.as_deref(),
+        Some("2020-04-09T15:36:26.144000000Z")
+    );
+    assert_eq!(line.bytes, b"hello\n".to_vec());
+
+    // TTY output has no framing, so we need to split it into lines.
+    let mut decoder = LogDecoder::default().with_timestamps(true);
+    decoder.extend(b"2020-04-09T15:36:26Z one\n2020-04-09T15:36:27Z tw");
+    let line = decoder.next_line().unwrap();
+    assert_eq!(line.timestamp.as_deref(), Some("2020-04-09T15:36:26Z"));
+    assert_eq!(line.bytes, b"one\n".to_vec());
+    assert!(decoder.next_line().is_none());
+    decoder.extend(b"o");
+    let line = decoder.finish().unwrap();
+    assert_eq!(line.timestamp.as_deref(), Some("2020-04-09T15:36:27Z"));
+    assert_eq!(line.bytes, b"two".to_vec());
+
+    let since = std::time::UNIX_EPOCH + Duration::new(1_586_446_586, 5);
+    let opts = LogOptions::default().since(since).tail(10).timestamps();
+    assert_eq!(
+        opts.to_url_params(),
+        "stdout=1&stderr=1&since=1586446586.000000005&tail=10&timestamps=1"
+    );
+}
+
+#[test]
+#[cfg(test)]
+fn stream_logs() {
+    use futures::TryStreamExt;
+    use hyper::{Body, Response};
+
+    let mut data = vec![1, 0, 0, 0, 0, 0, 0, 3];
+    data.extend(b"one");
+    data.extend(&[1, 0, 0, 0, 0, 0, 0, 3]);
+    data.extend(b"two");
+    let response = Response::new(Body::from(data));
+    let lines: Vec<LogLine> =
+        futures::executor::block_on(decode_log_stream(response, false).try_collect()).unwrap();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[1].bytes, b"two".to_vec());
+}
+
+#[test]
+#[cfg(test)]
+fn decode_archive_path_stat() {
+    use hyper::{Body, Response};
+
+    let json =
+        r#"{"name":"hosts","size":174,"mode":420,"mtime":"2019-01-01T00:00:00Z","linkTarget":""}"#;
+    let response = Response::builder()
+        .header("X-Docker-Container-Path-Stat", base64::encode(json))
+        .body(Body::empty())
+        .unwrap();
+    let stat = decode_path_stat(&response).unwrap();
+    assert_eq!(stat.name, "hosts");
+    assert_eq!(stat.size, 174);
+    assert_eq!(stat.mode, 0o644);
+}
+
+#[test]
+#[cfg(test)]
+fn copy_archive_round_trip() {
+    assert_eq!(
+        split_container_path("/etc/app.conf").unwrap(),
+        ("/etc", "app.conf")
+    );
+    assert_eq!(split_container_path("/data/").unwrap(), ("/", "data"));
+    assert!(split_container_path("/").is_err());
+
+    let root = std::env::temp_dir().join(format!("boondock-archive-{}", std::process::id()));
+    let src = root.join("src");
+    std::fs::create_dir_all(src.join("nested")).unwrap();
+    std::fs::write(src.join("nested/app.conf"), b"port = 80\n").unwrap();
+
+    let tar = tar_path(&src, "config").unwrap();
+    let dest = root.join("dest");
+    untar_to(&tar, &dest).unwrap();
+    assert_eq!(
+        std::fs::read(dest.join("nested/app.conf")).unwrap(),
+        b"port = 80\n"
+    );
+
+    let mut evil = tar::Builder::new(Vec::new());
+    let mut header = tar::Header::new_gnu();
+    // `set_path` refuses `..`, so write the name directly.
+    let name = b"config/../../escaped";
+    header.as_gnu_mut().unwrap().name[..name.len()].copy_from_slice(name);
+    header.set_size(0);
+    header.set_cksum();
+    evil.append(&header, &[][..]).unwrap();
+    assert!(untar_to(&evil.into_inner().unwrap(), &root.join("evil")).is_err());
+
+    // A symlink in the archive can't be used to write outside `local`.
+    let outside = root.join("outside");
+    std::fs::create_dir_all(&outside).unwrap();
+    let mut evil = tar::Builder::new(Vec::new());
+    let mut header = tar::Header::new_gnu();
+    header.set_entry_type(tar::EntryType::Symlink);
+    header.set_size(0);
+    evil.append_link(&mut header, "config/link", &outside)
+        .unwrap();
+    let mut header = tar::Header::new_gnu();
+    header.set_size(4);
+    header.set_mode(0o644);
+    evil.append_data(&mut header, "config/link/passwd", &b"root"[..])
+        .unwrap();
+    assert!(untar_to(&evil.into_inner().unwrap(), &root.join("evil")).is_err());
+    assert!(!outside.join("passwd").exists());
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+#[cfg(test)]
+fn dockerignore_patterns() {
+    let ignore = DockerIgnore::parse(
+        "# comment\n\n/target\n*.log\n!important.log\n**/node_modules\ndocs/*.m[a-d]\n",
+    );
+    assert!(ignore.is_excluded("target"));
+    assert!(ignore.is_excluded("target/debug/app"));
+    assert!(ignore.is_excluded("build.log"));
+    assert!(!ignore.is_excluded("important.log"));
+    assert!(!ignore.is_excluded("logs/build.log"));
+    assert!(ignore.is_excluded("node_modules"));
+    assert!(ignore.is_excluded("web/app/node_modules/left-pad/index.js"));
+    assert!(ignore.is_excluded("docs/readme.md"));
+    assert!(!ignore.is_excluded("docs/readme.mx"));
+    assert!(!ignore.is_excluded("src/main.rs"));
+    assert!(ignore.has_exceptions());
+}
+
+#[test]
+#[cfg(test)]
+fn build_context_honors_dockerignore() {
+    let root = std::env::temp_dir().join(format!("boondock-context-{}", std::process::id()));
+    std::fs::create_dir_all(root.join("src")).unwrap();
+    std::fs::create_dir_all(root.join("target/debug")).unwrap();
+    std::fs::write(root.join("Dockerfile"), "FROM scratch\n").unwrap();
+    std::fs::write(
+        root.join(".dockerignore"),
+        "target\nDockerfile\n.dockerignore\n",
+    )
+    .unwrap();
+    std::fs::write(root.join("src/main.rs"), "fn main() {}\n").unwrap();
+    std::fs::write(root.join("target/debug/app"), "binary").unwrap();
+
+    let mut tar = vec![];
+    tar_build_context(&root, "./Dockerfile", &mut tar).unwrap();
+    let mut archive = tar::Archive::new(&tar[..]);
+    let names = archive
+        .entries()
+        .unwrap()
+        .map(|e| e.unwrap().path().unwrap().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec![".dockerignore", "Dockerfile", "src", "src/main.rs"]
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_return)]
 fn get_containers_response() -> String {
     return "[{\"Id\":\"ed3221f4adc05b9ecfbf56b1aa76d4e6e70d5b73b3876c322fc10d017c64ca86\",\"Names\":[\"/rust\"],\"Image\":\"ghmlee/rust:latest\",\"Command\":\"bash\",\"Created\":1439434052,\"Ports\":[{\"IP\":\"0.0.0.0\",\"PrivatePort\":8888,\"PublicPort\":8888,\"Type\":\"tcp\"}],\"SizeRootFs\":253602755,\"Labels\":{},\"Status\":\"Exited (137) 12 hours ago\",\"HostConfig\":{\"NetworkMode\":\"default\"},\"SizeRw\":10832473}]".to_string();
 }
 
 #[cfg(test)]
+fn get_containers_response_with_networks_and_mounts() -> String {
+    r#"[{"Id":"8dfafdbc3a40","Names":["/boring_feynman"],"Image":"nginx:latest","ImageID":"sha256:d1a364dc548d5357f0da3268c888e1971bbdb957ee3f028fe7194f1d61c6fdee","Command":"nginx -g 'daemon off;'","Created":1367854155,"State":"running","Status":"Up 2 hours","Ports":[{"IP":"0.0.0.0","PrivatePort":80,"PublicPort":8080,"Type":"tcp"}],"Labels":{"com.example.vendor":"Acme"},"SizeRw":12288,"SizeRootFs":0,"HostConfig":{"NetworkMode":"default"},"NetworkSettings":{"Networks":{"bridge":{"IPAMConfig":null,"Links":null,"Aliases":null,"NetworkID":"7ea29fc1412292a2d7bba362f9253545fecdfa8ce9a6e37dd10ba8bee7129812","EndpointID":"2cdc4edb1ded3631c81f57966563e5c8525b81121bb3706a9a9a3ae102711f3f","Gateway":"172.17.0.1","IPAddress":"172.17.0.2","IPPrefixLen":16,"IPv6Gateway":"","GlobalIPv6Address":"","GlobalIPv6PrefixLen":0,"MacAddress":"02:42:ac:11:00:02"}}},"Mounts":[{"Type":"volume","Name":"data","Source":"/var/lib/docker/volumes/data/_data","Destination":"/data","Driver":"local","Mode":"","RW":true,"Propagation":""}]}]"#.to_string()
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_return)]
 #[allow(dead_code)]
 fn get_containers_response_long() -> String {
     return "[{\"Id\":\"2931241fd8d910316faaff849d906b0decfcd1ec123fff5153bcbae9f73a112e\",\"Names\":[\"/abdcdev_abdcctl_1\"],\"Image\":\"abcdefgh/abcdef-abcd-abdcctl:abcdefgh-v0.1.0\",\"ImageID\":\"96e385a9d743afd5b704a794f92560cce88aee8ab464018b1a84caf7b5d9d22b\",\"Command\":\"/w/w npm start\",\"Created\":1449220146,\"Ports\":[{\"PrivatePort\":3000,\"Type\":\"tcp\"},{\"IP\":\"127.0.0.1\",\"PrivatePort\":3100,\"PublicPort\":3100,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"dbb2feac5036db2f484344e8732207404b4d0ae22540cf43f4344fc17db71d90\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"abdcctl\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"2\"},\"Status\":\"Up 2 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"8344036afa76f538144939b1b5c21535c0c297965c9edb1c50ded6c9de0d4e51\",\"Names\":[\"/abdcdev_abdcopsabcd_1\"],\"Image\":\"abcdefgh/abcdef-abcd-ops:abcdefgh-v0.1.0\",\"ImageID\":\"bfd4a651b7bb5bcdebe77fc68e2723194d40ad2beb9c3e33e4649f1b411fce9b\",\"Command\":\"/w/w npm start\",\"Created\":1449220138,\"Ports\":[{\"PrivatePort\":3000,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"210b124c721f2d3da1d0ca81217b2044e4185852a3759ff140d26067eca1a258\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"abdcopsabcd\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"2\"},\"Status\":\"Up 2 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"eb4c97482c961833c5bc0ce7e727bf68c1d6e853f777da4ef32ad1da0cd00551\",\"Names\":[\"/abdcdev_abcdefadmin_1\"],\"Image\":\"abdcdev_abcdefadmin\",\"ImageID\":\"c2d23cb88f87426007f8179af74a6964a06b69d5911c4dab0e3e5b9acaabd6af\",\"Command\":\"/w/w npm start\",\"Created\":1449220133,\"Ports\":[{\"PrivatePort\":3000,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"408798c1a8a0ba71883180c19806262f411654c530e53f7d1c5f77f769e64e2e\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"abcdefadmin\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"2\"},\"Status\":\"Up 2 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"b28afbd208625505fd68ab3814c4d14f7a1bd70db1a68d5ef8a2f0af046eed55\",\"Names\":[\"/abdcdev_abcdefctl_1\"],\"Image\":\"abcdefgh/abcdef-abcdefctl:abcdefgh-v0.1.3\",\"ImageID\":\"8dd0d7a8f2b161b423e3c66410ffb01c46cff76782e41e7b7633cced0ae696ef\",\"Command\":\"/usr/bin/abcdefctl supervise\",\"Created\":1449220030,\"Ports\":[],\"Labels\":{\"com.docker.compose.config-hash\":\"454b184fb1d39a1716f888389d4932c6a583c28f895cbc77c1b8d2b291910219\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"abcdefctl\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\"},\"Status\":\"Up 2 hours\",\"HostConfig\":{\"NetworkMode\":\"host\"}},{\"Id\":\"552b7f8975fe4306749eb9e058366e7001c34523b776c5200c366295a8e12f31\",\"Names\":[\"/weaveproxy\"],\"Image\":\"weaveworks/weaveexec:1.3.1\",\"ImageID\":\"619d88f027004f82d23c1bd2a93636cde7e9dd8d0306b63801c6c5504828c8fa\",\"Command\":\"/home/weave/weaveproxy --no-default-ipalloc --no-rewrite-hosts --without-dns -H /var/run/weave/weave.sock -H 0.0.0.0:12345 --tlsverify --tlscacert /home/weave/tls/ca.pem --tlscert /home/weave/tls/cert.pem --tlskey /home/weave/tls/key.pem\",\"Created\":1449150621,\"Ports\":[],\"Labels\":{\"works.weave.role\":\"system\"},\"Status\":\"Up 22 hours\",\"HostConfig\":{\"NetworkMode\":\"host\"}},{\"Id\":\"8dec1642c907035b1afdd765a3960134edb72bab7ec21221f1104e6c592b9d47\",\"Names\":[\"/weave\"],\"Image\":\"weaveworks/weave:1.3.1\",\"ImageID\":\"4482abc1ac8c5c36e464a8ff222b72f77c46c73295507b1d6681c59af1e0794e\",\"Command\":\"/home/weave/weaver --port 6783 --name 4e:d0:a7:51:27:54 --nickname dev --datapath weave --iface veth-weave --ipalloc-range 169.254.0.0/16 --dns-effective-listen-address 172.17.0.1 --dns-listen-address 172.17.0.1:53 --http-addr 127.0.0.1:6784 --docker-api unix:///var/run/docker.sock\",\"Created\":1449150612,\"Ports\":[],\"Labels\":{\"works.weave.role\":\"system\"},\"Status\":\"Up 22 hours\",\"HostConfig\":{\"NetworkMode\":\"host\"}},{\"Id\":\"a281d40eb5a5c549dd627297f3546a762d07e3c06585f101d426111c5ca87125\",\"Names\":[\"/abdcdev_abcdefabcd_1\"],\"Image\":\"abcdefgh/abcdef-abcd\",\"ImageID\":\"45d74b4cf11517e380fbc52196ba12dc6c0f83fe97ab1cb9ada4c92d0cadad89\",\"Command\":\"/w/w npm start\",\"Created\":1449146597,\"Ports\":[{\"IP\":\"127.0.0.1\",\"PrivatePort\":3000,\"PublicPort\":3200,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"0bfc03b9fc73d9ff97d329a55d2121225d97feb7efdb50bd32e3a00ee8fa16b6\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"abcdefabcd\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"2\"},\"Status\":\"Up 4 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"698bdfb90a2bf91fd5a5717a362110617e07348c8d841a3fcaf52c6001f6b925\",\"Names\":[\"/abdcdev_sslrproxy_1\"],\"Image\":\"abdcdev_sslrproxy\",\"ImageID\":\"383fb91a69460529844cff4a8af20c56054461f148d5b168d66e60cb32e4de1a\",\"Command\":\"/w/w nginx -c /etc/nginx/nginx.conf\",\"Created\":1449146521,\"Ports\":[{\"IP\":\"0.0.0.0\",\"PrivatePort\":443,\"PublicPort\":443,\"Type\":\"tcp\"},{\"PrivatePort\":80,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"e00af6e792687216c18e4972ff8211940fde66ac94535ff50a1a7bd1880ae61d\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"sslrproxy\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"2\"},\"Status\":\"Up 4 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"2bc7e3061892492c82054fa3be6c6e5e9f8ca9f933b217385bcfc3d55ac9bd1d\",\"Names\":[\"/abdcdev_adminabcdefauth_1\"],\"Image\":\"abcdefgh/abcdef-auth\",\"ImageID\":\"de0c2e6c590f0c50870520728b1a1b1a943ebee0f6f38eac10b26117bdc01cd1\",\"Command\":\"/w/w npm start\",\"Created\":1449146518,\"Ports\":[{\"PrivatePort\":3001,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"9990f233869e71289783868219004d1006bcb61062eeb96ec06b6591293c57b6\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"adminabcdefauth\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"2\"},\"Status\":\"Up 4 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"41b2d1ba3a3135756562c777cf02822a4f81cbd24daf535920083f73b1e7496d\",\"Names\":[\"/abdcdev_intabcdefauth_1\"],\"Image\":\"abcdefgh/abcdef-auth\",\"ImageID\":\"de0c2e6c590f0c50870520728b1a1b1a943ebee0f6f38eac10b26117bdc01cd1\",\"Command\":\"/w/w npm start\",\"Created\":1449146515,\"Ports\":[{\"PrivatePort\":3001,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"3bfd1573ed9b17830b71a7d1ca5badc28623af9366a503146c2038a17e4e3795\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"intabcdefauth\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"2\"},\"Status\":\"Up 4 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"603fcc4e5c90d8430d87cf4a5d17ed286eec71d7f118495c55bc8c2b9f503552\",\"Names\":[\"/abdcdev_ldap_1\"],\"Image\":\"abdcdev_ldap\",\"ImageID\":\"b53cb44bbfb3cdbcc0e2d6361e840d4e54bbe86af6bcfc458632a38448f5e06e\",\"Command\":\"/w/w /bin/sh -c /usr/local/bin/start_ldap.sh\",\"Created\":1449146451,\"Ports\":[{\"IP\":\"127.0.0.1\",\"PrivatePort\":389,\"PublicPort\":389,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"1737e5571ef796e1b0bd5280e719b159d893510bc93cc41b841f625954cea7e0\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"ldap\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"0\"},\"Status\":\"Up 4 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"997b74083f92e52396d0743f8c69d5665dc525194d7a3e14d67cdd9ea09c4375\",\"Names\":[\"/abdcdev_rsyslog_1\"],\"Image\":\"abcdefgh/rsyslog:elasticsearch\",\"ImageID\":\"465c2dc38907b52a4f6ddc6ba02793cd0be1fc87d59e9d68cdc37811706c9149\",\"Command\":\"/w/w /usr/local/bin/start.sh -n\",\"Created\":1449146448,\"Ports\":[{\"IP\":\"127.0.0.1\",\"PrivatePort\":514,\"PublicPort\":1514,\"Type\":\"tcp\"},{\"PrivatePort\":514,\"Type\":\"udp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"80fcc75ec55280a51b7091c25dc549d274a02789a0461b5239507b6b735b4285\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"rsyslog\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"1\"},\"Status\":\"Up 4 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"411e267eeae94f73de1b63623314f93d5f1df971420ff8076b9f85407389ef58\",\"Names\":[\"/abdcdev_kibana_1\"],\"Image\":\"abcdefgh/kibana\",\"ImageID\":\"3e305ce8fe94240b42c4ac215c1e266d265e093cdbdee7154df2d8be0bd22445\",\"Command\":\"/w/w /bin/sh -c 'counter=0    while [ ! \\\\\"$(curl elasticsearch:9200 > /dev/null)\\\\\" -a $counter -lt 30  ]; do    sleep 1; ((counter++)); echo $counter;    done    ./bin/kibana'\",\"Created\":1449146425,\"Ports\":[{\"IP\":\"127.0.0.1\",\"PrivatePort\":5601,\"PublicPort\":5601,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"7b4ba8414c065d3aa206cecd8bbc114b0de6da1acabb55eec1819609fed60cbb\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"kibana\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"1\"},\"Status\":\"Up 4 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"6af6d7cfeae2745fb0aca72ca29a87196f835464244717680322d84af4df3605\",\"Names\":[\"/abdcdev_ssllogstash_1\"],\"Image\":\"abdcdev_ssllogstash\",\"ImageID\":\"8a6111f837f79cdeb81139de5621b666d68d91fe82df37456b7d1118e90608d7\",\"Command\":\"/w/w /opt/logstash/bin/logstash -f /etc/logstash/conf.d/\",\"Created\":1449146297,\"Ports\":[{\"IP\":\"0.0.0.0\",\"PrivatePort\":50000,\"PublicPort\":514,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"bbd9a7ddc4471ab02c1b716e597b9b1d07c8a278b3b3daef4e420f54f1c60e82\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"ssllogstash\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"2\"},\"Status\":\"Up 4 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"b444aa95b3f2ac55d188e3b8bcde5d262dddb9a3bd860f5ec7f502f283e61e19\",\"Names\":[\"/abdcdev_extsaltmaster_1\"],\"Image\":\"abdcdev_extsaltmaster\",\"ImageID\":\"818e61f5ab40dcbc322350fcc35ceb38c0142b3706aca74758397ac375e75ab3\",\"Command\":\"/w/w salt-master -l info\",\"Created\":1449146294,\"Ports\":[{\"IP\":\"0.0.0.0\",\"PrivatePort\":44506,\"PublicPort\":44506,\"Type\":\"tcp\"},{\"IP\":\"0.0.0.0\",\"PrivatePort\":44505,\"PublicPort\":44505,\"Type\":\"tcp\"},{\"PrivatePort\":4506,\"Type\":\"tcp\"},{\"PrivatePort\":4505,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"c164e56fef0af4c9323ec77e45f715b279e245b267b85848f48a5f6d0fb58cc8\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"extsaltmaster\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"2\"},\"Status\":\"Up 4 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"ef0bf8c1ef2b1b13cdb16f3d8bd4dc65542519e88c7a91584eedc6ca0dded456\",\"Names\":[\"/ef0bf8c1ef_abdcdev_netopsabcd_1\"],\"Image\":\"abcdefgh/abcdef-abcd-netops:abcdefgh-v0.2.0\",\"ImageID\":\"697f78b7255da1a5f422dc863023d50011d1c3256771e56f240adf3c63e4ea32\",\"Command\":\"/w/w npm start\",\"Created\":1449146288,\"Ports\":[{\"PrivatePort\":3000,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"39a290b43d5c490055274d13a1939434ac44d1f898eadbb32759f262a6e2bb8d\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"netopsabcd\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"2\"},\"Status\":\"Up 2 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"82240f370bc8770a589ffb71c9af0b1ba551c46bb81fbc6edb008405e0c84e03\",\"Names\":[\"/abdcdev_netopsjobs_1\"],\"Image\":\"abdcdev_netopsjobs\",\"ImageID\":\"1f84baea8e9a805346f755cd8cb095ad3ba13cfd00dd3a24fb55e049d46ebde5\",\"Command\":\"/w/w npm start\",\"Created\":1449146120,\"Ports\":[{\"PrivatePort\":3000,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"8c426b26c9e6de3b8af33fb5159b8b442440cfa96775bacc65394c828a0b2250\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"netopsjobs\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"2\"},\"Status\":\"Up 4 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"ad8f4a8344710568f4955f08b649ac1ee1d033a23e5395701b4f0a81fc50d05b\",\"Names\":[\"/abdcdev_rabbitmq_1\"],\"Image\":\"rabbitmq:3-management\",\"ImageID\":\"9e6ba0accabe2633011ce5d4b5f9da4531c4df5358033b63c182f83161255d89\",\"Command\":\"/w/w /docker-entrypoint.sh rabbitmq-server\",\"Created\":1449146117,\"Ports\":[{\"IP\":\"127.0.0.1\",\"PrivatePort\":5672,\"PublicPort\":5672,\"Type\":\"tcp\"},{\"PrivatePort\":5671,\"Type\":\"tcp\"},{\"PrivatePort\":4369,\"Type\":\"tcp\"},{\"PrivatePort\":25672,\"Type\":\"tcp\"},{\"IP\":\"127.0.0.1\",\"PrivatePort\":15672,\"PublicPort\":15672,\"Type\":\"tcp\"},{\"PrivatePort\":15671,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"ccc4a223ccc863d037e375dec1325e81d3b2cfaeb2bd565eb2466eb5aaddd0c4\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"rabbitmq\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"2\"},\"Status\":\"Up 4 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"78237fdec313691858e76c19507a967b14acbbd3e308907706ede436c9ff795f\",\"Names\":[\"/abdcdev_iperf3_1\"],\"Image\":\"abcdefgh/iperf3:latest\",\"ImageID\":\"78c515c71c616bf59ac082e7453a9d787e00c3f1c409e0f0cf5bd86292086f2f\",\"Command\":\"/bin/sh -c 'iperf3 -s'\",\"Created\":1449146093,\"Ports\":[{\"IP\":\"0.0.0.0\",\"PrivatePort\":5201,\"PublicPort\":5201,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"437e525f28fdc0fba960139dfa70e85ce73cfb9caaf5d5680ee72da906710c9d\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"iperf3\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"2\"},\"Status\":\"Up 4 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}},{\"Id\":\"de4ae2223b7732780c44e6395550487ece0abb4776f38c1fe5c317706bd54f32\",\"Names\":[\"/abdcdev_elasticsearch_1\"],\"Image\":\"abcdefgh/elasticsearch\",\"ImageID\":\"750277ad2cea8c5bc712abaa3962bc8eed78944cec19bda9663c65e7f0bd5d6a\",\"Command\":\"/w/w /elasticsearch/bin/elasticsearch\",\"Created\":1449146051,\"Ports\":[{\"IP\":\"127.0.0.1\",\"PrivatePort\":9300,\"PublicPort\":9300,\"Type\":\"tcp\"},{\"IP\":\"127.0.0.1\",\"PrivatePort\":9200,\"PublicPort\":9200,\"Type\":\"tcp\"}],\"Labels\":{\"com.docker.compose.config-hash\":\"60b131b015ee9d7fed7774b654838b6ad37904de9192d8be082ef101e13d9e0d\",\"com.docker.compose.container-number\":\"1\",\"com.docker.compose.oneoff\":\"False\",\"com.docker.compose.project\":\"abdcdev\",\"com.docker.compose.service\":\"elasticsearch\",\"com.docker.compose.version\":\"1.5.1\",\"za.co.abcdefgh.abcdef.projectname\":\"abcdefDev\",\"za.co.abcdefgh.abcdef.startorder\":\"0\"},\"Status\":\"Up 4 hours\",\"HostConfig\":{\"NetworkMode\":\"default\"}}]".to_string();
 }
 
 #[cfg(test)]
+fn get_modern_system_info_response() -> String {
+    r#"{"ID":"7fe80fd0-17b2-4a5c-8b0e-2e7d5fd57b3e","Containers":3,"ContainersRunning":1,"ContainersPaused":0,"ContainersStopped":2,"Images":12,"Driver":"overlay2","DriverStatus":[["Backing Filesystem","extfs"],["Supports d_type","true"]],"Plugins":{"Volume":["local"],"Network":["bridge","host","ipvlan","macvlan","null","overlay"],"Authorization":null,"Log":["awslogs","fluentd","json-file","local"]},"MemoryLimit":true,"SwapLimit":true,"CpuCfsPeriod":true,"CpuCfsQuota":true,"CPUShares":true,"CPUSet":true,"PidsLimit":true,"IPv4Forwarding":true,"BridgeNfIptables":true,"BridgeNfIp6tables":true,"Debug":false,"NFd":37,"OomKillDisable":false,"NGoroutines":52,"SystemTime":"2023-06-01T12:00:00.000000000Z","LoggingDriver":"json-file","CgroupDriver":"systemd","CgroupVersion":"2","NEventsListener":0,"KernelVersion":"6.1.0-9-amd64","OperatingSystem":"Debian GNU/Linux 12 (bookworm)","OSVersion":"12","OSType":"linux","Architecture":"x86_64","IndexServerAddress":"https://index.docker.io/v1/","RegistryConfig":{"AllowNondistributableArtifactsCIDRs":null,"AllowNondistributableArtifactsHostnames":null,"InsecureRegistryCIDRs":["127.0.0.0/8"],"IndexConfigs":{"docker.io":{"Name":"docker.io","Mirrors":[],"Secure":true,"Official":true}},"Mirrors":null},"NCPU":8,"MemTotal":33536610304,"GenericResources":null,"DockerRootDir":"/var/lib/docker","HttpProxy":"","HttpsProxy":"","NoProxy":"","Name":"build-01","Labels":[],"ExperimentalBuild":false,"ServerVersion":"24.0.2","Runtimes":{"io.containerd.runc.v2":{"path":"runc"},"runc":{"path":"runc"}},"DefaultRuntime":"runc","Swarm":{"NodeID":"qgvbxr4mm62x5vlkxz0zlsp2k","NodeAddr":"10.0.0.2","LocalNodeState":"active","ControlAvailable":true,"Error":"","RemoteManagers":[{"NodeID":"qgvbxr4mm62x5vlkxz0zlsp2k","Addr":"10.0.0.2:2377"}],"Nodes":1,"Managers":1},"LiveRestoreEnabled":false,"Isolation":"","InitBinary":"docker-init","SecurityOptions":["name=apparmor","name=seccomp,profile=builtin","name=cgroupns"],"Warnings":null}"#.to_owned()
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_return)]
 fn get_system_info_response() -> String {
     return "{\"Containers\":6,\"Debug\":0,\"DockerRootDir\":\"/var/lib/docker\",\"Driver\":\"btrfs\",\"DriverStatus\":[[\"Build Version\",\"Btrfs v3.17.1\"],[\"Library Version\",\"101\"]],\"ExecutionDriver\":\"native-0.2\",\"ID\":\"WG63:3NIU:TSI2:FV7J:IL2O:YPXA:JR3F:XEKT:JZVR:JA6T:QMYE:B4SB\",\"IPv4Forwarding\":1,\"Images\":190,\"IndexServerAddress\":\"https://index.docker.io/v1/\",\"InitPath\":\"/usr/libexec/docker/dockerinit\",\"InitSha1\":\"30c93967bdc3634b6036e1a76fd547bbe171b264\",\"KernelVersion\":\"3.18.6\",\"Labels\":null,\"MemTotal\":16854257664,\"MemoryLimit\":1,\"NCPU\":4,\"NEventsListener\":0,\"NFd\":68,\"NGoroutines\":95,\"Name\":\"core\",\"OperatingSystem\":\"CoreOS 607.0.0\",\"RegistryConfig\":{\"IndexConfigs\":{\"docker.io\":{\"Mirrors\":null,\"Name\":\"docker.io\",\"Official\":true,\"Secure\":true}},\"InsecureRegistryCIDRs\":[\"127.0.0.0/8\"]},\"SwapLimit\":1}".to_string();
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_return)]
 fn get_images_response() -> String {
     return "[{\"Created\":1428533761,\"Id\":\"533da4fa223bfbca0f56f65724bb7a4aae7a1acd6afa2309f370463eaf9c34a4\",\"ParentId\":\"84ac0b87e42afe881d36f03dea817f46893f9443f9fc10b64ec279737384df12\",\"RepoTags\":[\"ghmlee/rust:nightly\"],\"Size\":0,\"VirtualSize\":806688288},{\"Created\":1371157430,\"Id\":\"511136ea3c5a64f264b78b5433614aec563103b4d4702f3ba7d4d2698e22c158\",\"ParentId\":\"\",\"RepoTags\":[],\"Size\":0,\"VirtualSize\":0},
     {\"Created\":1371157430,\"Id\":\"511136ea3c5a64f264b78b5433614aec563103b4d4702f3ba7d4d2698e22c158\",\"ParentId\":\"\",\"RepoTags\":null,\"Size\":0,\"VirtualSize\":0}]".to_string();
 }
 
 #[cfg(test)]
+fn get_image_info_response() -> String {
+    r#"{"Id":"sha256:a24bb4013296f61e89ba57005a7b3e52274d8edd3ae2077d04395f806b63d83e","RepoTags":["alpine:latest"],"RepoDigests":["alpine@sha256:185518070891758909c9f839cf4ca393ee977ac378609f700f60a771a2dfe321"],"Parent":"","Comment":"","Created":"2020-05-29T21:19:46.363518345Z","Container":"fb4f8c1a4b0d","DockerVersion":"18.09.7","Author":"","Config":{"Hostname":"","Domainname":"","User":"","AttachStdin":false,"AttachStdout":false,"AttachStderr":false,"Tty":false,"OpenStdin":false,"StdinOnce":false,"Env":["PATH=/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin:/sbin:/bin"],"Cmd":["/bin/sh"],"ArgsEscaped":true,"Image":"sha256:3543079adc6fb5170279692361be8b24e89ef1809a374c1b4429e1d560d1459c","Volumes":null,"WorkingDir":"","Entrypoint":null,"OnBuild":null,"Labels":null},"Architecture":"amd64","Os":"linux","Size":5613158,"VirtualSize":5613158,"GraphDriver":{"Data":{"MergedDir":"/var/lib/docker/overlay2/1f5a/merged"},"Name":"overlay2"},"RootFS":{"Type":"layers","Layers":["sha256:50644c29ef5a27c9a40c393a73ece2479de78325cae7d762ef3cdc19bf42dd0a","sha256:9b9b7f3d56a01e3d9076874990c62e7a516cc4032f784f421574d06b18ef9aa4"]},"Metadata":{"LastTagTime":"0001-01-01T00:00:00Z"}}"#.to_owned()
+}
+
+#[cfg(test)]
+fn get_networks_response() -> String {
+    r#"[{"Name":"bridge","Id":"f2de39df4171b0dc801e8002d1d999b77256983dfc63041c0f34030aa3977566","Created":"2016-10-19T06:21:00.416543526Z","Scope":"local","Driver":"bridge","EnableIPv6":false,"IPAM":{"Driver":"default","Options":null,"Config":[{"Subnet":"172.17.0.0/16","Gateway":"172.17.0.1"}]},"Internal":false,"Attachable":false,"Ingress":false,"Containers":{},"Options":{"com.docker.network.bridge.default_bridge":"true","com.docker.network.bridge.enable_icc":"true","com.docker.network.bridge.enable_ip_masquerade":"true","com.docker.network.bridge.host_binding_ipv4":"0.0.0.0","com.docker.network.bridge.name":"docker0","com.docker.network.driver.mtu":"1500"},"Labels":{}},{"Name":"none","Id":"e086a3893b05ab69242d3c44e49483a3bbbd3a26b46baa8f61ab797c1088d794","Created":"0001-01-01T00:00:00Z","Scope":"local","Driver":"null","EnableIPv6":false,"IPAM":{"Driver":"default","Options":null,"Config":[]},"Internal":false,"Attachable":false,"Ingress":false,"Containers":null,"Options":{},"Labels":null}]"#.to_owned()
+}
+
+#[cfg(test)]
+fn get_network_info_response() -> String {
+    r#"{"Name":"net01","Id":"7d86d31b1478e7cca9ebed7e73aa0fdeec46c5ca29497431d3007d2d9e15ed99","Created":"2016-10-19T04:33:30.360899459Z","Scope":"local","Driver":"bridge","EnableIPv6":false,"IPAM":{"Driver":"default","Config":[{"Subnet":"172.19.0.0/16","IPRange":"172.19.0.0/24","Gateway":"172.19.0.1"}],"Options":{"foo":"bar"}},"Internal":false,"Attachable":false,"Ingress":false,"Containers":{"19a4d5d687db25203351ed79d478946f861258f018fe384f229f2efa4b23513c":{"Name":"test","EndpointID":"628cadb8bcb92de107b2a1e516cbffe463e321f548feb37697cce00ad694f21a","MacAddress":"02:42:ac:13:00:02","IPv4Address":"172.19.0.2/16","IPv6Address":""}},"Options":{"com.docker.network.bridge.default_bridge":"true"},"Labels":{"com.example.some-label":"some-value"},"Peers":[{"Name":"6869d7c1732b","IP":"10.0.0.2"}]}"#.to_owned()
+}
+
+#[cfg(test)]
+fn get_events_response() -> String {
+    [
+        r#"{"status":"start","id":"ede54ee1afda","from":"alpine","Type":"container","Action":"start","Actor":{"ID":"ede54ee1afda","Attributes":{"image":"alpine","name":"my-container"}},"scope":"local","time":1461943101,"timeNano":1461943101381709551}"#,
+        r#"{"status":"die","id":"ede54ee1afda","from":"alpine","Type":"container","Action":"die","Actor":{"ID":"ede54ee1afda","Attributes":{"exitCode":"0","image":"alpine","name":"my-container"}},"scope":"local","time":1461943105,"timeNano":1461943105079144137}"#,
+    ]
+    .join("\n")
+}
+
+#[cfg(test)]
+fn get_system_df_response() -> String {
+    r#"{"LayersSize":1092588,"Images":[{"Id":"sha256:2b8fd9751c4c0f5dd266fcae00707e67a2545ef34f9a29354585f93dac906749","ParentId":"","RepoTags":["busybox:latest"],"RepoDigests":["busybox@sha256:a59906e33509d14c036c8678d687bd4eec81ed7c4b8ce907b888c607f6a1e0e6"],"Created":1466724217,"Size":1092588,"SharedSize":0,"VirtualSize":1092588,"Labels":{},"Containers":1}],"Containers":[{"Id":"e575172ed11dc01bfce087fb27bee502db149e1a0fad7c296ad300bbff178148","Names":["/top"],"Image":"busybox","ImageID":"sha256:2b8fd9751c4c0f5dd266fcae00707e67a2545ef34f9a29354585f93dac906749","Command":"top","Created":1472592424,"Ports":[],"SizeRootFs":1092588,"SizeRw":0,"Labels":{},"State":"exited","Status":"Exited (0) 56 minutes ago","HostConfig":{"NetworkMode":"default"},"NetworkSettings":{"Networks":{"bridge":{"IPAMConfig":null,"Links":null,"Aliases":null,"NetworkID":"d687bc59335f0e5c9ee8193e5612e8aee000c8c62ea170cfb99c098f95899d92","EndpointID":"8ed5115aeaad9abb174f68dcf135b49f11daf597678315231a32ca28441dec6a","Gateway":"172.18.0.1","IPAddress":"172.18.0.2","IPPrefixLen":16,"IPv6Gateway":"","GlobalIPv6Address":"","GlobalIPv6PrefixLen":0,"MacAddress":"02:42:ac:12:00:02"}}},"Mounts":[]}],"Volumes":[{"Name":"my-volume","Driver":"local","Mountpoint":"/var/lib/docker/volumes/my-volume/_data","Labels":null,"Scope":"local","Options":null,"UsageData":{"Size":2048,"RefCount":1}},{"Name":"remote","Driver":"nfs","Mountpoint":"","Labels":null,"Scope":"global","Options":null,"UsageData":{"Size":-1,"RefCount":0}}],"BuildCache":[{"ID":"hw53o5aio51xtltp5xjp8v7fx","Parents":[],"Type":"regular","Description":"pulled from docker.io/library/debian@sha256:234cb88d3020898631af0ccbbcca9a66ae7306ecd30c9720690858c1b007d2a0","InUse":false,"Shared":true,"Size":51,"CreatedAt":"2021-06-28T13:31:01.474619385Z","LastUsedAt":"2021-07-07T22:02:32.738075951Z","UsageCount":26}]}"#.to_owned()
+}
+
+#[cfg(test)]
+fn get_swarm_info_response() -> String {
+    r#"{"ID":"abajmipo7b4xz5ip2nrla6b11","Version":{"Index":373531},"CreatedAt":"2016-08-18T10:44:24.496525531Z","UpdatedAt":"2017-08-09T07:09:37.632105588Z","Spec":{"Name":"default","Labels":{"com.example.corp.type":"production"},"Orchestration":{"TaskHistoryRetentionLimit":10},"Raft":{"SnapshotInterval":10000,"KeepOldSnapshots":0,"LogEntriesForSlowFollowers":500,"ElectionTick":3,"HeartbeatTick":1},"Dispatcher":{"HeartbeatPeriod":5000000000},"CAConfig":{"NodeCertExpiry":7776000000000000,"ExternalCAs":[{"Protocol":"cfssl","URL":"https://ca.example.com","Options":{}}]},"EncryptionConfig":{"AutoLockManagers":false},"TaskDefaults":{"LogDriver":{"Name":"json-file","Options":{"max-file":"10","max-size":"100m"}}}},"TLSInfo":{"TrustRoot":"-----BEGIN CERTIFICATE-----\nMIIBajCCARCgAwIBAgIUbYqrLSOSQHoxD8CwG6Bi2PJi9c8wCgYIKoZIzj0EAwIw\n-----END CERTIFICATE-----\n","CertIssuerSubject":"MBMxETAPBgNVBAMTCHN3YXJtLWNh","CertIssuerPublicKey":"MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAEmT9XIw9h1qoNclv9VeHmf/Vi6/uI2vFXdBveXTpcPjqx6i9wNazchk1XWV/dKTKvSh9xyGKmiIeRcE4OiMnJ1A=="},"RootRotationInProgress":false,"DataPathPort":4789,"DefaultAddrPool":["10.10.0.0/16","20.20.0.0/16"],"SubnetSize":24,"JoinTokens":{"Worker":"SWMTKN-1-3pu6hszjas19xyp7ghgosyx9k8atbfcr8p2is99znpy26u2lkl-1awxwuwd3z9j1z3puu7rcgdbx","Manager":"SWMTKN-1-3pu6hszjas19xyp7ghgosyx9k8atbfcr8p2is99znpy26u2lkl-7p73s1dx5in4tatdymyhg9hu2"}}"#.to_string()
+}
+
+#[cfg(test)]
+fn get_services_response() -> String {
+    r#"[{"ID":"9mnpnzenvg8p8tdbtq4wvbkcz","Version":{"Index":19},"CreatedAt":"2016-06-07T21:05:51.880065305Z","UpdatedAt":"2016-06-07T21:07:29.962229872Z","Spec":{"Name":"hopeful_cori","TaskTemplate":{"ContainerSpec":{"Image":"redis:7@sha256:0b2d6d3f0f2a8fd5bc8ee9b1d0bde2b1b5d8d1fd3ee7a3ea07a1b4bc1bbc0c4e","Args":["--appendonly","yes"],"Secrets":[{"File":{"Name":"app-key","UID":"0","GID":"0","Mode":292},"SecretID":"ktnbjxoalbkvbvedmg1urrz8h","SecretName":"app-key"}]},"Resources":{"Limits":{},"Reservations":{}},"RestartPolicy":{"Condition":"any","MaxAttempts":0},"Placement":{},"ForceUpdate":0},"Mode":{"Replicated":{"Replicas":1}},"UpdateConfig":{"Parallelism":1,"Delay":1000000000,"FailureAction":"pause","Monitor":15000000000,"MaxFailureRatio":0.15},"RollbackConfig":{"Parallelism":1,"Delay":1000000000,"FailureAction":"pause","Monitor":15000000000,"MaxFailureRatio":0.15},"EndpointSpec":{"Mode":"vip","Ports":[{"Protocol":"tcp","TargetPort":6379,"PublishedPort":30001}]}},"Endpoint":{"Spec":{"Mode":"vip","Ports":[{"Protocol":"tcp","TargetPort":6379,"PublishedPort":30001}]},"Ports":[{"Protocol":"tcp","TargetPort":6379,"PublishedPort":30001}],"VirtualIPs":[{"NetworkID":"4qvuz4ko70xaltuqbt8956gd1","Addr":"10.255.0.2/16"},{"NetworkID":"4qvuz4ko70xaltuqbt8956gd1","Addr":"10.255.0.3/16"}]},"UpdateStatus":{"State":"rollback_completed","StartedAt":"2016-06-07T21:06:51.880065305Z","CompletedAt":"2016-06-07T21:07:29.962229872Z","Message":"rollback completed"},"ServiceStatus":{"RunningTasks":1,"DesiredTasks":1,"CompletedTasks":0}}]"#.to_string()
+}
+
+#[cfg(test)]
+fn get_tasks_response() -> String {
+    r#"[{"ID":"0kzzo1i0y4jz6027t0k7aezc7","Version":{"Index":71},"CreatedAt":"2016-06-07T21:07:31.171892745Z","UpdatedAt":"2016-06-07T21:07:31.376370513Z","Spec":{"ContainerSpec":{"Image":"redis"},"Resources":{"Limits":{},"Reservations":{}},"RestartPolicy":{"Condition":"any","MaxAttempts":0},"Placement":{}},"ServiceID":"9mnpnzenvg8p8tdbtq4wvbkcz","Slot":1,"NodeID":"60gvrl6tm78dmak4yl7srz94v","Status":{"Timestamp":"2016-06-07T21:07:31.290032978Z","State":"running","Message":"started","ContainerStatus":{"ContainerID":"e5d62702a1b48d01c3e02ca1e0212a250801fa8d67caca0b6f35919ebc12f035","PID":677}},"DesiredState":"running","NetworksAttachments":[{"Network":{"ID":"4qvuz4ko70xaltuqbt8956gd1"},"Addresses":["10.255.0.10/16"]}]},{"ID":"1yljwbmlr8er2waf8orvqpwms","Version":{"Index":30},"CreatedAt":"2016-06-07T21:07:30.019104782Z","UpdatedAt":"2016-06-07T21:07:30.231958098Z","Name":"hopeful_cori","Spec":{"ContainerSpec":{"Image":"redis"}},"ServiceID":"9mnpnzenvg8p8tdbtq4wvbkcz","Slot":1,"NodeID":"60gvrl6tm78dmak4yl7srz94v","Status":{"Timestamp":"2016-06-07T21:07:30.202183143Z","State":"failed","Message":"started","Err":"task: non-zero exit (1)","ContainerStatus":{"ContainerID":"","ExitCode":1}},"DesiredState":"shutdown"}]"#.to_string()
+}
+
+#[cfg(test)]
+fn get_nodes_response() -> String {
+    r#"[{"ID":"24ifsmvkjbyhk","Version":{"Index":373531},"CreatedAt":"2016-08-18T10:44:24.496525531Z","UpdatedAt":"2017-08-09T07:09:37.632105588Z","Spec":{"Availability":"active","Name":"node-name","Role":"manager","Labels":{"zone":"us-east-1a"}},"Description":{"Hostname":"bf3067039e47","Platform":{"Architecture":"x86_64","OS":"linux"},"Resources":{"NanoCPUs":4000000000,"MemoryBytes":8272408576,"GenericResources":[{"DiscreteResourceSpec":{"Kind":"SSD","Value":3}}]},"Engine":{"EngineVersion":"24.0.6","Labels":{"foo":"bar"},"Plugins":[{"Type":"Log","Name":"awslogs"},{"Type":"Network","Name":"bridge"},{"Type":"Volume","Name":"local"}]},"TLSInfo":{"TrustRoot":"-----BEGIN CERTIFICATE-----\n-----END CERTIFICATE-----\n","CertIssuerSubject":"MBMxETAPBgNVBAMTCHN3YXJtLWNh","CertIssuerPublicKey":"MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAE"}},"Status":{"State":"ready","Message":"","Addr":"172.17.0.2"},"ManagerStatus":{"Leader":true,"Reachability":"reachable","Addr":"10.0.0.46:2377"}}]"#.to_string()
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_return)]
 fn get_container_info_response() -> String {
     return r#"{"Id":"774758ca1db8d05bd848d2b3456c8253a417a0511329692869df1cbe82978d37","Created":"2016-10-25T11:59:37.858589354Z","Path":"rails","Args":["server","-b","0.0.0.0"],"State":{"Status":"running","Running":true,"Paused":false,"Restarting":false,"OOMKilled":false,"Dead":false,"Pid":13038,"ExitCode":0,"Error":"","StartedAt":"2016-10-25T11:59:38.261828009Z","FinishedAt":"0001-01-01T00:00:00Z"},"Image":"sha256:f5e9d349e7e5c0f6de798d732d83fa5e087695cd100149121f01c891e6167c13","ResolvConfPath":"/var/lib/docker/containers/774758ca1db8d05bd848d2b3456c8253a417a0511329692869df1cbe82978d37/resolv.conf","HostnamePath":"/var/lib/docker/containers/774758ca1db8d05bd848d2b3456c8253a417a0511329692869df1cbe82978d37/hostname","HostsPath":"/var/lib/docker/containers/774758ca1db8d05bd848d2b3456c8253a417a0511329692869df1cbe82978d37/hosts","LogPath":"/var/lib/docker/containers/774758ca1db8d05bd848d2b3456c8253a417a0511329692869df1cbe82978d37/774758ca1db8d05bd848d2b3456c8253a417a0511329692869df1cbe82978d37-json.log","Name":"/railshello_web_1","RestartCount":0,"Driver":"aufs","MountLabel":"","ProcessLabel":"","AppArmorProfile":"","ExecIDs":null,"HostConfig":{"Binds":[],"ContainerIDFile":"","LogConfig":{"Type":"json-file","Config":{}},"NetworkMode":"railshello_default","PortBindings":{"3000/tcp":[{"HostIp":"","HostPort":"3000"}]},"RestartPolicy":{"Name":"","MaximumRetryCount":0},"AutoRemove":false,"VolumeDriver":"","VolumesFrom":[],"CapAdd":null,"CapDrop":null,"Dns":null,"DnsOptions":null,"DnsSearch":null,"ExtraHosts":null,"GroupAdd":null,"IpcMode":"","Cgroup":"","Links":null,"OomScoreAdj":0,"PidMode":"","Privileged":false,"PublishAllPorts":false,"ReadonlyRootfs":false,"SecurityOpt":null,"UTSMode":"","UsernsMode":"","ShmSize":67108864,"Runtime":"runc","ConsoleSize":[0,0],"Isolation":"","CpuShares":0,"Memory":0,"CgroupParent":"","BlkioWeight":0,"BlkioWeightDevice":null,"BlkioDeviceReadBps":null,"BlkioDeviceWriteBps":null,"BlkioDeviceReadIOps":null,"BlkioDeviceWriteIOps":null,"CpuPeriod":0,"CpuQuota":0,"CpusetCpus":"","CpusetMems":"","Devices":null,"DiskQuota":0,"KernelMemory":0,"MemoryReservation":0,"MemorySwap":0,"MemorySwappiness":-1,"OomKillDisable":false,"PidsLimit":0,"Ulimits":null,"CpuCount":0,"CpuPercent":0,"IOMaximumIOps":0,"IOMaximumBandwidth":0},"GraphDriver":{"Name":"aufs","Data":null},"Mounts":[],"Config":{"Hostname":"774758ca1db8","Domainname":"","User":"","AttachStdin":false,"AttachStdout":false,"AttachStderr":false,"ExposedPorts":{"3000/tcp":{}},"Tty":false,"OpenStdin":false,"StdinOnce":false,"Env":["RACK_ENV=development","PROJECT_NAME=rails_hello","GLOBAL_PASSWORD=magic","SOME_PASSWORD=secret","RAILS_ENV=development","DATABASE_URL=postgres://postgres@db:5432/rails_hello_development","PATH=/usr/local/bundle/bin:/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin:/sbin:/bin","RUBY_MAJOR=2.3","RUBY_VERSION=2.3.1","RUBY_DOWNLOAD_SHA256=b87c738cb2032bf4920fef8e3864dc5cf8eae9d89d8d523ce0236945c5797dcd","RUBYGEMS_VERSION=2.6.7","BUNDLER_VERSION=1.13.4","GEM_HOME=/usr/local/bundle","BUNDLE_PATH=/usr/local/bundle","BUNDLE_BIN=/usr/local/bundle/bin","BUNDLE_SILENCE_ROOT_WARNING=1","BUNDLE_APP_CONFIG=/usr/local/bundle"],"Cmd":["rails","server","-b","0.0.0.0"],"Image":"faraday/rails_hello","Volumes":null,"WorkingDir":"/usr/src/app","Entrypoint":null,"OnBuild":null,"Labels":{"com.docker.compose.config-hash":"ff040c76ba24b1bac8d89e95cfb5ba7e29bd19423ed548a1436ae3c94bc6381a","com.docker.compose.container-number":"1","com.docker.compose.oneoff":"False","com.docker.compose.project":"railshello","com.docker.compose.service":"web","com.docker.compose.version":"1.8.1","io.fdy.cage.lib.coffee_rails":"/usr/src/app/vendor/coffee-rails","io.fdy.cage.pod":"frontend","io.fdy.cage.shell":"bash","io.fdy.cage.srcdir":"/usr/src/app","io.fdy.cage.target":"development","io.fdy.cage.test":"bundle exec rake"}},"NetworkSettings":{"Bridge":"","SandboxID":"ca243185e052f364f6f9e4141ee985397cda9c66a87258f8a8048a05452738cf","HairpinMode":false,"LinkLocalIPv6Address":"","LinkLocalIPv6PrefixLen":0,"Ports":{"3000/tcp":[{"HostIp":"0.0.0.0","HostPort":"3000"}]},"SandboxKey":"/var/run/docker/netns/ca243185e052","SecondaryIPAddresses":null,"SecondaryIPv6Addresses":null,"EndpointID":"","Gateway":"","GlobalIPv6Address":"","GlobalIPv6PrefixLen":0,"IPAddress":"","IPPrefixLen":0,"IPv6Gateway":"","MacAddress":"","Networks":{"railshello_default":{"IPAMConfig":null,"Links":null,"Aliases":["web","774758ca1db8"],"NetworkID":"4b237b1de0928a11bb399adaa249705b666bdc5dece3e9bdc260a630643bf945","EndpointID":"7d5e1e9df4bdf400654b96afdd1d42040c150a4f5b414f084c8fd5c95a9a906e","Gateway":"172.24.0.1","IPAddress":"172.24.0.3","IPPrefixLen":16,"IPv6Gateway":"","GlobalIPv6Address":"","GlobalIPv6PrefixLen":0,"MacAddress":"02:42:ac:18:00:03"}}}}"#.to_string();
 }
 
 #[cfg(test)]
+fn get_container_state_with_health_response() -> String {
+    r#"{"Status":"running","Running":true,"Paused":false,"Restarting":false,"OOMKilled":false,"Dead":false,"Pid":4242,"ExitCode":0,"Error":"","StartedAt":"2020-04-09T15:36:26.144Z","FinishedAt":"0001-01-01T00:00:00Z","Health":{"Status":"unhealthy","FailingStreak":3,"Log":[{"Start":"2020-04-09T15:36:56.1Z","End":"2020-04-09T15:36:56.2Z","ExitCode":1,"Output":"curl: (7) Failed to connect\n"}]}}"#.to_string()
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_return)]
 fn get_processes_response() -> String {
     return "{\"Processes\":[[\"4586\",\"999\",\"rust\"]],\"Titles\":[\"PID\",\"USER\",\"COMMAND\"]}".to_string();
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_return)]
 fn get_filesystem_changes_response() -> String {
-    return "[{\"Path\":\"/tmp\",\"Kind\":0}]".to_string();
+    return r#"[{"Path":"/tmp","Kind":0},{"Path":"/tmp/new","Kind":1},{"Path":"/etc/motd","Kind":2},{"Path":"/odd","Kind":7}]"#.to_string();
 }
 
 #[cfg(test)]
+fn get_modern_version_response() -> String {
+    r#"{"Platform":{"Name":"Docker Engine - Community"},"Components":[{"Name":"Engine","Version":"24.0.2","Details":{"ApiVersion":"1.43","Arch":"amd64","BuildTime":"2023-05-25T21:18:36.000000000+00:00","Experimental":"false","GitCommit":"659604f","GoVersion":"go1.20.4","KernelVersion":"6.1.0-9-amd64","MinAPIVersion":"1.12","Os":"linux"}},{"Name":"containerd","Version":"1.6.21","Details":{"GitCommit":"3dce8eb055cbb6872793272b4f20ed16117344f8"}},{"Name":"runc","Version":"1.1.7","Details":{"GitCommit":"v1.1.7-0-g860f061"}},{"Name":"docker-init","Version":"0.19.0","Details":{"GitCommit":"de40ad0"}}],"Version":"24.0.2","ApiVersion":"1.43","MinAPIVersion":"1.12","GitCommit":"659604f","GoVersion":"go1.20.4","Os":"linux","Arch":"amd64","KernelVersion":"6.1.0-9-amd64","BuildTime":"2023-05-25T21:18:36.000000000+00:00"}"#.to_owned()
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_return)]
 fn get_version_response() -> String {
     return "{\"Version\":\"1.8.1\",\"ApiVersion\":\"1.20\",\"GitCommit\":\"d12ea79\",\"GoVersion\":\"go1.4.2\",\"Os\":\"linux\",\"Arch\":\"amd64\",\"KernelVersion\":\"4.0.9-boot2docker\",\"BuildTime\":\"Thu Aug 13 02:49:29 UTC 2015\"}".to_string();
 }
 
-/*
+#[cfg(test)]
+fn get_create_container_response() -> String {
+    r#"{"Id":"e90e34656806","Warnings":[]}"#.to_string()
+}
+
+#[cfg(test)]
+fn get_wait_container_response() -> String {
+    r#"{"Error":null,"StatusCode":137}"#.to_string()
+}
+
+#[cfg(test)]
+fn get_pull_image_response() -> String {
+    concat!(
+        "{\"status\":\"Pulling from library/alpine\",\"id\":\"latest\"}\r\n",
+        "{\"status\":\"Downloading\",\"progressDetail\":{\"current\":32,\"total\":2065},\"progress\":\"[>   ]     32B/2.065kB\",\"id\":\"a3ed95caeb02\"}\r\n",
+        "{\"status\":\"Pull complete\",\"progressDetail\":{},\"id\":\"a3ed95caeb02\"}\r\n",
+        "{\"errorDetail\":{\"message\":\"manifest unknown\"},\"error\":\"manifest unknown\"}\r\n",
+    )
+    .to_string()
+}
+
+#[cfg(test)]
+fn get_build_image_response() -> String {
+    concat!(
+        r#"{"stream":"Step 1/2 : FROM alpine\n"}"#,
+        "\r\n",
+        r#"{"stream":" ---\u003e a24bb4013296\n"}"#,
+        "\r\n",
+        r#"{"aux":{"ID":"sha256:4a4b5d0b3e1f"}}"#,
+        "\r\n",
+        r#"{"errorDetail":{"code":1,"message":"The command '/bin/sh -c false' returned a non-zero code: 1"},"error":"The command '/bin/sh -c false' returned a non-zero code: 1"}"#,
+        "\r\n",
+    )
+    .to_string()
+}
+
 #[cfg(test)]
 fn get_stats_response() -> String {
-    let headers = "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nConnection: Close\r\n\r\n";
-    let s1 = get_stats_single_event(1);
-    let s2 = get_stats_single_event(2);
-    let s3 = get_stats_single_event(3);
-
-    let stream = MemoryStream::with_input(headers.as_bytes());
-    let mut writer = HttpWriter::ChunkedWriter(stream);
-    writer.write(s1.as_bytes()).unwrap();
-    writer.write(b"\n").unwrap();
-    writer.write(s2.as_bytes()).unwrap();
-    writer.write(b"\n").unwrap();
-    writer.write(s3.as_bytes()).unwrap();
-
-    let buf = match writer.end() {
-        Ok(w) => w,
-        Err(_) => {
-            panic!("error ending writer for stats response");
-        }
-    };
-    let body = String::from_utf8(buf.into_inner()).unwrap();
-    return body;
+    format!(
+        "{}\n{}\n{}\n",
+        get_stats_single_event(1),
+        get_stats_single_event(2),
+        get_stats_single_event(3)
+    )
 }
 
 #[cfg(test)]
+#[allow(clippy::needless_return)]
 fn get_stats_single_event(n: u64) -> String {
     return format!("{{\"read\":\"2015-04-09T07:02:08.48002208{}Z\",\"network\":{{\"rx_bytes\":5820720,\"rx_packets\":2742,\"rx_errors\":0,\"rx_dropped\":1,\"tx_bytes\":158527,\"tx_packets\":2124,\"tx_errors\":0,\"tx_dropped\":0}},\"cpu_stats\":{{\"cpu_usage\":{{\"total_usage\":19194125000,\"percpu_usage\":[14110113138,3245604417,845722573,992684872],\"usage_in_kernelmode\":1110000000,\"usage_in_usermode\":18160000000}},\"system_cpu_usage\":1014488290000000,\"throttling_data\":{{\"periods\":0,\"throttled_periods\":0,\"throttled_time\":0}}}},\"memory_stats\":{{\"usage\":208437248,\"max_usage\":318791680,\"stats\":{{\"active_anon\":27213824,\"active_file\":129069056,\"cache\":178946048,\"hierarchical_memory_limit\":18446744073709551615,\"hierarchical_memsw_limit\":18446744073709551615,\"inactive_anon\":0,\"inactive_file\":49876992,\"mapped_file\":10809344,\"pgfault\":99588,\"pgmajfault\":819,\"pgpgin\":130731,\"pgpgout\":153466,\"rss\":29331456,\"rss_huge\":6291456,\"swap\":0,\"total_active_anon\":27213824,\"total_active_file\":129069056,\"total_cache\":178946048,\"total_inactive_anon\":0,\"total_inactive_file\":49876992,\"total_mapped_file\":10809344,\"total_pgfault\":99588,\"total_pgmajfault\":819,\"total_pgpgin\":130731,\"total_pgpgout\":153466,\"total_rss\":29331456,\"total_rss_huge\":6291456,\"total_swap\":0,\"total_unevictable\":0,\"total_writeback\":0,\"unevictable\":0,\"writeback\":0}},\"failcnt\":0,\"limit\":16854257664}},\"blkio_stats\":{{\"io_service_bytes_recursive\":[{{\"major\":8,\"minor\":0,\"op\":\"Read\",\"value\":150687744}},{{\"major\":8,\"minor\":0,\"op\":\"Write\",\"value\":0}},{{\"major\":8,\"minor\":0,\"op\":\"Sync\",\"value\":0}},{{\"major\":8,\"minor\":0,\"op\":\"Async\",\"value\":150687744}},{{\"major\":8,\"minor\":0,\"op\":\"Total\",\"value\":150687744}}],\"io_serviced_recursive\":[{{\"major\":8,\"minor\":0,\"op\":\"Read\",\"value\":484}},{{\"major\":8,\"minor\":0,\"op\":\"Write\",\"value\":0}},{{\"major\":8,\"minor\":0,\"op\":\"Sync\",\"value\":0}},{{\"major\":8,\"minor\":0,\"op\":\"Async\",\"value\":484}},{{\"major\":8,\"minor\":0,\"op\":\"Total\",\"value\":484}}],\"io_queue_recursive\":[{{\"major\":8,\"minor\":0,\"op\":\"Read\",\"value\":0}},{{\"major\":8,\"minor\":0,\"op\":\"Write\",\"value\":0}},{{\"major\":8,\"minor\":0,\"op\":\"Sync\",\"value\":0}},{{\"major\":8,\"minor\":0,\"op\":\"Async\",\"value\":0}},{{\"major\":8,\"minor\":0,\"op\":\"Total\",\"value\":0}}],\"io_service_time_recursive\":[{{\"major\":8,\"minor\":0,\"op\":\"Read\",\"value\":2060941295}},{{\"major\":8,\"minor\":0,\"op\":\"Write\",\"value\":0}},{{\"major\":8,\"minor\":0,\"op\":\"Sync\",\"value\":0}},{{\"major\":8,\"minor\":0,\"op\":\"Async\",\"value\":2060941295}},{{\"major\":8,\"minor\":0,\"op\":\"Total\",\"value\":2060941295}}],\"io_wait_time_recursive\":[{{\"major\":8,\"minor\":0,\"op\":\"Read\",\"value\":5476872825}},{{\"major\":8,\"minor\":0,\"op\":\"Write\",\"value\":0}},{{\"major\":8,\"minor\":0,\"op\":\"Sync\",\"value\":0}},{{\"major\":8,\"minor\":0,\"op\":\"Async\",\"value\":5476872825}},{{\"major\":8,\"minor\":0,\"op\":\"Total\",\"value\":5476872825}}],\"io_merged_recursive\":[{{\"major\":8,\"minor\":0,\"op\":\"Read\",\"value\":79}},{{\"major\":8,\"minor\":0,\"op\":\"Write\",\"value\":0}},{{\"major\":8,\"minor\":0,\"op\":\"Sync\",\"value\":0}},{{\"major\":8,\"minor\":0,\"op\":\"Async\",\"value\":79}},{{\"major\":8,\"minor\":0,\"op\":\"Total\",\"value\":79}}],\"io_time_recursive\":[{{\"major\":8,\"minor\":0,\"op\":\"\",\"value\":1814}}],\"sectors_recursive\":[{{\"major\":8,\"minor\":0,\"op\":\"\",\"value\":294312}}]}}}}", n).to_string();
 }
-*/
@@ -0,0 +1,90 @@
+//! Conversions from the timestamp formats used by Docker to `chrono` types.
+
+use chrono::{DateTime, TimeZone, Utc};
+
+/// Parse an RFC 3339 timestamp.  Docker uses Go's zero time,
+/// `0001-01-01T00:00:00Z`, to mean "never", so we map it to `None`.
+pub(crate) fn parse_rfc3339(value: &str) -> Option<DateTime<Utc>> {
+    let timestamp = DateTime::parse_from_rfc3339(value).ok()?;
+    if timestamp.timestamp() <= GO_ZERO_TIME {
+        None
+    } else {
+        Some(timestamp.with_timezone(&Utc))
+    }
+}
+
+/// Convert a Unix timestamp, in seconds.
+pub(crate) fn from_unix(secs: u64) -> Option<DateTime<Utc>> {
+    Utc.timestamp_opt(secs as i64, 0).single()
+}
+
+/// `0001-01-01T00:00:00Z` as a Unix timestamp.
+const GO_ZERO_TIME: i64 = -62_135_596_800;
+
+/// Serialize a `DateTime<Utc>` as a Unix timestamp, in seconds.
+pub(crate) mod unix {
+    use chrono::{DateTime, TimeZone, Utc};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        timestamp: &DateTime<Utc>,
+        ser: S,
+    ) -> Result<S::Ok, S::Error> {
+        ser.serialize_i64(timestamp.timestamp())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(de: D) -> Result<DateTime<Utc>, D::Error> {
+        let secs = i64::deserialize(de)?;
+        Utc.timestamp_opt(secs, 0)
+            .single()
+            .ok_or_else(|| de::Error::custom(format!("invalid Unix timestamp: {}", secs)))
+    }
+}
+
+/// Serialize a `DateTime<Utc>` as an RFC 3339 timestamp.
+pub(crate) mod rfc3339 {
+    use chrono::{DateTime, SecondsFormat, Utc};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        timestamp: &DateTime<Utc>,
+        ser: S,
+    ) -> Result<S::Ok, S::Error> {
+        ser.serialize_str(&timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(de: D) -> Result<DateTime<Utc>, D::Error> {
+        let value = String::deserialize(de)?;
+        DateTime::parse_from_rfc3339(&value)
+            .map(|timestamp| timestamp.with_timezone(&Utc))
+            .map_err(|_| de::Error::custom(format!("invalid RFC 3339 timestamp: {}", value)))
+    }
+}
+
+/// Serialize an optional `DateTime<Utc>` as an RFC 3339 timestamp, using
+/// Go's zero time for `None`, like Docker does.
+pub(crate) mod optional_rfc3339 {
+    use chrono::{DateTime, Utc};
+    use serde::{Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        timestamp: &Option<DateTime<Utc>>,
+        ser: S,
+    ) -> Result<S::Ok, S::Error> {
+        match timestamp {
+            Some(timestamp) => super::rfc3339::serialize(timestamp, ser),
+            None => ser.serialize_str("0001-01-01T00:00:00Z"),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        de: D,
+    ) -> Result<Option<DateTime<Utc>>, D::Error> {
+        let timestamp = super::rfc3339::deserialize(de)?;
+        if timestamp.timestamp() <= super::GO_ZERO_TIME {
+            Ok(None)
+        } else {
+            Ok(Some(timestamp))
+        }
+    }
+}
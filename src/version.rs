@@ -1,8 +1,22 @@
-#[derive(Debug, Serialize, Deserialize)]
+use std::collections::HashMap;
+
+use crate::image::null_to_default;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(non_snake_case)]
 pub struct Version {
+    #[serde(default)]
+    pub Platform: Option<Platform>,
+    /// The versions of each part of the Docker engine, such as `"Engine"`,
+    /// `"containerd"` and `"runc"`.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Components: Vec<ComponentVersion>,
     pub Version: String,
+    /// The newest API version this daemon supports, such as `"1.43"`.
     pub ApiVersion: String,
+    /// The oldest API version this daemon supports.
+    #[serde(default)]
+    pub MinAPIVersion: Option<String>,
     pub GitCommit: String,
     pub GoVersion: String,
     pub Os: String,
@@ -11,3 +25,49 @@ pub struct Version {
     pub BuildTime: Option<String>,
     pub Experimental: Option<bool>,
 }
+
+impl Version {
+    /// The newest API version this daemon supports, as `(major, minor)`.
+    pub fn api_version(&self) -> Option<(u32, u32)> {
+        parse_api_version(&self.ApiVersion)
+    }
+
+    /// Does this daemon support at least API version `major.minor`?
+    pub fn supports_api(&self, major: u32, minor: u32) -> bool {
+        matches!(self.api_version(), Some(version) if version >= (major, minor))
+    }
+
+    /// The version of the component named `name`, such as `"containerd"`.
+    pub fn component(&self, name: &str) -> Option<&ComponentVersion> {
+        self.Components
+            .iter()
+            .find(|component| component.Name == name)
+    }
+}
+
+/// The product this daemon belongs to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct Platform {
+    /// Such as `"Docker Engine - Community"`.
+    pub Name: String,
+}
+
+/// The version of one part of the Docker engine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ComponentVersion {
+    pub Name: String,
+    pub Version: String,
+    /// Extra information, such as the `"GitCommit"`.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Details: HashMap<String, String>,
+}
+
+/// Parse an API version like `"1.43"`.
+fn parse_api_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.splitn(2, '.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
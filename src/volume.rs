@@ -0,0 +1,87 @@
+//! Docker volumes.
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use crate::image::null_to_default;
+#[cfg(feature = "chrono")]
+use crate::timestamps;
+
+/// A volume, as returned by `Docker::volumes`, `Docker::volume_info` and
+/// `Docker::create_volume`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct Volume {
+    pub Name: String,
+    /// The volume driver, usually `"local"`.
+    pub Driver: String,
+    /// Where the volume is stored on the Docker host.
+    pub Mountpoint: String,
+    /// An RFC 3339 timestamp.  Missing for volumes created by old daemons.
+    #[serde(default)]
+    pub CreatedAt: Option<String>,
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Labels: HashMap<String, String>,
+    /// `"local"` or `"global"`.
+    #[serde(default)]
+    pub Scope: String,
+    /// Options passed to the driver when the volume was created.
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub Options: HashMap<String, String>,
+    /// How much space the volume uses.  Only reported by
+    /// `Docker::system_df`.
+    #[serde(default)]
+    pub UsageData: Option<VolumeUsageData>,
+}
+
+#[cfg(feature = "chrono")]
+impl Volume {
+    /// When this volume was created.
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.CreatedAt
+            .as_deref()
+            .and_then(timestamps::parse_rfc3339)
+    }
+}
+
+/// Disk usage of a volume.  Docker reports `-1` for values it couldn't
+/// work out, such as the size of volumes using non-local drivers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct VolumeUsageData {
+    /// The size of the volume's contents, in bytes.
+    pub Size: i64,
+    /// The number of containers using the volume.
+    pub RefCount: i64,
+}
+
+impl VolumeUsageData {
+    /// The size of the volume, if Docker knows it.
+    pub fn size(&self) -> Option<u64> {
+        u64::try_from(self.Size).ok()
+    }
+
+    /// The number of containers using the volume, if Docker knows it.
+    pub fn ref_count(&self) -> Option<u64> {
+        u64::try_from(self.RefCount).ok()
+    }
+}
+
+/// The response to `Docker::prune_volumes`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub struct PruneVolumesResponse {
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub VolumesDeleted: Vec<String>,
+    pub SpaceReclaimed: u64,
+}
+
+/// The response to `Docker::volumes`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+pub(crate) struct VolumeList {
+    #[serde(deserialize_with = "null_to_default", default)]
+    pub(crate) Volumes: Vec<Volume>,
+}